@@ -628,9 +628,15 @@ class ProductProvider extends ChangeNotifier {
     // Get old and new data
     final oldPrice = oldProduct.priceHistory.isNotEmpty
         ? oldProduct.priceHistory.last.price
-        : 0;
+        : null;
     final newPrice = newProduct.priceHistory.isNotEmpty
         ? newProduct.priceHistory.last.price
+        : null;
+    // Prices in different currencies cannot be compared
+    final priceChange = oldPrice != null &&
+            newPrice != null &&
+            oldPrice.currency == newPrice.currency
+        ? newPrice.asMajor().compareTo(oldPrice.asMajor())
         : 0;
     final oldInStock = oldProduct.priceHistory.isNotEmpty
         ? oldProduct.priceHistory.last.inStock
@@ -643,22 +649,22 @@ class ProductProvider extends ChangeNotifier {
         newProduct.images.isNotEmpty ? newProduct.images.first : null;
 
     // Check for price drop
-    if (newPrice < oldPrice && notificationSettings.priceDropEnabled) {
+    if (priceChange < 0 && notificationSettings.priceDropEnabled) {
       await NotificationService.showPriceDropNotification(
         title: newProduct.title,
         imageUrl: imageUrl,
-        oldPrice: oldPrice,
-        newPrice: newPrice,
+        oldPrice: oldPrice!,
+        newPrice: newPrice!,
       );
     }
 
     // Check for price increase
-    if (newPrice > oldPrice && notificationSettings.priceIncreaseEnabled) {
+    if (priceChange > 0 && notificationSettings.priceIncreaseEnabled) {
       await NotificationService.showPriceIncreaseNotification(
         title: newProduct.title,
         imageUrl: imageUrl,
-        oldPrice: oldPrice,
-        newPrice: newPrice,
+        oldPrice: oldPrice!,
+        newPrice: newPrice!,
       );
     }
 
@@ -4,6 +4,7 @@ import 'package:flutter_local_notifications/flutter_local_notifications.dart';
 import 'package:path_provider/path_provider.dart';
 import 'package:path/path.dart' as path;
 import 'package:http/http.dart' as http;
+import 'package:palert/src/rust/api/money.dart';
 
 class NotificationService {
   static final FlutterLocalNotificationsPlugin _notificationsPlugin =
@@ -91,8 +92,8 @@ class NotificationService {
   static Future<void> showPriceDropNotification({
     required String title,
     String? imageUrl,
-    required int oldPrice,
-    required int newPrice,
+    required Money oldPrice,
+    required Money newPrice,
   }) async {
     String? bigPicturePath;
     if (imageUrl != null && imageUrl.isNotEmpty) {
@@ -121,7 +122,7 @@ class NotificationService {
         styleInformation: BigPictureStyleInformation(
           FilePathAndroidBitmap(bigPicturePath),
           contentTitle: title,
-          summaryText: 'Price dropped from \\$oldPrice to \\$newPrice',
+          summaryText: 'Price dropped from ${oldPrice.display()} to ${newPrice.display()}',
         ),
       );
     } else {
@@ -152,7 +153,7 @@ class NotificationService {
     await _notificationsPlugin.show(
       0,
       title,
-      'Price dropped from \\$oldPrice to \\$newPrice',
+      'Price dropped from ${oldPrice.display()} to ${newPrice.display()}',
       notificationDetails,
     );
   }
@@ -285,8 +286,8 @@ class NotificationService {
   static Future<void> showPriceIncreaseNotification({
     required String title,
     String? imageUrl,
-    required int oldPrice,
-    required int newPrice,
+    required Money oldPrice,
+    required Money newPrice,
   }) async {
     String? bigPicturePath;
     if (imageUrl != null && imageUrl.isNotEmpty) {
@@ -315,7 +316,7 @@ class NotificationService {
         styleInformation: BigPictureStyleInformation(
           FilePathAndroidBitmap(bigPicturePath),
           contentTitle: title,
-          summaryText: 'Price increased from ₹$oldPrice to ₹$newPrice',
+          summaryText: 'Price increased from ${oldPrice.display()} to ${newPrice.display()}',
         ),
       );
     } else {
@@ -338,7 +339,7 @@ class NotificationService {
     await _notificationsPlugin.show(
       5,
       title,
-      'Price increased from ₹$oldPrice to ₹$newPrice',
+      'Price increased from ${oldPrice.display()} to ${newPrice.display()}',
       notificationDetails,
     );
   }
@@ -7,9 +7,11 @@ import '../frb_generated.dart';
 import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_text`
 
-Future<ProductDetails> extractDetails(
-        {required String htmlSource, required String url}) =>
-    RustLib.instance.api
-        .crateApiAmazonParserExtractDetails(htmlSource: htmlSource, url: url);
+            // These functions are ignored because they are not marked as `pub`: `core_price`, `get_text`
+
+
+            Future<ProductDetails>  extractDetails({required String htmlSource , required String url }) => RustLib.instance.api.crateApiAmazonParserExtractDetails(htmlSource: htmlSource, url: url);
+
+            
+            
\ No newline at end of file
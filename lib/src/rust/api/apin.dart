@@ -4,56 +4,133 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'binary_discovery.dart';
+import 'browser_service_manager.dart';
+import 'cookie_jar.dart';
+import 'diagnostics.dart';
+import 'events.dart';
+import 'fetcher.dart';
+import 'image_cache.dart';
 import 'models.dart';
+import 'money.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'price_engine.dart';
+import 'proxy.dart';
+import 'rate_limiter.dart';
+import 'recycle_policy.dart';
+import 'resource_blocking.dart';
+import 'retry_policy.dart';
+import 'site_config.dart';
+import 'timeouts.dart';
+import 'watchdog.dart';
 
-Future<PriceEngine> getPriceEngine(
-        {required int port,
-        required String browserPath,
-        required String dbPath,
-        required String driverPath}) =>
-    RustLib.instance.api.crateApiApinGetPriceEngine(
-        port: port,
-        browserPath: browserPath,
-        dbPath: dbPath,
-        driverPath: driverPath);
-
-Future<void> shutdownPriceEngine({required PriceEngine engine}) =>
-    RustLib.instance.api.crateApiApinShutdownPriceEngine(engine: engine);
-
-Future<ProductRecord> fetchAndUpdateProduct(
-        {required PriceEngine engine, required String url}) =>
-    RustLib.instance.api
-        .crateApiApinFetchAndUpdateProduct(engine: engine, url: url);
-
-Future<List<ProductRecord>> getAllProductsInDb({required PriceEngine engine}) =>
-    RustLib.instance.api.crateApiApinGetAllProductsInDb(engine: engine);
-
-Future<void> removeProductById(
-        {required PriceEngine engine, required String productId}) =>
-    RustLib.instance.api
-        .crateApiApinRemoveProductById(engine: engine, productId: productId);
-
-Future<void> createBackup(
-        {required PriceEngine engine, required String backupPath}) =>
-    RustLib.instance.api
-        .crateApiApinCreateBackup(engine: engine, backupPath: backupPath);
-
-Future<void> restoreFromBackup(
-        {required PriceEngine engine,
-        required String backupPath,
-        required bool replaceExisting}) =>
-    RustLib.instance.api.crateApiApinRestoreFromBackup(
-        engine: engine,
-        backupPath: backupPath,
-        replaceExisting: replaceExisting);
-
-Future<PriceEngineStatus> checkServiceStatus({required PriceEngine engine}) =>
-    RustLib.instance.api.crateApiApinCheckServiceStatus(engine: engine);
-
-Future<int> getCurrentPort({required PriceEngine engine}) =>
-    RustLib.instance.api.crateApiApinGetCurrentPort(engine: engine);
-
-Future<String> restartBrowserService({required PriceEngine engine}) =>
-    RustLib.instance.api.crateApiApinRestartBrowserService(engine: engine);
+
+            
+
+            Future<PriceEngine>  getPriceEngine({required int port , required String browserPath , required String dbPath , required String driverPath }) => RustLib.instance.api.crateApiApinGetPriceEngine(port: port, browserPath: browserPath, dbPath: dbPath, driverPath: driverPath);
+
+Future<PriceEngine>  getPriceEngineForBrowser({required int port , required BrowserKind browserKind , required String browserPath , required String dbPath , required String driverPath }) => RustLib.instance.api.crateApiApinGetPriceEngineForBrowser(port: port, browserKind: browserKind, browserPath: browserPath, dbPath: dbPath, driverPath: driverPath);
+
+Future<PriceEngine>  getPriceEngineWithOptions({required int port , required BrowserKind browserKind , required BrowserOptions options , required String browserPath , required String dbPath , required String driverPath }) => RustLib.instance.api.crateApiApinGetPriceEngineWithOptions(port: port, browserKind: browserKind, options: options, browserPath: browserPath, dbPath: dbPath, driverPath: driverPath);
+
+/// Finds the browser and driver of `browser_kind` on this machine and checks that their versions match.
+Future<DiscoveryReport>  discoverBrowserBinaries({required BrowserKind browserKind }) => RustLib.instance.api.crateApiApinDiscoverBrowserBinaries(browserKind: browserKind);
+
+/// Checks user-chosen browser and driver paths; an empty path is discovered instead.
+Future<DiscoveryReport>  checkBrowserBinaries({required BrowserKind browserKind , required String browserPath , required String driverPath }) => RustLib.instance.api.crateApiApinCheckBrowserBinaries(browserKind: browserKind, browserPath: browserPath, driverPath: driverPath);
+
+Future<DiscoveryReport>  getBrowserBinaries({required PriceEngine engine }) => RustLib.instance.api.crateApiApinGetBrowserBinaries(engine: engine);
+
+/// Returns the last `lines` lines chromedriver (or the configured driver) printed, for troubleshooting.
+Future<List<String>>  getDriverLogTail({required PriceEngine engine , required int lines }) => RustLib.instance.api.crateApiApinGetDriverLogTail(engine: engine, lines: lines);
+
+/// Creates an engine that uses an existing WebDriver server or Selenium Grid instead of spawning a driver.
+Future<PriceEngine>  getRemotePriceEngine({required String webdriverUrl , required BrowserKind browserKind , required BrowserOptions options , required String dbPath }) => RustLib.instance.api.crateApiApinGetRemotePriceEngine(webdriverUrl: webdriverUrl, browserKind: browserKind, options: options, dbPath: dbPath);
+
+Future<PriceEngine>  getHttpOnlyPriceEngine({required String dbPath }) => RustLib.instance.api.crateApiApinGetHttpOnlyPriceEngine(dbPath: dbPath);
+
+Future<void>  shutdownPriceEngine({required PriceEngine engine }) => RustLib.instance.api.crateApiApinShutdownPriceEngine(engine: engine);
+
+Future<ProductRecord>  fetchAndUpdateProduct({required PriceEngine engine , required String url }) => RustLib.instance.api.crateApiApinFetchAndUpdateProduct(engine: engine, url: url);
+
+Future<List<RefreshOutcome>>  refreshAllProducts({required PriceEngine engine }) => RustLib.instance.api.crateApiApinRefreshAllProducts(engine: engine);
+
+Future<void>  setBrowserPoolSize({required PriceEngine engine , required int poolSize }) => RustLib.instance.api.crateApiApinSetBrowserPoolSize(engine: engine, poolSize: poolSize);
+
+Future<void>  setBrowserRecyclePolicy({required PriceEngine engine , required RecyclePolicy policy }) => RustLib.instance.api.crateApiApinSetBrowserRecyclePolicy(engine: engine, policy: policy);
+
+Future<void>  setFetchTimeouts({required PriceEngine engine , required FetchTimeouts timeouts }) => RustLib.instance.api.crateApiApinSetFetchTimeouts(engine: engine, timeouts: timeouts);
+
+Future<FetchTimeouts>  getFetchTimeouts({required PriceEngine engine }) => RustLib.instance.api.crateApiApinGetFetchTimeouts(engine: engine);
+
+Future<List<ProductRecord>>  getAllProductsInDb({required PriceEngine engine }) => RustLib.instance.api.crateApiApinGetAllProductsInDb(engine: engine);
+
+Future<void>  removeProductById({required PriceEngine engine , required String productId }) => RustLib.instance.api.crateApiApinRemoveProductById(engine: engine, productId: productId);
+
+Future<void>  createBackup({required PriceEngine engine , required String backupPath }) => RustLib.instance.api.crateApiApinCreateBackup(engine: engine, backupPath: backupPath);
+
+Future<void>  restoreFromBackup({required PriceEngine engine , required String backupPath , required bool replaceExisting }) => RustLib.instance.api.crateApiApinRestoreFromBackup(engine: engine, backupPath: backupPath, replaceExisting: replaceExisting);
+
+Future<ImageCacheStats>  getImageCacheStats({required PriceEngine engine }) => RustLib.instance.api.crateApiApinGetImageCacheStats(engine: engine);
+
+Future<ImageCacheStats>  cleanupImageCache({required PriceEngine engine }) => RustLib.instance.api.crateApiApinCleanupImageCache(engine: engine);
+
+Future<List<SiteConfig>>  getSiteConfigs({required PriceEngine engine }) => RustLib.instance.api.crateApiApinGetSiteConfigs(engine: engine);
+
+Future<void>  setSiteFetcher({required PriceEngine engine , required String domain , required FetcherKind fetcher }) => RustLib.instance.api.crateApiApinSetSiteFetcher(engine: engine, domain: domain, fetcher: fetcher);
+
+Future<void>  setSiteFallback({required PriceEngine engine , required String domain , required bool enabled }) => RustLib.instance.api.crateApiApinSetSiteFallback(engine: engine, domain: domain, enabled: enabled);
+
+Future<void>  setSiteReadyCondition({required PriceEngine engine , required String domain , required ReadyCondition condition , required int timeoutMs }) => RustLib.instance.api.crateApiApinSetSiteReadyCondition(engine: engine, domain: domain, condition: condition, timeoutMs: timeoutMs);
+
+Future<void>  setSiteRateLimit({required PriceEngine engine , required String domain , required RateLimit rateLimit }) => RustLib.instance.api.crateApiApinSetSiteRateLimit(engine: engine, domain: domain, rateLimit: rateLimit);
+
+Future<void>  setSiteRetryPolicy({required PriceEngine engine , required String domain , required RetryPolicy retryPolicy }) => RustLib.instance.api.crateApiApinSetSiteRetryPolicy(engine: engine, domain: domain, retryPolicy: retryPolicy);
+
+/// Imports cookies (Netscape cookies.txt or JSON) for `domain`; returns how many were imported.
+Future<int>  importCookies({required PriceEngine engine , required String domain , required String content , required CookieFormat format }) => RustLib.instance.api.crateApiApinImportCookies(engine: engine, domain: domain, content: content, format: format);
+
+Future<String>  exportCookies({required PriceEngine engine , required String domain , required CookieFormat format }) => RustLib.instance.api.crateApiApinExportCookies(engine: engine, domain: domain, format: format);
+
+/// Lists the latest screenshots and page sources saved when fetching the product failed.
+Future<List<DiagnosticCapture>>  getDiagnosticCaptures({required PriceEngine engine , required String productId , required int limit }) => RustLib.instance.api.crateApiApinGetDiagnosticCaptures(engine: engine, productId: productId, limit: limit);
+
+/// Lists the latest captures saved when fetching `url` failed, or of every page when `url`
+/// is `None`; works for links that never became a product because their first fetch failed.
+Future<List<DiagnosticCapture>>  getDiagnosticCapturesForUrl({required PriceEngine engine , String? url , required int limit }) => RustLib.instance.api.crateApiApinGetDiagnosticCapturesForUrl(engine: engine, url: url, limit: limit);
+
+Future<DiagnosticCaptureContent>  openDiagnosticCapture({required PriceEngine engine , required String captureId }) => RustLib.instance.api.crateApiApinOpenDiagnosticCapture(engine: engine, captureId: captureId);
+
+Future<void>  setDiagnosticsRetention({required PriceEngine engine , required int maxCaptures }) => RustLib.instance.api.crateApiApinSetDiagnosticsRetention(engine: engine, maxCaptures: maxCaptures);
+
+Future<void>  setDefaultProxies({required PriceEngine engine , required List<String> proxies }) => RustLib.instance.api.crateApiApinSetDefaultProxies(engine: engine, proxies: proxies);
+
+Future<void>  setSiteProxies({required PriceEngine engine , required String domain , required List<String> proxies }) => RustLib.instance.api.crateApiApinSetSiteProxies(engine: engine, domain: domain, proxies: proxies);
+
+Future<List<ProxyStatus>>  getProxyStatus({required PriceEngine engine }) => RustLib.instance.api.crateApiApinGetProxyStatus(engine: engine);
+
+Future<void>  setSiteBlockCooldown({required PriceEngine engine , required String domain , required int cooldownMs }) => RustLib.instance.api.crateApiApinSetSiteBlockCooldown(engine: engine, domain: domain, cooldownMs: cooldownMs);
+
+Future<void>  setSiteAllowedResources({required PriceEngine engine , required String domain , required List<ResourceType> allowed }) => RustLib.instance.api.crateApiApinSetSiteAllowedResources(engine: engine, domain: domain, allowed: allowed);
+
+/// Forwards engine events, such as a site serving a captcha, to the UI until the stream is closed.
+Stream<EngineEvent>  subscribeEngineEvents({required PriceEngine engine }) => RustLib.instance.api.crateApiApinSubscribeEngineEvents(engine: engine);
+
+Future<List<FetchPathStats>>  getFetchPathStats({required PriceEngine engine }) => RustLib.instance.api.crateApiApinGetFetchPathStats(engine: engine);
+
+Future<List<FetchTimingStats>>  getFetchTimingStats({required PriceEngine engine }) => RustLib.instance.api.crateApiApinGetFetchTimingStats(engine: engine);
+
+Future<PriceEngineStatus>  checkServiceStatus({required PriceEngine engine }) => RustLib.instance.api.crateApiApinCheckServiceStatus(engine: engine);
+
+Future<int>  getCurrentPort({required PriceEngine engine }) => RustLib.instance.api.crateApiApinGetCurrentPort(engine: engine);
+
+Future<String>  restartBrowserService({required PriceEngine engine }) => RustLib.instance.api.crateApiApinRestartBrowserService(engine: engine);
+
+/// Probes the browser service in the background and restarts it when it fails; status
+/// changes arrive through `subscribe_engine_events`.
+Future<void>  startServiceWatchdog({required PriceEngine engine , required WatchdogConfig config }) => RustLib.instance.api.crateApiApinStartServiceWatchdog(engine: engine, config: config);
+
+Future<void>  stopServiceWatchdog({required PriceEngine engine }) => RustLib.instance.api.crateApiApinStopServiceWatchdog(engine: engine);
+
+            
+            
\ No newline at end of file
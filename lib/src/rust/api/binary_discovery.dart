@@ -0,0 +1,77 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'browser_service_manager.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `binary_info`, `browser_info`, `browser_locations`, `cached_version`, `compatibility`, `driver_locations`, `find_browser`, `find_driver`, `find_in_path`, `info`, `known_versions`, `non_empty`, `run_version`, `version_from_install_dir`, `version_pattern`, `windows_install`, `windows_locations`, `windows_roots`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            /// Searches PATH and the usual install locations for `kind`'s browser and driver.
+Future<DiscoveryReport>  discover({required BrowserKind kind }) => RustLib.instance.api.crateApiBinaryDiscoveryDiscover(kind: kind);
+
+/// Checks the given browser and driver, discovering whichever path is empty.
+Future<DiscoveryReport>  inspect({required BrowserKind kind , required String browserPath , required String driverPath }) => RustLib.instance.api.crateApiBinaryDiscoveryInspect(kind: kind, browserPath: browserPath, driverPath: driverPath);
+
+            /// A browser or driver executable and the version it reports
+class BinaryInfo  {
+                final String path;
+/// Full version string, e.g. "126.0.6478.126"; `None` if it could not be read.
+final String? version;
+final int? majorVersion;
+
+                const BinaryInfo({required this.path ,this.version ,this.majorVersion ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^version.hashCode^majorVersion.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BinaryInfo &&
+                runtimeType == other.runtimeType
+                && path == other.path&& version == other.version&& majorVersion == other.majorVersion;
+        
+            }
+
+/// The browser and driver found for a browser kind, and whether they work together
+class DiscoveryReport  {
+                final BrowserKind browserKind;
+final BinaryInfo? browser;
+final BinaryInfo? driver;
+/// False when a binary is missing or the driver was built for another browser version.
+final bool compatible;
+/// Explanation for the settings screen, including what to install on a mismatch.
+final String message;
+
+                const DiscoveryReport({required this.browserKind ,this.browser ,this.driver ,required this.compatible ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => browserKind.hashCode^browser.hashCode^driver.hashCode^compatible.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DiscoveryReport &&
+                runtimeType == other.runtimeType
+                && browserKind == other.browserKind&& browser == other.browser&& driver == other.driver&& compatible == other.compatible&& message == other.message;
+        
+            }
+            
\ No newline at end of file
@@ -5,74 +5,187 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'resource_blocking.dart';
+import 'timeouts.dart';
 
-// These functions are ignored because they are not marked as `pub`: `create_webdriver_internal`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`, `fmt`, `fmt`
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BrowserServiceManager>>
-abstract class BrowserServiceManager implements RustOpaqueInterface {
-  /// Checks if the browser service is responsive at the current port
-  Future<ServiceStatus> checkServiceHealth();
+            // These functions are ignored because they are not marked as `pub`: `chromium_like_caps`, `create_webdriver_internal`, `driver_args`, `driver_exit`, `driver_log_tail`, `driver_name`, `driver_pid`, `driver_url`, `firefox_caps`, `is_browser_process`, `location`, `max_sessions`, `move_to_next_port`, `ready_flag_is_reliable`, `reconnect`, `record_pids`, `set_driver_log_path`, `set_pid_file`, `spawn_driver`, `startup_after_exit`, `wait_until_ready`, `webdriver_capabilities`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Startup`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `drop`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
-  /// Finds the next available port starting from the current port
-  Future<int> findAvailablePort();
 
-  /// Gets the current port
-  Future<int> getCurrentPort();
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BrowserServiceManager>>
+                abstract class BrowserServiceManager implements RustOpaqueInterface {
+                    /// Checks if the browser service is responsive at the current port. A driver that
+/// does not answer within the status check timeout is unhealthy.
+ Future<ServiceStatus>  checkServiceHealth();
+
+
+/// Finds the next available port starting from the current port
+ Future<int>  findAvailablePort();
+
+
+/// Gets the browser kind being driven
+ Future<BrowserKind>  getBrowserKind();
+
+
+/// Gets the options browser sessions are launched with
+ Future<BrowserOptions>  getBrowserOptions();
+
+
+/// Gets the current port
+ Future<int>  getCurrentPort();
+
+
+/// Gets the proxy new browser sessions are routed through
+ Future<String?>  getProxy();
+
+
+/// Gets the timeouts browser sessions are created with
+ Future<FetchTimeouts>  getTimeouts();
+
+
+/// Checks if a port is available
+static Future<bool>  isPortAvailable({required int port })=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerIsPortAvailable(port: port);
+
+
+/// Whether the manager attaches to an external WebDriver server instead of running its own driver
+ Future<bool>  isRemote();
 
-  /// Checks if a port is available
-  static Future<bool> isPortAvailable({required int port}) =>
-      RustLib.instance.api
-          .crateApiBrowserServiceManagerBrowserServiceManagerIsPortAvailable(
-              port: port);
 
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  /// Creates a new service manager
-  static Future<BrowserServiceManager> newInstance(
-          {required int initialPort,
-          required String driverPath,
-          required String browserPath}) =>
-      RustLib.instance.api
-          .crateApiBrowserServiceManagerBrowserServiceManagerNew(
-              initialPort: initialPort,
-              driverPath: driverPath,
-              browserPath: browserPath);
-
-  /// Restarts the service with fallback to new port if needed
-  Future<ServiceStatus> restartService();
-
-  /// Sets a new port range
-  Future<void> setPortRange({required int minPort, required int maxPort});
-
-  /// Starts the chromedriver service on the current port
-  Future<ServiceStatus> startService();
-
-  /// Stops the chromedriver service
-  Future<void> stopService();
-}
+/// Creates a new service manager; the browser kind is guessed from the driver name
+static Future<BrowserServiceManager>  newInstance({required int initialPort , required String driverPath , required String browserPath })=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerNew(initialPort: initialPort, driverPath: driverPath, browserPath: browserPath);
+
+
+/// Creates a manager for a WebDriver server that is already running elsewhere, such
+/// as a Selenium Grid or a standalone Selenium container at `http://host:4444`.
+/// No driver process is spawned or killed; restarts only reconnect.
+static Future<BrowserServiceManager>  newRemote({required String url , required BrowserKind browserKind })=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerNewRemote(url: url, browserKind: browserKind);
+
+
+/// Restarts the service with fallback to new port if needed
+ Future<ServiceStatus>  restartService();
+
+
+/// Overrides the browser kind guessed from the driver name
+ Future<void>  setBrowserKind({required BrowserKind browserKind });
+
+
+/// Sets the options used for browser sessions created from now on
+ Future<void>  setBrowserOptions({required BrowserOptions options });
+
+
+/// Sets a new port range
+ Future<void>  setPortRange({required int minPort , required int maxPort });
+
+
+/// Routes browser sessions created from now on through `proxy` (e.g. `http://host:3128`
+/// or `socks5://host:1080`), or directly when `None`.
+ Future<void>  setProxy({String? proxy });
+
+
+/// Sets the timeouts used for browser sessions created from now on
+ Future<void>  setTimeouts({required FetchTimeouts timeouts });
+
+
+/// Starts the driver service on the current port, moving on to the next free port
+/// when the driver cannot bind it. Polls `/status` until the driver is ready or
+/// the startup timeout passes.
+ Future<ServiceStatus>  startService();
+
+
+/// Stops the driver service
+ Future<void>  stopService();
+
+
+
+                    
+                }
+                
+
+/// The browser family to drive, which decides capabilities, driver arguments and health checks
+enum BrowserKind {
+                    chrome,
+chromium,
+firefox,
+edge,
+                    ;
+                    /// Guesses the browser from the driver executable name, defaulting to Chrome.
+static Future<BrowserKind>  detect({required String driverPath })=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserKindDetect(driverPath: driverPath);
+
+
+                }
+
+/// How browser sessions are launched
+class BrowserOptions  {
+                /// Profile directory kept between runs, preserving cookies, location and consent state.
+/// Only one session can use a profile at a time, so this disables parallel sessions.
+final String? userDataDir;
+/// Extra command-line arguments passed to the browser as-is.
+final List<String> extraArgs;
+final int windowWidth;
+final int windowHeight;
+/// Preferred page language, e.g. "en-IN".
+final String? language;
+/// Replaces the browser's own user-agent string.
+final String? userAgent;
+/// Run without a visible window; turn off to watch the browser while debugging.
+final bool headless;
+/// Resource types sessions skip downloading; sites can allow some back through
+/// their `allowed_resources`.
+final List<ResourceType> blockedResources;
+
+                const BrowserOptions({this.userDataDir ,required this.extraArgs ,required this.windowWidth ,required this.windowHeight ,this.language ,this.userAgent ,required this.headless ,required this.blockedResources ,});
+
+                static Future<BrowserOptions>  default_()=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => userDataDir.hashCode^extraArgs.hashCode^windowWidth.hashCode^windowHeight.hashCode^language.hashCode^userAgent.hashCode^headless.hashCode^blockedResources.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BrowserOptions &&
+                runtimeType == other.runtimeType
+                && userDataDir == other.userDataDir&& extraArgs == other.extraArgs&& windowWidth == other.windowWidth&& windowHeight == other.windowHeight&& language == other.language&& userAgent == other.userAgent&& headless == other.headless&& blockedResources == other.blockedResources;
+        
+            }
 
 /// Result of a browser service check
-class ServiceStatus {
-  final bool isHealthy;
-  final int port;
-  final String? errorMessage;
-
-  const ServiceStatus({
-    required this.isHealthy,
-    required this.port,
-    this.errorMessage,
-  });
-
-  @override
-  int get hashCode =>
-      isHealthy.hashCode ^ port.hashCode ^ errorMessage.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ServiceStatus &&
-          runtimeType == other.runtimeType &&
-          isHealthy == other.isHealthy &&
-          port == other.port &&
-          errorMessage == other.errorMessage;
-}
+class ServiceStatus  {
+                final bool isHealthy;
+final int port;
+final String? errorMessage;
+/// How long starting the driver took until it answered, in milliseconds; 0 for plain checks.
+final BigInt startupMs;
+
+                const ServiceStatus({required this.isHealthy ,required this.port ,this.errorMessage ,required this.startupMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => isHealthy.hashCode^port.hashCode^errorMessage.hashCode^startupMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ServiceStatus &&
+                runtimeType == other.runtimeType
+                && isHealthy == other.isHealthy&& port == other.port&& errorMessage == other.errorMessage&& startupMs == other.startupMs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,26 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_all_to_session`, `apply_to_session`, `belongs_to`, `format`, `is_expired`, `is_within`, `key_path`, `open_sealed`, `open`, `parse_netscape`, `parse`, `read_from_session`, `return_from_visit`, `root_path`, `seal`, `shows_domain`, `to_set_cookie`, `to_webdriver`, `unix_time`, `visit_domain`, `write_private`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AddCookie`, `CookieVault`, `SessionCookie`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `format_request`
+
+
+            
+
+            /// File formats cookies can be imported from and exported to
+enum CookieFormat {
+                    /// The tab-separated `cookies.txt` format written by curl and browser extensions.
+netscape,
+/// A JSON array of cookie objects, as exported by browser extensions.
+json,
+                    ;
+                    
+                }
+            
\ No newline at end of file
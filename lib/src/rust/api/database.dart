@@ -4,35 +4,122 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'fetcher.dart';
 import 'models.dart';
+import 'money.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `setup_database`
+
+            // These functions are ignored because they are not marked as `pub`: `cached_image_from_row`, `data_dir`, `get_cached_images`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DatabaseBackup`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Database>>
+                abstract class Database implements RustOpaqueInterface {
+                    /// Creates a backup of all data in JSON format
+ Future<void>  createBackup({required String backupPath });
+
+
+ Future<List<ProductRecord>>  getAllProductsWithHistory();
+
+
+/// Returns every stored encrypted cookie jar.
+ Future<List<SealedCookieJar>>  getAllSessionCookies();
+
+
+/// Looks up a cached image by its remote URL, regardless of which product owns it.
+ Future<CachedImage?>  getCachedImageByUrl({required String url });
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Database>>
-abstract class Database implements RustOpaqueInterface {
-  /// Creates a backup of all data in JSON format
-  Future<void> createBackup({required String backupPath});
 
-  Future<List<ProductRecord>> getAllProductsWithHistory();
+/// Aggregates recorded checks per site and fetcher.
+ Future<List<FetchPathStats>>  getFetchPathStats();
 
-  Future<ProductRecord?> getProductWithHistory({required String productId});
 
-  Future<void> insertPriceEntry({required ProductDetails details});
+/// Aggregates recorded fetch timings per site, fetcher and set of blocked resources,
+/// so runs with and without blocking can be compared.
+ Future<List<FetchTimingStats>>  getFetchTimingStats();
+
+
+ Future<ProductRecord?>  getProductWithHistory({required String productId });
+
+
+/// Returns the content hashes of every image still referenced by a product.
+ Future<Set<String>>  getReferencedImageHashes();
+
+
+ Future<void>  insertPriceEntry({required ProductDetails details });
+
 
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  /// Opens a connection to the SQLite database and sets up the tables.
-  static Future<Database> newInstance({required String dbPath}) =>
-      RustLib.instance.api.crateApiDatabaseDatabaseNew(dbPath: dbPath);
+/// Opens a connection to the SQLite database and migrates it to the current schema.
+/// Fails with [`migrations::SchemaTooNew`] if a newer version of the app wrote it.
+static Future<Database>  newInstance({required String dbPath })=>RustLib.instance.api.crateApiDatabaseDatabaseNew(dbPath: dbPath);
+
+
+/// Records which fetcher served a product check and whether it was a fallback.
+ Future<void>  recordFetchCheck({required String site , required String url , required FetcherKind fetcher , required bool fellBack });
+
+
+/// Records how long a successful page fetch took and which resources were blocked during it.
+ Future<void>  recordFetchTiming({required String site , required String url , required FetcherKind fetcher , required String blockedResources , required BigInt durationMs });
+
+
+ Future<void>  removeProduct({required String productId });
+
+
+/// Forgets the cached images of `product_id` whose URL is not in `current_urls`, so
+/// images the product no longer shows can be cleaned up. Returns how many were dropped.
+ Future<BigInt>  removeStaleCachedImages({required String productId , required List<String> currentUrls });
+
+
+/// Restores data from a JSON backup file
+ Future<void>  restoreFromBackup({required String backupPath , required bool replaceExisting });
+
+
+/// Stores the encrypted cookie jar of one domain, replacing any earlier one.
+ Future<void>  saveSessionCookies({required String domain , required List<int> nonce , required List<int> ciphertext });
+
+
+/// Records a locally cached copy of one of a product's images.
+ Future<void>  upsertCachedImage({required String productId , required BigInt position , required CachedImage image });
+
+
+/// Inserts or updates a product's static details.
+ Future<void>  upsertProduct({required ProductDetails details });
+
+
+
+                    
+                }
+                
+
+/// One domain's cookie jar as stored: encrypted, with the nonce needed to decrypt it
+class SealedCookieJar  {
+                final String domain;
+final Uint8List nonce;
+final Uint8List ciphertext;
+
+                const SealedCookieJar({required this.domain ,required this.nonce ,required this.ciphertext ,});
 
-  Future<void> removeProduct({required String productId});
+                
+                
 
-  /// Restores data from a JSON backup file
-  Future<void> restoreFromBackup(
-      {required String backupPath, required bool replaceExisting});
+                
+        @override
+        int get hashCode => domain.hashCode^nonce.hashCode^ciphertext.hashCode;
+        
 
-  /// Inserts or updates a product's static details.
-  Future<void> upsertProduct({required ProductDetails details});
-}
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SealedCookieJar &&
+                runtimeType == other.runtimeType
+                && domain == other.domain&& nonce == other.nonce&& ciphertext == other.ciphertext;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `capture_ids`, `list`, `new`, `open`, `prune`, `read_metadata`, `save`, `set_max_captures`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DiagnosticsStore`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            
+
+            /// What the engine saw when a fetch failed or returned a suspicious page
+class DiagnosticCapture  {
+                /// Folder name of the capture; sorts by creation time.
+final String id;
+final String url;
+final String fetcher;
+final String reason;
+final String timestamp;
+final String? htmlPath;
+final String? screenshotPath;
+
+                const DiagnosticCapture({required this.id ,required this.url ,required this.fetcher ,required this.reason ,required this.timestamp ,this.htmlPath ,this.screenshotPath ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^url.hashCode^fetcher.hashCode^reason.hashCode^timestamp.hashCode^htmlPath.hashCode^screenshotPath.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DiagnosticCapture &&
+                runtimeType == other.runtimeType
+                && id == other.id&& url == other.url&& fetcher == other.fetcher&& reason == other.reason&& timestamp == other.timestamp&& htmlPath == other.htmlPath&& screenshotPath == other.screenshotPath;
+        
+            }
+
+/// The saved files of one capture
+class DiagnosticCaptureContent  {
+                final DiagnosticCapture capture;
+final String? html;
+final Uint8List? screenshotPng;
+
+                const DiagnosticCaptureContent({required this.capture ,this.html ,this.screenshotPng ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => capture.hashCode^html.hashCode^screenshotPng.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DiagnosticCaptureContent &&
+                runtimeType == other.runtimeType
+                && capture == other.capture&& html == other.html&& screenshotPng == other.screenshotPng;
+        
+            }
+            
\ No newline at end of file
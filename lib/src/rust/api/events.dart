@@ -0,0 +1,33 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'events.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `emit`, `new`, `now_timestamp`, `subscribe`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `EventBus`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            
+
+            @freezed
+                sealed class EngineEvent with _$EngineEvent  {
+                    const EngineEvent._();
+
+                     /// A site served a captcha or access-denied page and its fetches are paused.
+const factory EngineEvent.siteBlocked({   required String domain ,  required String url ,  required String reason ,  required BigInt cooldownMs ,  required String timestamp , }) = EngineEvent_SiteBlocked;
+ /// The background watchdog saw the browser service go down or come back.
+const factory EngineEvent.serviceStatusChanged({   required bool isHealthy ,  required int port ,  required String message ,  required String timestamp , }) = EngineEvent_ServiceStatusChanged;
+ /// The watchdog used up its restart budget and leaves the browser service down.
+const factory EngineEvent.serviceRestartsExhausted({   required int restarts ,  required BigInt windowMs ,  required String timestamp , }) = EngineEvent_ServiceRestartsExhausted;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `classify_webdriver`, `classify`, `timed_out_or`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FetchError`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            /// Broad categories of fetch failures, used to decide how to recover
+enum FetchErrorKind {
+                    /// The driver process or session is gone; only a restart helps.
+driverDead,
+/// Navigation or page readiness took too long.
+navigationTimeout,
+/// The site answered with a 5xx status.
+httpServerError,
+/// The site answered with a 4xx status.
+httpClientError,
+/// The site served a captcha or access-denied page instead of the product.
+botWall,
+/// The page arrived but did not contain a usable product.
+parseFailure,
+/// Anything else, such as a transient network error.
+other,
+                    ;
+                    /// Whether trying the same request again may succeed.
+ Future<bool>  isRetryable()=>RustLib.instance.api.crateApiFetchErrorFetchErrorKindIsRetryable(that: this, );
+
+
+                }
+            
\ No newline at end of file
@@ -0,0 +1,28 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `add_cookies`, `as_str`, `build_client`, `client_for`, `new`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `HttpFetcher`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `fetch_page`
+// These functions have error during generation (see debug logs or enable `stop_on_error: true` for more details): `fetch_page`
+
+
+            
+
+            /// Which backend is used to fetch a site's pages
+enum FetcherKind {
+                    /// Drive a real browser through WebDriver; needed for pages rendered by JavaScript.
+browser,
+/// Plain HTTP requests; enough for server-rendered pages and needs no browser.
+http,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -7,7 +7,10 @@ import '../frb_generated.dart';
 import 'models.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-Future<ProductDetails> extractDetails(
-        {required String htmlSource, required String url}) =>
-    RustLib.instance.api
-        .crateApiFlipkartParserExtractDetails(htmlSource: htmlSource, url: url);
+
+            
+
+            Future<ProductDetails>  extractDetails({required String htmlSource , required String url }) => RustLib.instance.api.crateApiFlipkartParserExtractDetails(htmlSource: htmlSource, url: url);
+
+            
+            
\ No newline at end of file
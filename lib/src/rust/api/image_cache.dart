@@ -0,0 +1,41 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cache_image`, `cached_files`, `client_for`, `new`, `remove_unreferenced`, `set_timeouts`, `stats`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ImageCache`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            
+
+            /// Size of the on-disk image cache
+class ImageCacheStats  {
+                final int fileCount;
+final BigInt totalBytes;
+
+                const ImageCacheStats({required this.fileCount ,required this.totalBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => fileCount.hashCode^totalBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ImageCacheStats &&
+                runtimeType == other.runtimeType
+                && fileCount == other.fileCount&& totalBytes == other.totalBytes;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,19 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `backup_path_for`, `backup`, `cascade_deletes_and_index`, `create_base_schema`, `has_tables`, `migrate_prices_to_money`, `run`, `schema_version`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Migration`, `SchemaTooNew`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`
+
+
+            /// Schema version this build of the app creates and understands.
+Future<int>  latestVersion() => RustLib.instance.api.crateApiMigrationsLatestVersion();
+
+            
+            
\ No newline at end of file
@@ -5,128 +5,249 @@
 
 import '../frb_generated.dart';
 import '../lib.dart';
+import 'money.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`, `fmt`
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ProductDetails>>
-abstract class ProductDetails implements RustOpaqueInterface {
-  List<String> get features;
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
-  String get id;
 
-  List<String> get images;
+            
 
-  bool get inStock;
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ProductDetails>>
+                abstract class ProductDetails implements RustOpaqueInterface {
+                     List<String> get features;
 
-  int? get price;
 
-  String get rating;
+ String get id;
 
-  String? get seller;
 
-  String get site;
+ List<String> get images;
 
-  Value get specifications;
 
-  String get title;
+ bool get inStock;
+
+
+ Money? get price;
+
+
+ String get rating;
+
+
+ String? get seller;
+
+
+ String get site;
+
+
+ Value get specifications;
+
+
+ String get title;
+
+
+ String get url;
 
-  String get url;
 
   set features(List<String> features);
 
+
   set id(String id);
 
+
   set images(List<String> images);
 
+
   set inStock(bool inStock);
 
-  set price(int? price);
+
+  set price(Money? price);
+
 
   set rating(String rating);
 
+
   set seller(String? seller);
 
+
   set site(String site);
 
+
   set specifications(Value specifications);
 
+
   set title(String title);
 
+
   set url(String url);
-}
-
-class PriceEntry {
-  final int price;
-  final bool inStock;
-  final String timestamp;
-
-  const PriceEntry({
-    required this.price,
-    required this.inStock,
-    required this.timestamp,
-  });
-
-  @override
-  int get hashCode => price.hashCode ^ inStock.hashCode ^ timestamp.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is PriceEntry &&
-          runtimeType == other.runtimeType &&
-          price == other.price &&
-          inStock == other.inStock &&
-          timestamp == other.timestamp;
-}
-
-class ProductRecord {
-  final String id;
-  final String site;
-  final String url;
-  final String title;
-  final String? seller;
-  final List<String> images;
-  final String specifications;
-  final List<String> features;
-  final List<PriceEntry> priceHistory;
-
-  const ProductRecord({
-    required this.id,
-    required this.site,
-    required this.url,
-    required this.title,
-    this.seller,
-    required this.images,
-    required this.specifications,
-    required this.features,
-    required this.priceHistory,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      site.hashCode ^
-      url.hashCode ^
-      title.hashCode ^
-      seller.hashCode ^
-      images.hashCode ^
-      specifications.hashCode ^
-      features.hashCode ^
-      priceHistory.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is ProductRecord &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          site == other.site &&
-          url == other.url &&
-          title == other.title &&
-          seller == other.seller &&
-          images == other.images &&
-          specifications == other.specifications &&
-          features == other.features &&
-          priceHistory == other.priceHistory;
-}
+
+
+
+                    
+                }
+                
+
+class CachedImage  {
+                final String url;
+final String contentHash;
+final String localPath;
+final String thumbnailPath;
+
+                const CachedImage({required this.url ,required this.contentHash ,required this.localPath ,required this.thumbnailPath ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^contentHash.hashCode^localPath.hashCode^thumbnailPath.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CachedImage &&
+                runtimeType == other.runtimeType
+                && url == other.url&& contentHash == other.contentHash&& localPath == other.localPath&& thumbnailPath == other.thumbnailPath;
+        
+            }
+
+class FetchPathStats  {
+                final String site;
+final String fetcher;
+final int checks;
+final int fallbacks;
+final String lastUsed;
+
+                const FetchPathStats({required this.site ,required this.fetcher ,required this.checks ,required this.fallbacks ,required this.lastUsed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => site.hashCode^fetcher.hashCode^checks.hashCode^fallbacks.hashCode^lastUsed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FetchPathStats &&
+                runtimeType == other.runtimeType
+                && site == other.site&& fetcher == other.fetcher&& checks == other.checks&& fallbacks == other.fallbacks&& lastUsed == other.lastUsed;
+        
+            }
+
+class FetchTimingStats  {
+                final String site;
+final String fetcher;
+final String blockedResources;
+final int fetches;
+final BigInt avgDurationMs;
+final BigInt maxDurationMs;
+
+                const FetchTimingStats({required this.site ,required this.fetcher ,required this.blockedResources ,required this.fetches ,required this.avgDurationMs ,required this.maxDurationMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => site.hashCode^fetcher.hashCode^blockedResources.hashCode^fetches.hashCode^avgDurationMs.hashCode^maxDurationMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FetchTimingStats &&
+                runtimeType == other.runtimeType
+                && site == other.site&& fetcher == other.fetcher&& blockedResources == other.blockedResources&& fetches == other.fetches&& avgDurationMs == other.avgDurationMs&& maxDurationMs == other.maxDurationMs;
+        
+            }
+
+class PriceEntry  {
+                final Money price;
+final bool inStock;
+final String timestamp;
+
+                const PriceEntry({required this.price ,required this.inStock ,required this.timestamp ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => price.hashCode^inStock.hashCode^timestamp.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PriceEntry &&
+                runtimeType == other.runtimeType
+                && price == other.price&& inStock == other.inStock&& timestamp == other.timestamp;
+        
+            }
+
+class ProductRecord  {
+                final String id;
+final String site;
+final String url;
+final String title;
+final String? seller;
+final List<String> images;
+final String specifications;
+final List<String> features;
+final List<PriceEntry> priceHistory;
+final List<CachedImage> cachedImages;
+
+                const ProductRecord({required this.id ,required this.site ,required this.url ,required this.title ,this.seller ,required this.images ,required this.specifications ,required this.features ,required this.priceHistory ,required this.cachedImages ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^site.hashCode^url.hashCode^title.hashCode^seller.hashCode^images.hashCode^specifications.hashCode^features.hashCode^priceHistory.hashCode^cachedImages.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProductRecord &&
+                runtimeType == other.runtimeType
+                && id == other.id&& site == other.site&& url == other.url&& title == other.title&& seller == other.seller&& images == other.images&& specifications == other.specifications&& features == other.features&& priceHistory == other.priceHistory&& cachedImages == other.cachedImages;
+        
+            }
+
+class RefreshOutcome  {
+                final String url;
+final ProductRecord? product;
+final String? error;
+
+                const RefreshOutcome({required this.url ,this.product ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^product.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RefreshOutcome &&
+                runtimeType == other.runtimeType
+                && url == other.url&& product == other.product&& error == other.error;
+        
+            }
+            
\ No newline at end of file
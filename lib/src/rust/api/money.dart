@@ -0,0 +1,65 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `currency_symbol`, `detect_currency`, `group_digits`, `minor_digits`, `split_decimal`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `MoneyRepr`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `fmt`, `from`
+
+
+            
+
+            /// A monetary amount stored as an integer number of minor units (e.g. paise, cents)
+/// together with its ISO 4217 currency code.
+class Money  {
+                final PlatformInt64 minorUnits;
+final String currency;
+
+                const Money({required this.minorUnits ,required this.currency ,});
+
+                /// Returns the amount in major units, for display and charting.
+ double  asMajor()=>RustLib.instance.api.crateApiMoneyMoneyAsMajor(that: this, );
+
+
+/// The amount as the app shows it, e.g. `₹1,23,456.00`.
+ String  display()=>RustLib.instance.api.crateApiMoneyMoneyDisplay(that: this, );
+
+
+/// Creates an amount from whole major units (e.g. rupees).
+static Future<Money>  fromMajor({required PlatformInt64 major , required String currency })=>RustLib.instance.api.crateApiMoneyMoneyFromMajor(major: major, currency: currency);
+
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+/// Creates an amount from minor units.
+static Future<Money>  newInstance({required PlatformInt64 minorUnits , required String currency })=>RustLib.instance.api.crateApiMoneyMoneyNew(minorUnits: minorUnits, currency: currency);
+
+
+/// Parses a displayed price such as `₹1,23,456.00`, `$1,299.99` or `1.299,00 €`.
+///
+/// The currency is taken from the symbol or code in the text, falling back to
+/// `default_currency`. Returns `None` if the text contains no number.
+static Future<Money?>  parse({required String text , required String defaultCurrency })=>RustLib.instance.api.crateApiMoneyMoneyParse(text: text, defaultCurrency: defaultCurrency);
+
+
+                
+
+                
+        @override
+        int get hashCode => minorUnits.hashCode^currency.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Money &&
+                runtimeType == other.runtimeType
+                && minorUnits == other.minorUnits&& currency == other.currency;
+        
+            }
+            
\ No newline at end of file
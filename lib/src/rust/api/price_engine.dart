@@ -4,81 +4,249 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'binary_discovery.dart';
 import 'browser_service_manager.dart';
+import 'cookie_jar.dart';
+import 'diagnostics.dart';
+import 'fetcher.dart';
+import 'image_cache.dart';
 import 'models.dart';
+import 'money.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'proxy.dart';
+import 'rate_limiter.dart';
+import 'recycle_policy.dart';
+import 'resource_blocking.dart';
+import 'retry_policy.dart';
+import 'site_config.dart';
+import 'timeouts.dart';
+import 'watchdog.dart';
 
+
+            // These functions are ignored because they are not marked as `pub`: `apply_cookies`, `assemble`, `cache_product_images`, `capture_failure`, `fetch_details_with_fallback`, `fetch_details`, `fetch_page_once`, `fetch_page_source`, `is_incomplete`, `load_saved_cookies`, `parser_for`, `prefetch_with_browser_pool`, `record_fetch_timing`, `restore_saved_cookies`, `save_browser_cookies`, `save_cookies`, `should_capture`, `site_config_for`, `site_config_mut`, `store_product_details`, `subscribe_events`, `update_from_prefetched`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PriceEngine>>
-abstract class PriceEngine implements RustOpaqueInterface {
-  /// Checks the health status of the browser service
-  Future<PriceEngineStatus> checkServiceStatus();
 
-  /// Creates a backup of the database in JSON format
-  Future<void> createBackup({required String backupPath});
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PriceEngine>>
+                abstract class PriceEngine implements RustOpaqueInterface {
+                    /// Checks the health status of the browser service
+ Future<PriceEngineStatus>  checkServiceStatus();
+
+
+/// Deletes cached images no longer referenced by any product and returns what was freed.
+ Future<ImageCacheStats>  cleanupImageCache();
+
+
+/// Creates a backup of the database in JSON format
+ Future<void>  createBackup({required String backupPath });
+
+
+/// Exports the cookies the session currently holds for `domain`, saving them as well
+/// so changes made by the site (refreshed logins) are kept.
+ Future<String>  exportCookies({required String domain , required CookieFormat format });
+
+
+/// Fetches product details with automatic retry and fallback mechanisms
+ Future<ProductRecord>  fetchAndUpdateProduct({required String url });
+
+
+ Future<List<ProductRecord>>  getAllProductsInDb();
+
+
+/// Reports the browser and driver this engine runs, their versions and whether they match
+ Future<DiscoveryReport>  getBrowserBinaries();
+
+
+/// Gets the current port being used by the browser service
+ Future<int>  getCurrentPort();
+
+
+/// Returns the most recent diagnostic captures for a product, newest first.
+ Future<List<DiagnosticCapture>>  getDiagnosticCaptures({required String productId , required int limit });
+
+
+/// Returns the most recent diagnostic captures of `url`, or of every page when `None`,
+/// newest first. Unlike [`Self::get_diagnostic_captures`] this needs no product, so it
+/// also lists pages whose first fetch failed before they were ever saved.
+ Future<List<DiagnosticCapture>>  getDiagnosticCapturesForUrl({String? url , required int limit });
+
+
+/// Returns the newest `lines` lines of the browser driver's log, oldest first
+ Future<List<String>>  getDriverLogTail({required int lines });
+
 
-  /// Fetches product details with automatic retry and fallback mechanisms
-  Future<ProductRecord> fetchAndUpdateProduct({required String url});
+/// Returns how often each fetcher served each site, for tuning site settings.
+ Future<List<FetchPathStats>>  getFetchPathStats();
 
-  Future<List<ProductRecord>> getAllProductsInDb();
 
-  /// Gets the current port being used by the browser service
-  Future<int> getCurrentPort();
+ Future<FetchTimeouts>  getFetchTimeouts();
+
+
+/// Returns how long successful fetches took, per site, fetcher and set of blocked
+/// resources, to compare page loads with and without resource blocking.
+ Future<List<FetchTimingStats>>  getFetchTimingStats();
+
+
+/// Returns the size of the local image cache.
+ Future<ImageCacheStats>  getImageCacheStats();
+
+
+/// Returns the health of the configured proxies.
+ Future<List<ProxyStatus>>  getProxyStatus();
+
+
+/// Returns the per-site settings currently in use.
+ Future<List<SiteConfig>>  getSiteConfigs();
+
+
+/// Imports cookies for `domain` from a cookies.txt or JSON export, e.g. to scrape
+/// while signed in. They are loaded into the browser and HTTP sessions and stored
+/// encrypted so they survive restarts. Returns how many cookies were imported.
+ Future<int>  importCookies({required String domain , required String content , required CookieFormat format });
+
 
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  static Future<PriceEngine> newInstance(
-          {required int port,
-          required String browserPath,
-          required String dbPath,
-          required String driverPath}) =>
-      RustLib.instance.api.crateApiPriceEnginePriceEngineNew(
-          port: port,
-          browserPath: browserPath,
-          dbPath: dbPath,
-          driverPath: driverPath);
-
-  /// Removes a product from the database by its ID.
-  Future<void> removeProductById({required String productId});
-
-  /// Restarts the browser service if needed
-  Future<ServiceStatus> restartBrowserService();
-
-  /// Restores database from a JSON backup file
-  Future<void> restoreFromBackup(
-      {required String backupPath, required bool replaceExisting});
-
-  /// Shuts down the browser engine gracefully.
-  Future<void> shutdown();
-}
-
-class PriceEngineStatus {
-  final bool isHealthy;
-  final int currentPort;
-  final String message;
-  final String lastCheck;
-
-  const PriceEngineStatus({
-    required this.isHealthy,
-    required this.currentPort,
-    required this.message,
-    required this.lastCheck,
-  });
-
-  @override
-  int get hashCode =>
-      isHealthy.hashCode ^
-      currentPort.hashCode ^
-      message.hashCode ^
-      lastCheck.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is PriceEngineStatus &&
-          runtimeType == other.runtimeType &&
-          isHealthy == other.isHealthy &&
-          currentPort == other.currentPort &&
-          message == other.message &&
-          lastCheck == other.lastCheck;
-}
+static Future<PriceEngine>  newInstance({required int port , required String browserPath , required String dbPath , required String driverPath })=>RustLib.instance.api.crateApiPriceEnginePriceEngineNew(port: port, browserPath: browserPath, dbPath: dbPath, driverPath: driverPath);
+
+
+/// Creates an engine that never starts a browser; every site is fetched over plain HTTP.
+static Future<PriceEngine>  newHttpOnly({required String dbPath })=>RustLib.instance.api.crateApiPriceEnginePriceEngineNewHttpOnly(dbPath: dbPath);
+
+
+/// Creates an engine that drives browsers through an already running WebDriver server,
+/// such as a Selenium Grid at `http://host:4444`, instead of starting its own driver.
+static Future<PriceEngine>  newRemote({required String webdriverUrl , required BrowserKind browserKind , required BrowserOptions options , required String dbPath })=>RustLib.instance.api.crateApiPriceEnginePriceEngineNewRemote(webdriverUrl: webdriverUrl, browserKind: browserKind, options: options, dbPath: dbPath);
+
+
+/// Creates an engine for an explicitly chosen browser instead of guessing it from the driver name.
+static Future<PriceEngine>  newWithBrowserKind({required int port , required BrowserKind browserKind , required String browserPath , required String dbPath , required String driverPath })=>RustLib.instance.api.crateApiPriceEnginePriceEngineNewWithBrowserKind(port: port, browserKind: browserKind, browserPath: browserPath, dbPath: dbPath, driverPath: driverPath);
+
+
+/// Creates an engine whose browser is launched with `options` (profile directory,
+/// extra arguments, window size, language, user agent and headless mode).
+static Future<PriceEngine>  newWithOptions({required int port , required BrowserKind browserKind , required BrowserOptions options , required String browserPath , required String dbPath , required String driverPath })=>RustLib.instance.api.crateApiPriceEnginePriceEngineNewWithOptions(port: port, browserKind: browserKind, options: options, browserPath: browserPath, dbPath: dbPath, driverPath: driverPath);
+
+
+/// Loads the saved page and screenshot of a diagnostic capture.
+ Future<DiagnosticCaptureContent>  openDiagnosticCapture({required String captureId });
+
+
+/// Re-checks every tracked product. Browser-served pages are loaded in parallel
+/// through the session pool; anything that fails there, or is served over HTTP,
+/// goes through the regular single-product path with its retries and fallback.
+ Future<List<RefreshOutcome>>  refreshAllProducts();
+
+
+/// Removes a product from the database by its ID.
+ Future<void>  removeProductById({required String productId });
+
+
+/// Restarts the browser service if needed
+ Future<ServiceStatus>  restartBrowserService();
+
+
+/// Restores database from a JSON backup file
+ Future<void>  restoreFromBackup({required String backupPath , required bool replaceExisting });
+
+
+/// Sets how many browser sessions a refresh-all may run in parallel
+ Future<void>  setBrowserPoolSize({required int poolSize });
+
+
+/// Sets after how many page loads, how long, or how much memory the browser session is replaced
+ Future<void>  setBrowserRecyclePolicy({required RecyclePolicy policy });
+
+
+/// Sets the proxies used by every site without a proxy list of its own; empty means direct.
+ Future<void>  setDefaultProxies({required List<String> proxies });
+
+
+/// Sets how many diagnostic captures are kept; the oldest are deleted first.
+ Future<void>  setDiagnosticsRetention({required int maxCaptures });
+
+
+/// Sets how long page loads, scripts and whole fetches may take before they fail
+/// as timed out. Open browser sessions are updated immediately.
+ Future<void>  setFetchTimeouts({required FetchTimeouts timeouts });
+
+
+/// Lets `domain`'s pages load the given resource types even though the browser blocks them.
+ Future<void>  setSiteAllowedResources({required String domain , required List<ResourceType> allowed });
+
+
+/// Sets how long fetches from `domain` pause after it served a captcha or access-denied page.
+ Future<void>  setSiteBlockCooldown({required String domain , required int cooldownMs });
+
+
+/// Enables or disables falling back to the other fetcher for `domain`.
+ Future<void>  setSiteFallback({required String domain , required bool enabled });
+
+
+/// Chooses which fetcher is used for `domain`, adding the site if it is not configured yet.
+ Future<void>  setSiteFetcher({required String domain , required FetcherKind fetcher });
+
+
+/// Assigns `domain` its own rotation of proxies; empty falls back to the engine-wide proxies.
+ Future<void>  setSiteProxies({required String domain , required List<String> proxies });
+
+
+/// Sets the request throttling for `domain`.
+ Future<void>  setSiteRateLimit({required String domain , required RateLimit rateLimit });
+
+
+/// Sets what the browser waits for on `domain` pages and for how long.
+ Future<void>  setSiteReadyCondition({required String domain , required ReadyCondition condition , required int timeoutMs });
+
+
+/// Sets how failed fetches of `domain` pages are retried.
+ Future<void>  setSiteRetryPolicy({required String domain , required RetryPolicy retryPolicy });
+
+
+/// Shuts down the browser engine gracefully.
+ Future<void>  shutdown();
+
+
+/// Starts probing the browser service in the background, restarting it when it is
+/// unhealthy and emitting status changes as engine events. Replaces a running watchdog.
+ Future<void>  startServiceWatchdog({required WatchdogConfig config });
+
+
+/// Stops the background service watchdog, if one is running.
+ Future<void>  stopServiceWatchdog();
+
+
+
+                    
+                }
+                
+
+class PriceEngineStatus  {
+                final bool isHealthy;
+final int currentPort;
+final String message;
+final String lastCheck;
+final List<DomainRateStatus> rateLimits;
+
+                const PriceEngineStatus({required this.isHealthy ,required this.currentPort ,required this.message ,required this.lastCheck ,required this.rateLimits ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => isHealthy.hashCode^currentPort.hashCode^message.hashCode^lastCheck.hashCode^rateLimits.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PriceEngineStatus &&
+                runtimeType == other.runtimeType
+                && isHealthy == other.isHealthy&& currentPort == other.currentPort&& message == other.message&& lastCheck == other.lastCheck&& rateLimits == other.rateLimits;
+        
+            }
+            
\ No newline at end of file
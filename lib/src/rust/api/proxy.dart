@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `current`, `is_proxy_failure`, `mark_failed`, `mark_ok`, `new`, `next`, `pick`, `record`, `set_default_proxies`, `status`, `validate_all`, `webdriver_proxy`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ProxyHealth`, `ProxyRotator`, `RotatorState`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`, `default`
+
+
+            
+
+            /// Health of one configured proxy, for the status API
+class ProxyStatus  {
+                final String url;
+final bool healthy;
+/// Failures since the proxy last worked.
+final int consecutiveFailures;
+/// Time until an unhealthy proxy is tried again, in milliseconds.
+final BigInt retryInMs;
+
+                const ProxyStatus({required this.url ,required this.healthy ,required this.consecutiveFailures ,required this.retryInMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => url.hashCode^healthy.hashCode^consecutiveFailures.hashCode^retryInMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProxyStatus &&
+                runtimeType == other.runtimeType
+                && url == other.url&& healthy == other.healthy&& consecutiveFailures == other.consecutiveFailures&& retryInMs == other.retryInMs;
+        
+            }
+            
\ No newline at end of file
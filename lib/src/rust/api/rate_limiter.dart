@@ -0,0 +1,80 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `acquire`, `cooldown_remaining`, `new`, `new`, `new`, `reserve`, `start_cooldown`, `status`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DomainState`, `RateLimiter`, `WaitingGuard`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `drop`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            
+
+            /// Current throttling state of one domain, for the status API
+class DomainRateStatus  {
+                final String domain;
+/// Requests currently sleeping until their slot.
+final int waiting;
+/// Time until the next request could start, in milliseconds.
+final BigInt nextSlotInMs;
+/// Time left before fetches resume after the site blocked us, in milliseconds.
+final BigInt cooldownRemainingMs;
+
+                const DomainRateStatus({required this.domain ,required this.waiting ,required this.nextSlotInMs ,required this.cooldownRemainingMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => domain.hashCode^waiting.hashCode^nextSlotInMs.hashCode^cooldownRemainingMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DomainRateStatus &&
+                runtimeType == other.runtimeType
+                && domain == other.domain&& waiting == other.waiting&& nextSlotInMs == other.nextSlotInMs&& cooldownRemainingMs == other.cooldownRemainingMs;
+        
+            }
+
+/// Politeness settings for requests to one site
+class RateLimit  {
+                /// Sustained request rate the token bucket refills at.
+final int requestsPerMinute;
+/// How many requests may go out back-to-back after an idle period.
+final int burst;
+/// Minimum gap between the starts of two requests.
+final int minSpacingMs;
+/// Up to this much random delay is added to every request.
+final int jitterMs;
+
+                const RateLimit({required this.requestsPerMinute ,required this.burst ,required this.minSpacingMs ,required this.jitterMs ,});
+
+                static Future<RateLimit>  default_()=>RustLib.instance.api.crateApiRateLimiterRateLimitDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => requestsPerMinute.hashCode^burst.hashCode^minSpacingMs.hashCode^jitterMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RateLimit &&
+                runtimeType == other.runtimeType
+                && requestsPerMinute == other.requestsPerMinute&& burst == other.burst&& minSpacingMs == other.minSpacingMs&& jitterMs == other.jitterMs;
+        
+            }
+            
\ No newline at end of file
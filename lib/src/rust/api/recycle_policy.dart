@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `new`, `recycle_reason`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SessionUsage`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            /// When the browser session is replaced by a fresh one, to shed the memory and
+/// state long-lived sessions accumulate. A limit of 0 disables that check.
+class RecyclePolicy  {
+                /// Page loads after which the session is replaced.
+final int maxNavigations;
+/// Age after which the session is replaced, in milliseconds.
+final BigInt maxSessionAgeMs;
+/// Resident memory of the driver and browser processes above which the session is
+/// replaced. Memory can only be read on Linux; elsewhere this limit never triggers.
+final int maxMemoryMb;
+
+                const RecyclePolicy({required this.maxNavigations ,required this.maxSessionAgeMs ,required this.maxMemoryMb ,});
+
+                static Future<RecyclePolicy>  default_()=>RustLib.instance.api.crateApiRecyclePolicyRecyclePolicyDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxNavigations.hashCode^maxSessionAgeMs.hashCode^maxMemoryMb.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RecyclePolicy &&
+                runtimeType == other.runtimeType
+                && maxNavigations == other.maxNavigations&& maxSessionAgeMs == other.maxSessionAgeMs&& maxMemoryMb == other.maxMemoryMb;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,24 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply`, `as_str`, `describe`, `effective`, `extensions`, `url_patterns`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            
+
+            /// Kinds of page resources the browser can skip downloading
+enum ResourceType {
+                    image,
+font,
+media,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,48 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `backoff`, `should_retry`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            
+
+            /// How often and how patiently a failed fetch is retried
+class RetryPolicy  {
+                /// Total attempts, including the first one.
+final int maxAttempts;
+/// Delay before the first retry.
+final int initialBackoffMs;
+/// Upper bound for any single delay.
+final int maxBackoffMs;
+/// Factor the delay grows by after every retry.
+final double backoffMultiplier;
+
+                const RetryPolicy({required this.maxAttempts ,required this.initialBackoffMs ,required this.maxBackoffMs ,required this.backoffMultiplier ,});
+
+                static Future<RetryPolicy>  default_()=>RustLib.instance.api.crateApiRetryPolicyRetryPolicyDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxAttempts.hashCode^initialBackoffMs.hashCode^maxBackoffMs.hashCode^backoffMultiplier.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RetryPolicy &&
+                runtimeType == other.runtimeType
+                && maxAttempts == other.maxAttempts&& initialBackoffMs == other.initialBackoffMs&& maxBackoffMs == other.maxBackoffMs&& backoffMultiplier == other.backoffMultiplier;
+        
+            }
+            
\ No newline at end of file
@@ -5,76 +5,111 @@
 
 import '../frb_generated.dart';
 import 'browser_service_manager.dart';
+import 'fetcher.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'recycle_policy.dart';
+import 'timeouts.dart';
 
-// These functions are ignored because they are not marked as `pub`: `try_get_page_source`
+
+            // These functions are ignored because they are not marked as `pub`: `blocked_resources_for`, `capture_current_page`, `document_complete`, `driver_log_tail`, `fetch_pages_concurrently`, `get_page_source_when_ready`, `get_page_source_with_retry`, `get_site_cookies`, `load_page`, `open_session`, `recycle_session_if_due`, `restart_after_driver_failure`, `set_session`, `set_site_cookies`, `start_with_fallback`, `timed_out_wait`, `use_proxy`, `wait_until_ready`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `fetch_page`
 // These functions have error during generation (see debug logs or enable `stop_on_error: true` for more details): `shutdown`
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BrowserEngine>>
-abstract class BrowserEngine implements RustOpaqueInterface {
-  /// Checks the health of the browser service
-  Future<BrowserEngineStatus> checkServiceStatus();
 
-  /// Closes the currently active tab/window.
-  Future<void> closeCurrentTab();
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<BrowserEngine>>
+                abstract class BrowserEngine implements RustOpaqueInterface, Fetcher {
+                    /// Checks the health of the browser service
+ Future<BrowserEngineStatus>  checkServiceStatus();
+
+
+/// Closes the currently active tab/window.
+ Future<void>  closeCurrentTab();
+
+
+/// Gets the browser kind the engine drives
+ Future<BrowserKind>  getBrowserKind();
+
+
+/// Gets the current port being used
+ Future<int>  getCurrentPort();
+
 
-  /// Gets the current port being used
-  Future<int> getCurrentPort();
+/// Navigates to a URL and returns the page source with automatic retry
+ Future<String>  getPageSource({required String url });
+
+
+/// Gets the configured session pool size
+ Future<int>  getPoolSize();
+
+
+ Future<RecyclePolicy>  getRecyclePolicy();
+
+
+ Future<FetchTimeouts>  getTimeouts();
+
+
+/// Whether the engine uses an external WebDriver server rather than its own driver
+ Future<bool>  isRemote();
 
-  /// Navigates to a URL and returns the page source with automatic retry
-  Future<String> getPageSource({required String url});
 
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  /// Creates a new BrowserEngine with service management and fallback
-  static Future<BrowserEngine> newInstance(
-          {required int port,
-          required String browserPath,
-          required String driverPath}) =>
-      RustLib.instance.api.crateApiScraperEngineBrowserEngineNew(
-          port: port, browserPath: browserPath, driverPath: driverPath);
-
-  /// Creates a new BrowserEngine with automatic port management
-  static Future<(BrowserEngine, ServiceStatus)> newWithFallback(
-          {required int initialPort,
-          required String browserPath,
-          required String driverPath}) =>
-      RustLib.instance.api.crateApiScraperEngineBrowserEngineNewWithFallback(
-          initialPort: initialPort,
-          browserPath: browserPath,
-          driverPath: driverPath);
-
-  /// Restarts the browser service with fallback mechanisms
-  Future<ServiceStatus> restartWithFallback();
-}
-
-class BrowserEngineStatus {
-  final bool isRunning;
-  final int currentPort;
-  final String message;
-  final bool requiresRestart;
-
-  const BrowserEngineStatus({
-    required this.isRunning,
-    required this.currentPort,
-    required this.message,
-    required this.requiresRestart,
-  });
-
-  @override
-  int get hashCode =>
-      isRunning.hashCode ^
-      currentPort.hashCode ^
-      message.hashCode ^
-      requiresRestart.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is BrowserEngineStatus &&
-          runtimeType == other.runtimeType &&
-          isRunning == other.isRunning &&
-          currentPort == other.currentPort &&
-          message == other.message &&
-          requiresRestart == other.requiresRestart;
-}
+/// Creates a new BrowserEngine with service management and fallback
+static Future<BrowserEngine>  newInstance({required int port , required String browserPath , required String driverPath })=>RustLib.instance.api.crateApiScraperEngineBrowserEngineNew(port: port, browserPath: browserPath, driverPath: driverPath);
+
+
+/// Creates a new BrowserEngine with automatic port management
+static Future<(BrowserEngine,ServiceStatus)>  newWithFallback({required int initialPort , required String browserPath , required String driverPath })=>RustLib.instance.api.crateApiScraperEngineBrowserEngineNewWithFallback(initialPort: initialPort, browserPath: browserPath, driverPath: driverPath);
+
+
+/// Restarts the browser service with fallback mechanisms
+ Future<ServiceStatus>  restartWithFallback();
+
+
+/// Sets how many browser sessions `fetch_pages_concurrently` may use at once
+ Future<void>  setPoolSize({required int poolSize });
+
+
+/// Sets when sessions are replaced by fresh ones. Pooled sessions are held to the
+/// navigation and age limits; the memory limit is checked on the main session only.
+ Future<void>  setRecyclePolicy({required RecyclePolicy policy });
+
+
+/// Applies `timeouts` to the open sessions and to every session opened later
+ Future<void>  setTimeouts({required FetchTimeouts timeouts });
+
+
+
+                    
+                }
+                
+
+class BrowserEngineStatus  {
+                final bool isRunning;
+final int currentPort;
+final String message;
+final bool requiresRestart;
+
+                const BrowserEngineStatus({required this.isRunning ,required this.currentPort ,required this.message ,required this.requiresRestart ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => isRunning.hashCode^currentPort.hashCode^message.hashCode^requiresRestart.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BrowserEngineStatus &&
+                runtimeType == other.runtimeType
+                && isRunning == other.isRunning&& currentPort == other.currentPort&& message == other.message&& requiresRestart == other.requiresRestart;
+        
+            }
+            
\ No newline at end of file
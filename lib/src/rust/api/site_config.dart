@@ -0,0 +1,88 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'fetcher.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'rate_limiter.dart';
+import 'resource_blocking.dart';
+import 'retry_policy.dart';
+part 'site_config.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `default_site_configs`, `host_of`, `with_ready_selector`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            @freezed
+                sealed class ReadyCondition with _$ReadyCondition  {
+                    const ReadyCondition._();
+
+                     /// `document.readyState` is "complete".
+const factory ReadyCondition.documentComplete() = ReadyCondition_DocumentComplete;
+ /// At least one element matches the CSS selector.
+const factory ReadyCondition.elementPresent(  String field0,) = ReadyCondition_ElementPresent;
+ /// The document is complete and no new resources were requested for `quiet_ms`.
+const factory ReadyCondition.networkIdle({   required int quietMs , }) = ReadyCondition_NetworkIdle;
+ /// The JavaScript expression evaluates to a truthy value.
+const factory ReadyCondition.script(  String field0,) = ReadyCondition_Script;
+
+                    
+
+                    
+                }
+
+/// Scraping settings for one site, matched against a product URL's host
+class SiteConfig  {
+                final String domain;
+final FetcherKind fetcher;
+/// Retry through the other fetcher when the first one fails or returns an incomplete page.
+final bool fallback;
+/// When a browser-loaded page counts as ready to scrape.
+final ReadyCondition readyCondition;
+/// How long to wait for `ready_condition` before giving up.
+final int readyTimeoutMs;
+/// Throttling applied to every request to this site.
+final RateLimit rateLimit;
+/// How failed fetches of this site's pages are retried.
+final RetryPolicy retryPolicy;
+/// How long to stop fetching from this site after it served a bot wall.
+final int blockCooldownMs;
+/// Proxies this site is fetched through in rotation; empty uses the engine-wide proxies.
+final List<String> proxies;
+/// Resource types this site's pages need, loaded even when the browser blocks them.
+/// Not supported on Firefox, which blocks the same resources on every site.
+final List<ResourceType> allowedResources;
+
+                const SiteConfig({required this.domain ,required this.fetcher ,required this.fallback ,required this.readyCondition ,required this.readyTimeoutMs ,required this.rateLimit ,required this.retryPolicy ,required this.blockCooldownMs ,required this.proxies ,required this.allowedResources ,});
+
+                /// Whether `url` belongs to this site, including its subdomains.
+ Future<bool>  matches({required String url })=>RustLib.instance.api.crateApiSiteConfigSiteConfigMatches(that: this, url: url);
+
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+/// Creates settings for `domain` with the default (browser) fetcher and fallback enabled.
+static Future<SiteConfig>  newInstance({required String domain })=>RustLib.instance.api.crateApiSiteConfigSiteConfigNew(domain: domain);
+
+
+                
+
+                
+        @override
+        int get hashCode => domain.hashCode^fetcher.hashCode^fallback.hashCode^readyCondition.hashCode^readyTimeoutMs.hashCode^rateLimit.hashCode^retryPolicy.hashCode^blockCooldownMs.hashCode^proxies.hashCode^allowedResources.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SiteConfig &&
+                runtimeType == other.runtimeType
+                && domain == other.domain&& fetcher == other.fetcher&& fallback == other.fallback&& readyCondition == other.readyCondition&& readyTimeoutMs == other.readyTimeoutMs&& rateLimit == other.rateLimit&& retryPolicy == other.retryPolicy&& blockCooldownMs == other.blockCooldownMs&& proxies == other.proxies&& allowedResources == other.allowedResources;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,53 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `fetch_limit`, `limit_fetch`, `webdriver`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            
+
+            /// How long fetches may take before they fail with [`FetchError::TimedOut`].
+/// The browser limits apply to every WebDriver session the engine opens.
+class FetchTimeouts  {
+                /// Time a navigation may take before the browser gives up on the page.
+final BigInt pageLoadMs;
+/// Time a script run in the page, such as a ready check, may take.
+final BigInt scriptMs;
+/// Time element lookups keep retrying before reporting no match. Keep at 0 unless
+/// sites need it, since ready checks then wait this long on every miss.
+final BigInt implicitWaitMs;
+/// Limit for a whole fetch attempt, navigation and ready wait included; 0 disables it.
+/// Should exceed `page_load_ms` plus the sites' ready timeouts.
+final BigInt fetchMs;
+/// Time the driver may take to answer a health check before it counts as unhealthy.
+final BigInt statusCheckMs;
+
+                const FetchTimeouts({required this.pageLoadMs ,required this.scriptMs ,required this.implicitWaitMs ,required this.fetchMs ,required this.statusCheckMs ,});
+
+                static Future<FetchTimeouts>  default_()=>RustLib.instance.api.crateApiTimeoutsFetchTimeoutsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => pageLoadMs.hashCode^scriptMs.hashCode^implicitWaitMs.hashCode^fetchMs.hashCode^statusCheckMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FetchTimeouts &&
+                runtimeType == other.runtimeType
+                && pageLoadMs == other.pageLoadMs&& scriptMs == other.scriptMs&& implicitWaitMs == other.implicitWaitMs&& fetchMs == other.fetchMs&& statusCheckMs == other.statusCheckMs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `check`, `new`, `report`, `spawn`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Watchdog`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            
+
+            /// How often the background watchdog probes the browser driver and how many
+/// restarts it may make before it stops trying, so a crashing driver is not
+/// restarted in a loop.
+class WatchdogConfig  {
+                /// Time between health probes, in milliseconds.
+final BigInt intervalMs;
+/// Restarts allowed within `restart_window_ms`. Once they are used up the driver is
+/// left down until the oldest restart leaves the window; 0 only reports status changes.
+final int maxRestarts;
+final BigInt restartWindowMs;
+
+                const WatchdogConfig({required this.intervalMs ,required this.maxRestarts ,required this.restartWindowMs ,});
+
+                static Future<WatchdogConfig>  default_()=>RustLib.instance.api.crateApiWatchdogWatchdogConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => intervalMs.hashCode^maxRestarts.hashCode^restartWindowMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WatchdogConfig &&
+                runtimeType == other.runtimeType
+                && intervalMs == other.intervalMs&& maxRestarts == other.maxRestarts&& restartWindowMs == other.restartWindowMs;
+        
+            }
+            
\ No newline at end of file
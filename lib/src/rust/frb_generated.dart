@@ -5,3892 +5,7379 @@
 
 import 'api/amazon_parser.dart';
 import 'api/apin.dart';
+import 'api/binary_discovery.dart';
 import 'api/browser_service_manager.dart';
+import 'api/cookie_jar.dart';
 import 'api/database.dart';
+import 'api/diagnostics.dart';
+import 'api/events.dart';
+import 'api/fetch_error.dart';
+import 'api/fetcher.dart';
 import 'api/flipkart_parser.dart';
+import 'api/image_cache.dart';
+import 'api/migrations.dart';
 import 'api/models.dart';
+import 'api/money.dart';
 import 'api/price_engine.dart';
+import 'api/proxy.dart';
+import 'api/rate_limiter.dart';
+import 'api/recycle_policy.dart';
+import 'api/resource_blocking.dart';
+import 'api/retry_policy.dart';
 import 'api/scraper_engine.dart';
+import 'api/site_config.dart';
+import 'api/timeouts.dart';
+import 'api/watchdog.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {}
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => -2049072296;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'rust_lib_palert',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<BrowserEngineStatus>
-      crateApiScraperEngineBrowserEngineCheckServiceStatus(
-          {required BrowserEngine that});
-
-  Future<void> crateApiScraperEngineBrowserEngineCloseCurrentTab(
-      {required BrowserEngine that});
-
-  Future<int> crateApiScraperEngineBrowserEngineGetCurrentPort(
-      {required BrowserEngine that});
-
-  Future<String> crateApiScraperEngineBrowserEngineGetPageSource(
-      {required BrowserEngine that, required String url});
-
-  Future<BrowserEngine> crateApiScraperEngineBrowserEngineNew(
-      {required int port,
-      required String browserPath,
-      required String driverPath});
-
-  Future<(BrowserEngine, ServiceStatus)>
-      crateApiScraperEngineBrowserEngineNewWithFallback(
-          {required int initialPort,
-          required String browserPath,
-          required String driverPath});
-
-  Future<ServiceStatus> crateApiScraperEngineBrowserEngineRestartWithFallback(
-      {required BrowserEngine that});
-
-  Future<ServiceStatus>
-      crateApiBrowserServiceManagerBrowserServiceManagerCheckServiceHealth(
-          {required BrowserServiceManager that});
-
-  Future<int>
-      crateApiBrowserServiceManagerBrowserServiceManagerFindAvailablePort(
-          {required BrowserServiceManager that});
-
-  Future<int> crateApiBrowserServiceManagerBrowserServiceManagerGetCurrentPort(
-      {required BrowserServiceManager that});
-
-  Future<bool>
-      crateApiBrowserServiceManagerBrowserServiceManagerIsPortAvailable(
-          {required int port});
 
-  Future<BrowserServiceManager>
-      crateApiBrowserServiceManagerBrowserServiceManagerNew(
-          {required int initialPort,
-          required String driverPath,
-          required String browserPath});
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  Future<ServiceStatus>
-      crateApiBrowserServiceManagerBrowserServiceManagerRestartService(
-          {required BrowserServiceManager that});
+                  RustLib._();
 
-  Future<void> crateApiBrowserServiceManagerBrowserServiceManagerSetPortRange(
-      {required BrowserServiceManager that,
-      required int minPort,
-      required int maxPort});
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
 
-  Future<ServiceStatus>
-      crateApiBrowserServiceManagerBrowserServiceManagerStartService(
-          {required BrowserServiceManager that});
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  Future<void> crateApiBrowserServiceManagerBrowserServiceManagerStopService(
-      {required BrowserServiceManager that});
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  Future<void> crateApiDatabaseDatabaseCreateBackup(
-      {required Database that, required String backupPath});
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  Future<List<ProductRecord>> crateApiDatabaseDatabaseGetAllProductsWithHistory(
-      {required Database that});
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  Future<ProductRecord?> crateApiDatabaseDatabaseGetProductWithHistory(
-      {required Database that, required String productId});
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
 
-  Future<void> crateApiDatabaseDatabaseInsertPriceEntry(
-      {required Database that, required ProductDetails details});
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  Future<Database> crateApiDatabaseDatabaseNew({required String dbPath});
+                  @override
+                  String get codegenVersion => '2.11.1';
 
-  Future<void> crateApiDatabaseDatabaseRemoveProduct(
-      {required Database that, required String productId});
+                  @override
+                  int get rustContentHash => -1949495547;
 
-  Future<void> crateApiDatabaseDatabaseRestoreFromBackup(
-      {required Database that,
-      required String backupPath,
-      required bool replaceExisting});
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_palert',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
 
-  Future<void> crateApiDatabaseDatabaseUpsertProduct(
-      {required Database that, required ProductDetails details});
+                abstract class RustLibApi extends BaseApi {
+                  Future<BrowserEngineStatus> crateApiScraperEngineBrowserEngineCheckServiceStatus({required BrowserEngine that });
 
-  Future<PriceEngineStatus> crateApiPriceEnginePriceEngineCheckServiceStatus(
-      {required PriceEngine that});
+Future<void> crateApiScraperEngineBrowserEngineCloseCurrentTab({required BrowserEngine that });
 
-  Future<void> crateApiPriceEnginePriceEngineCreateBackup(
-      {required PriceEngine that, required String backupPath});
+Future<BrowserKind> crateApiScraperEngineBrowserEngineGetBrowserKind({required BrowserEngine that });
 
-  Future<ProductRecord> crateApiPriceEnginePriceEngineFetchAndUpdateProduct(
-      {required PriceEngine that, required String url});
+Future<int> crateApiScraperEngineBrowserEngineGetCurrentPort({required BrowserEngine that });
 
-  Future<List<ProductRecord>> crateApiPriceEnginePriceEngineGetAllProductsInDb(
-      {required PriceEngine that});
+Future<String> crateApiScraperEngineBrowserEngineGetPageSource({required BrowserEngine that , required String url });
 
-  Future<int> crateApiPriceEnginePriceEngineGetCurrentPort(
-      {required PriceEngine that});
+Future<int> crateApiScraperEngineBrowserEngineGetPoolSize({required BrowserEngine that });
 
-  Future<PriceEngine> crateApiPriceEnginePriceEngineNew(
-      {required int port,
-      required String browserPath,
-      required String dbPath,
-      required String driverPath});
+Future<RecyclePolicy> crateApiScraperEngineBrowserEngineGetRecyclePolicy({required BrowserEngine that });
 
-  Future<void> crateApiPriceEnginePriceEngineRemoveProductById(
-      {required PriceEngine that, required String productId});
+Future<FetchTimeouts> crateApiScraperEngineBrowserEngineGetTimeouts({required BrowserEngine that });
 
-  Future<ServiceStatus> crateApiPriceEnginePriceEngineRestartBrowserService(
-      {required PriceEngine that});
+Future<bool> crateApiScraperEngineBrowserEngineIsRemote({required BrowserEngine that });
 
-  Future<void> crateApiPriceEnginePriceEngineRestoreFromBackup(
-      {required PriceEngine that,
-      required String backupPath,
-      required bool replaceExisting});
+Future<BrowserEngine> crateApiScraperEngineBrowserEngineNew({required int port , required String browserPath , required String driverPath });
 
-  Future<void> crateApiPriceEnginePriceEngineShutdown(
-      {required PriceEngine that});
+Future<(BrowserEngine,ServiceStatus)> crateApiScraperEngineBrowserEngineNewWithFallback({required int initialPort , required String browserPath , required String driverPath });
 
-  List<String> crateApiModelsProductDetailsAutoAccessorGetFeatures(
-      {required ProductDetails that});
+Future<ServiceStatus> crateApiScraperEngineBrowserEngineRestartWithFallback({required BrowserEngine that });
 
-  String crateApiModelsProductDetailsAutoAccessorGetId(
-      {required ProductDetails that});
+Future<void> crateApiScraperEngineBrowserEngineSetPoolSize({required BrowserEngine that , required int poolSize });
 
-  List<String> crateApiModelsProductDetailsAutoAccessorGetImages(
-      {required ProductDetails that});
+Future<void> crateApiScraperEngineBrowserEngineSetRecyclePolicy({required BrowserEngine that , required RecyclePolicy policy });
 
-  bool crateApiModelsProductDetailsAutoAccessorGetInStock(
-      {required ProductDetails that});
+Future<void> crateApiScraperEngineBrowserEngineSetTimeouts({required BrowserEngine that , required FetchTimeouts timeouts });
 
-  int? crateApiModelsProductDetailsAutoAccessorGetPrice(
-      {required ProductDetails that});
+Future<ServiceStatus> crateApiBrowserServiceManagerBrowserServiceManagerCheckServiceHealth({required BrowserServiceManager that });
 
-  String crateApiModelsProductDetailsAutoAccessorGetRating(
-      {required ProductDetails that});
+Future<int> crateApiBrowserServiceManagerBrowserServiceManagerFindAvailablePort({required BrowserServiceManager that });
 
-  String? crateApiModelsProductDetailsAutoAccessorGetSeller(
-      {required ProductDetails that});
+Future<BrowserKind> crateApiBrowserServiceManagerBrowserServiceManagerGetBrowserKind({required BrowserServiceManager that });
 
-  String crateApiModelsProductDetailsAutoAccessorGetSite(
-      {required ProductDetails that});
+Future<BrowserOptions> crateApiBrowserServiceManagerBrowserServiceManagerGetBrowserOptions({required BrowserServiceManager that });
 
-  Value crateApiModelsProductDetailsAutoAccessorGetSpecifications(
-      {required ProductDetails that});
+Future<int> crateApiBrowserServiceManagerBrowserServiceManagerGetCurrentPort({required BrowserServiceManager that });
 
-  String crateApiModelsProductDetailsAutoAccessorGetTitle(
-      {required ProductDetails that});
+Future<String?> crateApiBrowserServiceManagerBrowserServiceManagerGetProxy({required BrowserServiceManager that });
 
-  String crateApiModelsProductDetailsAutoAccessorGetUrl(
-      {required ProductDetails that});
+Future<FetchTimeouts> crateApiBrowserServiceManagerBrowserServiceManagerGetTimeouts({required BrowserServiceManager that });
 
-  void crateApiModelsProductDetailsAutoAccessorSetFeatures(
-      {required ProductDetails that, required List<String> features});
+Future<bool> crateApiBrowserServiceManagerBrowserServiceManagerIsPortAvailable({required int port });
 
-  void crateApiModelsProductDetailsAutoAccessorSetId(
-      {required ProductDetails that, required String id});
+Future<bool> crateApiBrowserServiceManagerBrowserServiceManagerIsRemote({required BrowserServiceManager that });
 
-  void crateApiModelsProductDetailsAutoAccessorSetImages(
-      {required ProductDetails that, required List<String> images});
+Future<BrowserServiceManager> crateApiBrowserServiceManagerBrowserServiceManagerNew({required int initialPort , required String driverPath , required String browserPath });
 
-  void crateApiModelsProductDetailsAutoAccessorSetInStock(
-      {required ProductDetails that, required bool inStock});
+Future<BrowserServiceManager> crateApiBrowserServiceManagerBrowserServiceManagerNewRemote({required String url , required BrowserKind browserKind });
 
-  void crateApiModelsProductDetailsAutoAccessorSetPrice(
-      {required ProductDetails that, int? price});
+Future<ServiceStatus> crateApiBrowserServiceManagerBrowserServiceManagerRestartService({required BrowserServiceManager that });
 
-  void crateApiModelsProductDetailsAutoAccessorSetRating(
-      {required ProductDetails that, required String rating});
+Future<void> crateApiBrowserServiceManagerBrowserServiceManagerSetBrowserKind({required BrowserServiceManager that , required BrowserKind browserKind });
 
-  void crateApiModelsProductDetailsAutoAccessorSetSeller(
-      {required ProductDetails that, String? seller});
+Future<void> crateApiBrowserServiceManagerBrowserServiceManagerSetBrowserOptions({required BrowserServiceManager that , required BrowserOptions options });
 
-  void crateApiModelsProductDetailsAutoAccessorSetSite(
-      {required ProductDetails that, required String site});
+Future<void> crateApiBrowserServiceManagerBrowserServiceManagerSetPortRange({required BrowserServiceManager that , required int minPort , required int maxPort });
 
-  void crateApiModelsProductDetailsAutoAccessorSetSpecifications(
-      {required ProductDetails that, required Value specifications});
+Future<void> crateApiBrowserServiceManagerBrowserServiceManagerSetProxy({required BrowserServiceManager that , String? proxy });
 
-  void crateApiModelsProductDetailsAutoAccessorSetTitle(
-      {required ProductDetails that, required String title});
+Future<void> crateApiBrowserServiceManagerBrowserServiceManagerSetTimeouts({required BrowserServiceManager that , required FetchTimeouts timeouts });
 
-  void crateApiModelsProductDetailsAutoAccessorSetUrl(
-      {required ProductDetails that, required String url});
+Future<ServiceStatus> crateApiBrowserServiceManagerBrowserServiceManagerStartService({required BrowserServiceManager that });
 
-  Future<PriceEngineStatus> crateApiApinCheckServiceStatus(
-      {required PriceEngine engine});
+Future<void> crateApiBrowserServiceManagerBrowserServiceManagerStopService({required BrowserServiceManager that });
 
-  Future<void> crateApiApinCreateBackup(
-      {required PriceEngine engine, required String backupPath});
+Future<void> crateApiDatabaseDatabaseCreateBackup({required Database that , required String backupPath });
 
-  Future<ProductDetails> crateApiAmazonParserExtractDetails(
-      {required String htmlSource, required String url});
+Future<List<ProductRecord>> crateApiDatabaseDatabaseGetAllProductsWithHistory({required Database that });
 
-  Future<ProductDetails> crateApiFlipkartParserExtractDetails(
-      {required String htmlSource, required String url});
+Future<List<SealedCookieJar>> crateApiDatabaseDatabaseGetAllSessionCookies({required Database that });
 
-  Future<ProductRecord> crateApiApinFetchAndUpdateProduct(
-      {required PriceEngine engine, required String url});
+Future<CachedImage?> crateApiDatabaseDatabaseGetCachedImageByUrl({required Database that , required String url });
 
-  Future<List<ProductRecord>> crateApiApinGetAllProductsInDb(
-      {required PriceEngine engine});
+Future<List<FetchPathStats>> crateApiDatabaseDatabaseGetFetchPathStats({required Database that });
 
-  Future<int> crateApiApinGetCurrentPort({required PriceEngine engine});
+Future<List<FetchTimingStats>> crateApiDatabaseDatabaseGetFetchTimingStats({required Database that });
 
-  Future<PriceEngine> crateApiApinGetPriceEngine(
-      {required int port,
-      required String browserPath,
-      required String dbPath,
-      required String driverPath});
+Future<ProductRecord?> crateApiDatabaseDatabaseGetProductWithHistory({required Database that , required String productId });
 
-  Future<void> crateApiApinRemoveProductById(
-      {required PriceEngine engine, required String productId});
+Future<Set<String>> crateApiDatabaseDatabaseGetReferencedImageHashes({required Database that });
 
-  Future<String> crateApiApinRestartBrowserService(
-      {required PriceEngine engine});
+Future<void> crateApiDatabaseDatabaseInsertPriceEntry({required Database that , required ProductDetails details });
 
-  Future<void> crateApiApinRestoreFromBackup(
-      {required PriceEngine engine,
-      required String backupPath,
-      required bool replaceExisting});
+Future<Database> crateApiDatabaseDatabaseNew({required String dbPath });
 
-  Future<void> crateApiApinShutdownPriceEngine({required PriceEngine engine});
+Future<void> crateApiDatabaseDatabaseRecordFetchCheck({required Database that , required String site , required String url , required FetcherKind fetcher , required bool fellBack });
 
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_BrowserEngine;
+Future<void> crateApiDatabaseDatabaseRecordFetchTiming({required Database that , required String site , required String url , required FetcherKind fetcher , required String blockedResources , required BigInt durationMs });
 
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_BrowserEngine;
+Future<void> crateApiDatabaseDatabaseRemoveProduct({required Database that , required String productId });
 
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_BrowserEnginePtr;
+Future<BigInt> crateApiDatabaseDatabaseRemoveStaleCachedImages({required Database that , required String productId , required List<String> currentUrls });
 
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_BrowserServiceManager;
+Future<void> crateApiDatabaseDatabaseRestoreFromBackup({required Database that , required String backupPath , required bool replaceExisting });
 
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_BrowserServiceManager;
+Future<void> crateApiDatabaseDatabaseSaveSessionCookies({required Database that , required String domain , required List<int> nonce , required List<int> ciphertext });
 
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_BrowserServiceManagerPtr;
+Future<void> crateApiDatabaseDatabaseUpsertCachedImage({required Database that , required String productId , required BigInt position , required CachedImage image });
 
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_Database;
+Future<void> crateApiDatabaseDatabaseUpsertProduct({required Database that , required ProductDetails details });
 
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_Database;
+Future<PriceEngineStatus> crateApiPriceEnginePriceEngineCheckServiceStatus({required PriceEngine that });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DatabasePtr;
+Future<ImageCacheStats> crateApiPriceEnginePriceEngineCleanupImageCache({required PriceEngine that });
 
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_PriceEngine;
+Future<void> crateApiPriceEnginePriceEngineCreateBackup({required PriceEngine that , required String backupPath });
 
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_PriceEngine;
+Future<String> crateApiPriceEnginePriceEngineExportCookies({required PriceEngine that , required String domain , required CookieFormat format });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PriceEnginePtr;
+Future<ProductRecord> crateApiPriceEnginePriceEngineFetchAndUpdateProduct({required PriceEngine that , required String url });
 
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_ProductDetails;
+Future<List<ProductRecord>> crateApiPriceEnginePriceEngineGetAllProductsInDb({required PriceEngine that });
 
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_ProductDetails;
+Future<DiscoveryReport> crateApiPriceEnginePriceEngineGetBrowserBinaries({required PriceEngine that });
 
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_ProductDetailsPtr;
+Future<int> crateApiPriceEnginePriceEngineGetCurrentPort({required PriceEngine that });
 
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Value;
+Future<List<DiagnosticCapture>> crateApiPriceEnginePriceEngineGetDiagnosticCaptures({required PriceEngine that , required String productId , required int limit });
 
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Value;
+Future<List<DiagnosticCapture>> crateApiPriceEnginePriceEngineGetDiagnosticCapturesForUrl({required PriceEngine that , String? url , required int limit });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr;
-}
+Future<List<String>> crateApiPriceEnginePriceEngineGetDriverLogTail({required PriceEngine that , required int lines });
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
+Future<List<FetchPathStats>> crateApiPriceEnginePriceEngineGetFetchPathStats({required PriceEngine that });
 
-  @override
-  Future<BrowserEngineStatus>
-      crateApiScraperEngineBrowserEngineCheckServiceStatus(
-          {required BrowserEngine that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 1, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_browser_engine_status,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiScraperEngineBrowserEngineCheckServiceStatusConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
+Future<FetchTimeouts> crateApiPriceEnginePriceEngineGetFetchTimeouts({required PriceEngine that });
 
-  TaskConstMeta
-      get kCrateApiScraperEngineBrowserEngineCheckServiceStatusConstMeta =>
-          const TaskConstMeta(
+Future<List<FetchTimingStats>> crateApiPriceEnginePriceEngineGetFetchTimingStats({required PriceEngine that });
+
+Future<ImageCacheStats> crateApiPriceEnginePriceEngineGetImageCacheStats({required PriceEngine that });
+
+Future<List<ProxyStatus>> crateApiPriceEnginePriceEngineGetProxyStatus({required PriceEngine that });
+
+Future<List<SiteConfig>> crateApiPriceEnginePriceEngineGetSiteConfigs({required PriceEngine that });
+
+Future<int> crateApiPriceEnginePriceEngineImportCookies({required PriceEngine that , required String domain , required String content , required CookieFormat format });
+
+Future<PriceEngine> crateApiPriceEnginePriceEngineNew({required int port , required String browserPath , required String dbPath , required String driverPath });
+
+Future<PriceEngine> crateApiPriceEnginePriceEngineNewHttpOnly({required String dbPath });
+
+Future<PriceEngine> crateApiPriceEnginePriceEngineNewRemote({required String webdriverUrl , required BrowserKind browserKind , required BrowserOptions options , required String dbPath });
+
+Future<PriceEngine> crateApiPriceEnginePriceEngineNewWithBrowserKind({required int port , required BrowserKind browserKind , required String browserPath , required String dbPath , required String driverPath });
+
+Future<PriceEngine> crateApiPriceEnginePriceEngineNewWithOptions({required int port , required BrowserKind browserKind , required BrowserOptions options , required String browserPath , required String dbPath , required String driverPath });
+
+Future<DiagnosticCaptureContent> crateApiPriceEnginePriceEngineOpenDiagnosticCapture({required PriceEngine that , required String captureId });
+
+Future<List<RefreshOutcome>> crateApiPriceEnginePriceEngineRefreshAllProducts({required PriceEngine that });
+
+Future<void> crateApiPriceEnginePriceEngineRemoveProductById({required PriceEngine that , required String productId });
+
+Future<ServiceStatus> crateApiPriceEnginePriceEngineRestartBrowserService({required PriceEngine that });
+
+Future<void> crateApiPriceEnginePriceEngineRestoreFromBackup({required PriceEngine that , required String backupPath , required bool replaceExisting });
+
+Future<void> crateApiPriceEnginePriceEngineSetBrowserPoolSize({required PriceEngine that , required int poolSize });
+
+Future<void> crateApiPriceEnginePriceEngineSetBrowserRecyclePolicy({required PriceEngine that , required RecyclePolicy policy });
+
+Future<void> crateApiPriceEnginePriceEngineSetDefaultProxies({required PriceEngine that , required List<String> proxies });
+
+Future<void> crateApiPriceEnginePriceEngineSetDiagnosticsRetention({required PriceEngine that , required int maxCaptures });
+
+Future<void> crateApiPriceEnginePriceEngineSetFetchTimeouts({required PriceEngine that , required FetchTimeouts timeouts });
+
+Future<void> crateApiPriceEnginePriceEngineSetSiteAllowedResources({required PriceEngine that , required String domain , required List<ResourceType> allowed });
+
+Future<void> crateApiPriceEnginePriceEngineSetSiteBlockCooldown({required PriceEngine that , required String domain , required int cooldownMs });
+
+Future<void> crateApiPriceEnginePriceEngineSetSiteFallback({required PriceEngine that , required String domain , required bool enabled });
+
+Future<void> crateApiPriceEnginePriceEngineSetSiteFetcher({required PriceEngine that , required String domain , required FetcherKind fetcher });
+
+Future<void> crateApiPriceEnginePriceEngineSetSiteProxies({required PriceEngine that , required String domain , required List<String> proxies });
+
+Future<void> crateApiPriceEnginePriceEngineSetSiteRateLimit({required PriceEngine that , required String domain , required RateLimit rateLimit });
+
+Future<void> crateApiPriceEnginePriceEngineSetSiteReadyCondition({required PriceEngine that , required String domain , required ReadyCondition condition , required int timeoutMs });
+
+Future<void> crateApiPriceEnginePriceEngineSetSiteRetryPolicy({required PriceEngine that , required String domain , required RetryPolicy retryPolicy });
+
+Future<void> crateApiPriceEnginePriceEngineShutdown({required PriceEngine that });
+
+Future<void> crateApiPriceEnginePriceEngineStartServiceWatchdog({required PriceEngine that , required WatchdogConfig config });
+
+Future<void> crateApiPriceEnginePriceEngineStopServiceWatchdog({required PriceEngine that });
+
+List<String> crateApiModelsProductDetailsAutoAccessorGetFeatures({required ProductDetails that });
+
+String crateApiModelsProductDetailsAutoAccessorGetId({required ProductDetails that });
+
+List<String> crateApiModelsProductDetailsAutoAccessorGetImages({required ProductDetails that });
+
+bool crateApiModelsProductDetailsAutoAccessorGetInStock({required ProductDetails that });
+
+Money? crateApiModelsProductDetailsAutoAccessorGetPrice({required ProductDetails that });
+
+String crateApiModelsProductDetailsAutoAccessorGetRating({required ProductDetails that });
+
+String? crateApiModelsProductDetailsAutoAccessorGetSeller({required ProductDetails that });
+
+String crateApiModelsProductDetailsAutoAccessorGetSite({required ProductDetails that });
+
+Value crateApiModelsProductDetailsAutoAccessorGetSpecifications({required ProductDetails that });
+
+String crateApiModelsProductDetailsAutoAccessorGetTitle({required ProductDetails that });
+
+String crateApiModelsProductDetailsAutoAccessorGetUrl({required ProductDetails that });
+
+void crateApiModelsProductDetailsAutoAccessorSetFeatures({required ProductDetails that , required List<String> features });
+
+void crateApiModelsProductDetailsAutoAccessorSetId({required ProductDetails that , required String id });
+
+void crateApiModelsProductDetailsAutoAccessorSetImages({required ProductDetails that , required List<String> images });
+
+void crateApiModelsProductDetailsAutoAccessorSetInStock({required ProductDetails that , required bool inStock });
+
+void crateApiModelsProductDetailsAutoAccessorSetPrice({required ProductDetails that , Money? price });
+
+void crateApiModelsProductDetailsAutoAccessorSetRating({required ProductDetails that , required String rating });
+
+void crateApiModelsProductDetailsAutoAccessorSetSeller({required ProductDetails that , String? seller });
+
+void crateApiModelsProductDetailsAutoAccessorSetSite({required ProductDetails that , required String site });
+
+void crateApiModelsProductDetailsAutoAccessorSetSpecifications({required ProductDetails that , required Value specifications });
+
+void crateApiModelsProductDetailsAutoAccessorSetTitle({required ProductDetails that , required String title });
+
+void crateApiModelsProductDetailsAutoAccessorSetUrl({required ProductDetails that , required String url });
+
+Future<BrowserKind> crateApiBrowserServiceManagerBrowserKindDetect({required String driverPath });
+
+Future<BrowserOptions> crateApiBrowserServiceManagerBrowserOptionsDefault();
+
+Future<DiscoveryReport> crateApiApinCheckBrowserBinaries({required BrowserKind browserKind , required String browserPath , required String driverPath });
+
+Future<PriceEngineStatus> crateApiApinCheckServiceStatus({required PriceEngine engine });
+
+Future<ImageCacheStats> crateApiApinCleanupImageCache({required PriceEngine engine });
+
+Future<void> crateApiApinCreateBackup({required PriceEngine engine , required String backupPath });
+
+Future<DiscoveryReport> crateApiBinaryDiscoveryDiscover({required BrowserKind kind });
+
+Future<DiscoveryReport> crateApiApinDiscoverBrowserBinaries({required BrowserKind browserKind });
+
+Future<String> crateApiApinExportCookies({required PriceEngine engine , required String domain , required CookieFormat format });
+
+Future<ProductDetails> crateApiAmazonParserExtractDetails({required String htmlSource , required String url });
+
+Future<ProductDetails> crateApiFlipkartParserExtractDetails({required String htmlSource , required String url });
+
+Future<ProductRecord> crateApiApinFetchAndUpdateProduct({required PriceEngine engine , required String url });
+
+Future<bool> crateApiFetchErrorFetchErrorKindIsRetryable({required FetchErrorKind that });
+
+Future<FetchTimeouts> crateApiTimeoutsFetchTimeoutsDefault();
+
+Future<List<ProductRecord>> crateApiApinGetAllProductsInDb({required PriceEngine engine });
+
+Future<DiscoveryReport> crateApiApinGetBrowserBinaries({required PriceEngine engine });
+
+Future<int> crateApiApinGetCurrentPort({required PriceEngine engine });
+
+Future<List<DiagnosticCapture>> crateApiApinGetDiagnosticCaptures({required PriceEngine engine , required String productId , required int limit });
+
+Future<List<DiagnosticCapture>> crateApiApinGetDiagnosticCapturesForUrl({required PriceEngine engine , String? url , required int limit });
+
+Future<List<String>> crateApiApinGetDriverLogTail({required PriceEngine engine , required int lines });
+
+Future<List<FetchPathStats>> crateApiApinGetFetchPathStats({required PriceEngine engine });
+
+Future<FetchTimeouts> crateApiApinGetFetchTimeouts({required PriceEngine engine });
+
+Future<List<FetchTimingStats>> crateApiApinGetFetchTimingStats({required PriceEngine engine });
+
+Future<PriceEngine> crateApiApinGetHttpOnlyPriceEngine({required String dbPath });
+
+Future<ImageCacheStats> crateApiApinGetImageCacheStats({required PriceEngine engine });
+
+Future<PriceEngine> crateApiApinGetPriceEngine({required int port , required String browserPath , required String dbPath , required String driverPath });
+
+Future<PriceEngine> crateApiApinGetPriceEngineForBrowser({required int port , required BrowserKind browserKind , required String browserPath , required String dbPath , required String driverPath });
+
+Future<PriceEngine> crateApiApinGetPriceEngineWithOptions({required int port , required BrowserKind browserKind , required BrowserOptions options , required String browserPath , required String dbPath , required String driverPath });
+
+Future<List<ProxyStatus>> crateApiApinGetProxyStatus({required PriceEngine engine });
+
+Future<PriceEngine> crateApiApinGetRemotePriceEngine({required String webdriverUrl , required BrowserKind browserKind , required BrowserOptions options , required String dbPath });
+
+Future<List<SiteConfig>> crateApiApinGetSiteConfigs({required PriceEngine engine });
+
+Future<int> crateApiApinImportCookies({required PriceEngine engine , required String domain , required String content , required CookieFormat format });
+
+Future<DiscoveryReport> crateApiBinaryDiscoveryInspect({required BrowserKind kind , required String browserPath , required String driverPath });
+
+Future<int> crateApiMigrationsLatestVersion();
+
+double crateApiMoneyMoneyAsMajor({required Money that });
+
+String crateApiMoneyMoneyDisplay({required Money that });
+
+Future<Money> crateApiMoneyMoneyFromMajor({required PlatformInt64 major , required String currency });
+
+Future<Money> crateApiMoneyMoneyNew({required PlatformInt64 minorUnits , required String currency });
+
+Future<Money?> crateApiMoneyMoneyParse({required String text , required String defaultCurrency });
+
+Future<DiagnosticCaptureContent> crateApiApinOpenDiagnosticCapture({required PriceEngine engine , required String captureId });
+
+Future<RateLimit> crateApiRateLimiterRateLimitDefault();
+
+Future<RecyclePolicy> crateApiRecyclePolicyRecyclePolicyDefault();
+
+Future<List<RefreshOutcome>> crateApiApinRefreshAllProducts({required PriceEngine engine });
+
+Future<void> crateApiApinRemoveProductById({required PriceEngine engine , required String productId });
+
+Future<String> crateApiApinRestartBrowserService({required PriceEngine engine });
+
+Future<void> crateApiApinRestoreFromBackup({required PriceEngine engine , required String backupPath , required bool replaceExisting });
+
+Future<RetryPolicy> crateApiRetryPolicyRetryPolicyDefault();
+
+Future<void> crateApiApinSetBrowserPoolSize({required PriceEngine engine , required int poolSize });
+
+Future<void> crateApiApinSetBrowserRecyclePolicy({required PriceEngine engine , required RecyclePolicy policy });
+
+Future<void> crateApiApinSetDefaultProxies({required PriceEngine engine , required List<String> proxies });
+
+Future<void> crateApiApinSetDiagnosticsRetention({required PriceEngine engine , required int maxCaptures });
+
+Future<void> crateApiApinSetFetchTimeouts({required PriceEngine engine , required FetchTimeouts timeouts });
+
+Future<void> crateApiApinSetSiteAllowedResources({required PriceEngine engine , required String domain , required List<ResourceType> allowed });
+
+Future<void> crateApiApinSetSiteBlockCooldown({required PriceEngine engine , required String domain , required int cooldownMs });
+
+Future<void> crateApiApinSetSiteFallback({required PriceEngine engine , required String domain , required bool enabled });
+
+Future<void> crateApiApinSetSiteFetcher({required PriceEngine engine , required String domain , required FetcherKind fetcher });
+
+Future<void> crateApiApinSetSiteProxies({required PriceEngine engine , required String domain , required List<String> proxies });
+
+Future<void> crateApiApinSetSiteRateLimit({required PriceEngine engine , required String domain , required RateLimit rateLimit });
+
+Future<void> crateApiApinSetSiteReadyCondition({required PriceEngine engine , required String domain , required ReadyCondition condition , required int timeoutMs });
+
+Future<void> crateApiApinSetSiteRetryPolicy({required PriceEngine engine , required String domain , required RetryPolicy retryPolicy });
+
+Future<void> crateApiApinShutdownPriceEngine({required PriceEngine engine });
+
+Future<bool> crateApiSiteConfigSiteConfigMatches({required SiteConfig that , required String url });
+
+Future<SiteConfig> crateApiSiteConfigSiteConfigNew({required String domain });
+
+Future<void> crateApiApinStartServiceWatchdog({required PriceEngine engine , required WatchdogConfig config });
+
+Future<void> crateApiApinStopServiceWatchdog({required PriceEngine engine });
+
+Stream<EngineEvent> crateApiApinSubscribeEngineEvents({required PriceEngine engine });
+
+Future<WatchdogConfig> crateApiWatchdogWatchdogConfigDefault();
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BrowserEngine;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_BrowserEngine;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_BrowserEnginePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BrowserServiceManager;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_BrowserServiceManager;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_BrowserServiceManagerPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Database;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Database;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DatabasePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PriceEngine;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PriceEngine;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PriceEnginePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ProductDetails;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ProductDetails;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ProductDetailsPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Value;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Value;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<BrowserEngineStatus> crateApiScraperEngineBrowserEngineCheckServiceStatus({required BrowserEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_browser_engine_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineCheckServiceStatusConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineCheckServiceStatusConstMeta => const TaskConstMeta(
             debugName: "BrowserEngine_check_service_status",
             argNames: ["that"],
-          );
-
-  @override
-  Future<void> crateApiScraperEngineBrowserEngineCloseCurrentTab(
-      {required BrowserEngine that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 2, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiScraperEngineBrowserEngineCloseCurrentTabConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiScraperEngineBrowserEngineCloseCurrentTabConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<void> crateApiScraperEngineBrowserEngineCloseCurrentTab({required BrowserEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineCloseCurrentTabConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineCloseCurrentTabConstMeta => const TaskConstMeta(
             debugName: "BrowserEngine_close_current_tab",
             argNames: ["that"],
-          );
-
-  @override
-  Future<int> crateApiScraperEngineBrowserEngineGetCurrentPort(
-      {required BrowserEngine that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 3, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_u_16,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiScraperEngineBrowserEngineGetCurrentPortConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiScraperEngineBrowserEngineGetCurrentPortConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<BrowserKind> crateApiScraperEngineBrowserEngineGetBrowserKind({required BrowserEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_browser_kind,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineGetBrowserKindConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineGetBrowserKindConstMeta => const TaskConstMeta(
+            debugName: "BrowserEngine_get_browser_kind",
+            argNames: ["that"],
+        );
+        
+
+@override Future<int> crateApiScraperEngineBrowserEngineGetCurrentPort({required BrowserEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_16,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineGetCurrentPortConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineGetCurrentPortConstMeta => const TaskConstMeta(
             debugName: "BrowserEngine_get_current_port",
             argNames: ["that"],
-          );
-
-  @override
-  Future<String> crateApiScraperEngineBrowserEngineGetPageSource(
-      {required BrowserEngine that, required String url}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-            that, serializer);
-        sse_encode_String(url, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiScraperEngineBrowserEngineGetPageSourceConstMeta,
-      argValues: [that, url],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiScraperEngineBrowserEngineGetPageSourceConstMeta =>
-      const TaskConstMeta(
-        debugName: "BrowserEngine_get_page_source",
-        argNames: ["that", "url"],
-      );
-
-  @override
-  Future<BrowserEngine> crateApiScraperEngineBrowserEngineNew(
-      {required int port,
-      required String browserPath,
-      required String driverPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_u_16(port, serializer);
-        sse_encode_String(browserPath, serializer);
-        sse_encode_String(driverPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 5, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiScraperEngineBrowserEngineNewConstMeta,
-      argValues: [port, browserPath, driverPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiScraperEngineBrowserEngineNewConstMeta =>
-      const TaskConstMeta(
-        debugName: "BrowserEngine_new",
-        argNames: ["port", "browserPath", "driverPath"],
-      );
-
-  @override
-  Future<(BrowserEngine, ServiceStatus)>
-      crateApiScraperEngineBrowserEngineNewWithFallback(
-          {required int initialPort,
-          required String browserPath,
-          required String driverPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_u_16(initialPort, serializer);
-        sse_encode_String(browserPath, serializer);
-        sse_encode_String(driverPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_browser_engine_service_status,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiScraperEngineBrowserEngineNewWithFallbackConstMeta,
-      argValues: [initialPort, browserPath, driverPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiScraperEngineBrowserEngineNewWithFallbackConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<String> crateApiScraperEngineBrowserEngineGetPageSource({required BrowserEngine that , required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(that, serializer);
+sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineGetPageSourceConstMeta,
+            argValues: [that, url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineGetPageSourceConstMeta => const TaskConstMeta(
+            debugName: "BrowserEngine_get_page_source",
+            argNames: ["that", "url"],
+        );
+        
+
+@override Future<int> crateApiScraperEngineBrowserEngineGetPoolSize({required BrowserEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineGetPoolSizeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineGetPoolSizeConstMeta => const TaskConstMeta(
+            debugName: "BrowserEngine_get_pool_size",
+            argNames: ["that"],
+        );
+        
+
+@override Future<RecyclePolicy> crateApiScraperEngineBrowserEngineGetRecyclePolicy({required BrowserEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_recycle_policy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineGetRecyclePolicyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineGetRecyclePolicyConstMeta => const TaskConstMeta(
+            debugName: "BrowserEngine_get_recycle_policy",
+            argNames: ["that"],
+        );
+        
+
+@override Future<FetchTimeouts> crateApiScraperEngineBrowserEngineGetTimeouts({required BrowserEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fetch_timeouts,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineGetTimeoutsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineGetTimeoutsConstMeta => const TaskConstMeta(
+            debugName: "BrowserEngine_get_timeouts",
+            argNames: ["that"],
+        );
+        
+
+@override Future<bool> crateApiScraperEngineBrowserEngineIsRemote({required BrowserEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineIsRemoteConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineIsRemoteConstMeta => const TaskConstMeta(
+            debugName: "BrowserEngine_is_remote",
+            argNames: ["that"],
+        );
+        
+
+@override Future<BrowserEngine> crateApiScraperEngineBrowserEngineNew({required int port , required String browserPath , required String driverPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_16(port, serializer);
+sse_encode_String(browserPath, serializer);
+sse_encode_String(driverPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineNewConstMeta,
+            argValues: [port, browserPath, driverPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineNewConstMeta => const TaskConstMeta(
+            debugName: "BrowserEngine_new",
+            argNames: ["port", "browserPath", "driverPath"],
+        );
+        
+
+@override Future<(BrowserEngine,ServiceStatus)> crateApiScraperEngineBrowserEngineNewWithFallback({required int initialPort , required String browserPath , required String driverPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_16(initialPort, serializer);
+sse_encode_String(browserPath, serializer);
+sse_encode_String(driverPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_browser_engine_service_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineNewWithFallbackConstMeta,
+            argValues: [initialPort, browserPath, driverPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineNewWithFallbackConstMeta => const TaskConstMeta(
             debugName: "BrowserEngine_new_with_fallback",
             argNames: ["initialPort", "browserPath", "driverPath"],
-          );
-
-  @override
-  Future<ServiceStatus> crateApiScraperEngineBrowserEngineRestartWithFallback(
-      {required BrowserEngine that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 7, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_service_status,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta:
-          kCrateApiScraperEngineBrowserEngineRestartWithFallbackConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiScraperEngineBrowserEngineRestartWithFallbackConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<ServiceStatus> crateApiScraperEngineBrowserEngineRestartWithFallback({required BrowserEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_service_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineRestartWithFallbackConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineRestartWithFallbackConstMeta => const TaskConstMeta(
             debugName: "BrowserEngine_restart_with_fallback",
             argNames: ["that"],
-          );
-
-  @override
-  Future<ServiceStatus>
-      crateApiBrowserServiceManagerBrowserServiceManagerCheckServiceHealth(
-          {required BrowserServiceManager that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 8, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_service_status,
-        decodeErrorData: null,
-      ),
-      constMeta:
-          kCrateApiBrowserServiceManagerBrowserServiceManagerCheckServiceHealthConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiBrowserServiceManagerBrowserServiceManagerCheckServiceHealthConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<void> crateApiScraperEngineBrowserEngineSetPoolSize({required BrowserEngine that , required int poolSize })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(that, serializer);
+sse_encode_u_32(poolSize, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineSetPoolSizeConstMeta,
+            argValues: [that, poolSize],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineSetPoolSizeConstMeta => const TaskConstMeta(
+            debugName: "BrowserEngine_set_pool_size",
+            argNames: ["that", "poolSize"],
+        );
+        
+
+@override Future<void> crateApiScraperEngineBrowserEngineSetRecyclePolicy({required BrowserEngine that , required RecyclePolicy policy })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(that, serializer);
+sse_encode_box_autoadd_recycle_policy(policy, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineSetRecyclePolicyConstMeta,
+            argValues: [that, policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineSetRecyclePolicyConstMeta => const TaskConstMeta(
+            debugName: "BrowserEngine_set_recycle_policy",
+            argNames: ["that", "policy"],
+        );
+        
+
+@override Future<void> crateApiScraperEngineBrowserEngineSetTimeouts({required BrowserEngine that , required FetchTimeouts timeouts })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(that, serializer);
+sse_encode_box_autoadd_fetch_timeouts(timeouts, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiScraperEngineBrowserEngineSetTimeoutsConstMeta,
+            argValues: [that, timeouts],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiScraperEngineBrowserEngineSetTimeoutsConstMeta => const TaskConstMeta(
+            debugName: "BrowserEngine_set_timeouts",
+            argNames: ["that", "timeouts"],
+        );
+        
+
+@override Future<ServiceStatus> crateApiBrowserServiceManagerBrowserServiceManagerCheckServiceHealth({required BrowserServiceManager that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_service_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerCheckServiceHealthConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerCheckServiceHealthConstMeta => const TaskConstMeta(
             debugName: "BrowserServiceManager_check_service_health",
             argNames: ["that"],
-          );
-
-  @override
-  Future<int>
-      crateApiBrowserServiceManagerBrowserServiceManagerFindAvailablePort(
-          {required BrowserServiceManager that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 9, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_u_16,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta:
-          kCrateApiBrowserServiceManagerBrowserServiceManagerFindAvailablePortConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiBrowserServiceManagerBrowserServiceManagerFindAvailablePortConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<int> crateApiBrowserServiceManagerBrowserServiceManagerFindAvailablePort({required BrowserServiceManager that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_16,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerFindAvailablePortConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerFindAvailablePortConstMeta => const TaskConstMeta(
             debugName: "BrowserServiceManager_find_available_port",
             argNames: ["that"],
-          );
-
-  @override
-  Future<int> crateApiBrowserServiceManagerBrowserServiceManagerGetCurrentPort(
-      {required BrowserServiceManager that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 10, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_u_16,
-        decodeErrorData: null,
-      ),
-      constMeta:
-          kCrateApiBrowserServiceManagerBrowserServiceManagerGetCurrentPortConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiBrowserServiceManagerBrowserServiceManagerGetCurrentPortConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<BrowserKind> crateApiBrowserServiceManagerBrowserServiceManagerGetBrowserKind({required BrowserServiceManager that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_browser_kind,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerGetBrowserKindConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerGetBrowserKindConstMeta => const TaskConstMeta(
+            debugName: "BrowserServiceManager_get_browser_kind",
+            argNames: ["that"],
+        );
+        
+
+@override Future<BrowserOptions> crateApiBrowserServiceManagerBrowserServiceManagerGetBrowserOptions({required BrowserServiceManager that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_browser_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerGetBrowserOptionsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerGetBrowserOptionsConstMeta => const TaskConstMeta(
+            debugName: "BrowserServiceManager_get_browser_options",
+            argNames: ["that"],
+        );
+        
+
+@override Future<int> crateApiBrowserServiceManagerBrowserServiceManagerGetCurrentPort({required BrowserServiceManager that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_16,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerGetCurrentPortConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerGetCurrentPortConstMeta => const TaskConstMeta(
             debugName: "BrowserServiceManager_get_current_port",
             argNames: ["that"],
-          );
-
-  @override
-  Future<bool>
-      crateApiBrowserServiceManagerBrowserServiceManagerIsPortAvailable(
-          {required int port}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_u_16(port, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 11, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
-      ),
-      constMeta:
-          kCrateApiBrowserServiceManagerBrowserServiceManagerIsPortAvailableConstMeta,
-      argValues: [port],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiBrowserServiceManagerBrowserServiceManagerIsPortAvailableConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<String?> crateApiBrowserServiceManagerBrowserServiceManagerGetProxy({required BrowserServiceManager that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerGetProxyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerGetProxyConstMeta => const TaskConstMeta(
+            debugName: "BrowserServiceManager_get_proxy",
+            argNames: ["that"],
+        );
+        
+
+@override Future<FetchTimeouts> crateApiBrowserServiceManagerBrowserServiceManagerGetTimeouts({required BrowserServiceManager that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fetch_timeouts,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerGetTimeoutsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerGetTimeoutsConstMeta => const TaskConstMeta(
+            debugName: "BrowserServiceManager_get_timeouts",
+            argNames: ["that"],
+        );
+        
+
+@override Future<bool> crateApiBrowserServiceManagerBrowserServiceManagerIsPortAvailable({required int port })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_16(port, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerIsPortAvailableConstMeta,
+            argValues: [port],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerIsPortAvailableConstMeta => const TaskConstMeta(
             debugName: "BrowserServiceManager_is_port_available",
             argNames: ["port"],
-          );
-
-  @override
-  Future<BrowserServiceManager>
-      crateApiBrowserServiceManagerBrowserServiceManagerNew(
-          {required int initialPort,
-          required String driverPath,
-          required String browserPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_u_16(initialPort, serializer);
-        sse_encode_String(driverPath, serializer);
-        sse_encode_String(browserPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 12, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager,
-        decodeErrorData: null,
-      ),
-      constMeta:
-          kCrateApiBrowserServiceManagerBrowserServiceManagerNewConstMeta,
-      argValues: [initialPort, driverPath, browserPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiBrowserServiceManagerBrowserServiceManagerNewConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<bool> crateApiBrowserServiceManagerBrowserServiceManagerIsRemote({required BrowserServiceManager that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerIsRemoteConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerIsRemoteConstMeta => const TaskConstMeta(
+            debugName: "BrowserServiceManager_is_remote",
+            argNames: ["that"],
+        );
+        
+
+@override Future<BrowserServiceManager> crateApiBrowserServiceManagerBrowserServiceManagerNew({required int initialPort , required String driverPath , required String browserPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_16(initialPort, serializer);
+sse_encode_String(driverPath, serializer);
+sse_encode_String(browserPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerNewConstMeta,
+            argValues: [initialPort, driverPath, browserPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerNewConstMeta => const TaskConstMeta(
             debugName: "BrowserServiceManager_new",
             argNames: ["initialPort", "driverPath", "browserPath"],
-          );
-
-  @override
-  Future<ServiceStatus>
-      crateApiBrowserServiceManagerBrowserServiceManagerRestartService(
-          {required BrowserServiceManager that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 13, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_service_status,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta:
-          kCrateApiBrowserServiceManagerBrowserServiceManagerRestartServiceConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiBrowserServiceManagerBrowserServiceManagerRestartServiceConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<BrowserServiceManager> crateApiBrowserServiceManagerBrowserServiceManagerNewRemote({required String url , required BrowserKind browserKind })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_browser_kind(browserKind, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerNewRemoteConstMeta,
+            argValues: [url, browserKind],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerNewRemoteConstMeta => const TaskConstMeta(
+            debugName: "BrowserServiceManager_new_remote",
+            argNames: ["url", "browserKind"],
+        );
+        
+
+@override Future<ServiceStatus> crateApiBrowserServiceManagerBrowserServiceManagerRestartService({required BrowserServiceManager that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_service_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerRestartServiceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerRestartServiceConstMeta => const TaskConstMeta(
             debugName: "BrowserServiceManager_restart_service",
             argNames: ["that"],
-          );
-
-  @override
-  Future<void> crateApiBrowserServiceManagerBrowserServiceManagerSetPortRange(
-      {required BrowserServiceManager that,
-      required int minPort,
-      required int maxPort}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-            that, serializer);
-        sse_encode_u_16(minPort, serializer);
-        sse_encode_u_16(maxPort, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 14, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta:
-          kCrateApiBrowserServiceManagerBrowserServiceManagerSetPortRangeConstMeta,
-      argValues: [that, minPort, maxPort],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiBrowserServiceManagerBrowserServiceManagerSetPortRangeConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<void> crateApiBrowserServiceManagerBrowserServiceManagerSetBrowserKind({required BrowserServiceManager that , required BrowserKind browserKind })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+sse_encode_browser_kind(browserKind, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerSetBrowserKindConstMeta,
+            argValues: [that, browserKind],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerSetBrowserKindConstMeta => const TaskConstMeta(
+            debugName: "BrowserServiceManager_set_browser_kind",
+            argNames: ["that", "browserKind"],
+        );
+        
+
+@override Future<void> crateApiBrowserServiceManagerBrowserServiceManagerSetBrowserOptions({required BrowserServiceManager that , required BrowserOptions options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+sse_encode_box_autoadd_browser_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerSetBrowserOptionsConstMeta,
+            argValues: [that, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerSetBrowserOptionsConstMeta => const TaskConstMeta(
+            debugName: "BrowserServiceManager_set_browser_options",
+            argNames: ["that", "options"],
+        );
+        
+
+@override Future<void> crateApiBrowserServiceManagerBrowserServiceManagerSetPortRange({required BrowserServiceManager that , required int minPort , required int maxPort })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+sse_encode_u_16(minPort, serializer);
+sse_encode_u_16(maxPort, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerSetPortRangeConstMeta,
+            argValues: [that, minPort, maxPort],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerSetPortRangeConstMeta => const TaskConstMeta(
             debugName: "BrowserServiceManager_set_port_range",
             argNames: ["that", "minPort", "maxPort"],
-          );
-
-  @override
-  Future<ServiceStatus>
-      crateApiBrowserServiceManagerBrowserServiceManagerStartService(
-          {required BrowserServiceManager that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 15, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_service_status,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta:
-          kCrateApiBrowserServiceManagerBrowserServiceManagerStartServiceConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiBrowserServiceManagerBrowserServiceManagerStartServiceConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<void> crateApiBrowserServiceManagerBrowserServiceManagerSetProxy({required BrowserServiceManager that , String? proxy })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+sse_encode_opt_String(proxy, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerSetProxyConstMeta,
+            argValues: [that, proxy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerSetProxyConstMeta => const TaskConstMeta(
+            debugName: "BrowserServiceManager_set_proxy",
+            argNames: ["that", "proxy"],
+        );
+        
+
+@override Future<void> crateApiBrowserServiceManagerBrowserServiceManagerSetTimeouts({required BrowserServiceManager that , required FetchTimeouts timeouts })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+sse_encode_box_autoadd_fetch_timeouts(timeouts, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerSetTimeoutsConstMeta,
+            argValues: [that, timeouts],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerSetTimeoutsConstMeta => const TaskConstMeta(
+            debugName: "BrowserServiceManager_set_timeouts",
+            argNames: ["that", "timeouts"],
+        );
+        
+
+@override Future<ServiceStatus> crateApiBrowserServiceManagerBrowserServiceManagerStartService({required BrowserServiceManager that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_service_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerStartServiceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerStartServiceConstMeta => const TaskConstMeta(
             debugName: "BrowserServiceManager_start_service",
             argNames: ["that"],
-          );
-
-  @override
-  Future<void> crateApiBrowserServiceManagerBrowserServiceManagerStopService(
-      {required BrowserServiceManager that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 16, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta:
-          kCrateApiBrowserServiceManagerBrowserServiceManagerStopServiceConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiBrowserServiceManagerBrowserServiceManagerStopServiceConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<void> crateApiBrowserServiceManagerBrowserServiceManagerStopService({required BrowserServiceManager that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserServiceManagerStopServiceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserServiceManagerStopServiceConstMeta => const TaskConstMeta(
             debugName: "BrowserServiceManager_stop_service",
             argNames: ["that"],
-          );
-
-  @override
-  Future<void> crateApiDatabaseDatabaseCreateBackup(
-      {required Database that, required String backupPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-            that, serializer);
-        sse_encode_String(backupPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 17, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiDatabaseDatabaseCreateBackupConstMeta,
-      argValues: [that, backupPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDatabaseDatabaseCreateBackupConstMeta =>
-      const TaskConstMeta(
-        debugName: "Database_create_backup",
-        argNames: ["that", "backupPath"],
-      );
-
-  @override
-  Future<List<ProductRecord>> crateApiDatabaseDatabaseGetAllProductsWithHistory(
-      {required Database that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 18, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_product_record,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiDatabaseDatabaseGetAllProductsWithHistoryConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiDatabaseDatabaseGetAllProductsWithHistoryConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<void> crateApiDatabaseDatabaseCreateBackup({required Database that , required String backupPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+sse_encode_String(backupPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseCreateBackupConstMeta,
+            argValues: [that, backupPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseCreateBackupConstMeta => const TaskConstMeta(
+            debugName: "Database_create_backup",
+            argNames: ["that", "backupPath"],
+        );
+        
+
+@override Future<List<ProductRecord>> crateApiDatabaseDatabaseGetAllProductsWithHistory({required Database that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_product_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseGetAllProductsWithHistoryConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseGetAllProductsWithHistoryConstMeta => const TaskConstMeta(
             debugName: "Database_get_all_products_with_history",
             argNames: ["that"],
-          );
-
-  @override
-  Future<ProductRecord?> crateApiDatabaseDatabaseGetProductWithHistory(
-      {required Database that, required String productId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-            that, serializer);
-        sse_encode_String(productId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 19, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_product_record,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiDatabaseDatabaseGetProductWithHistoryConstMeta,
-      argValues: [that, productId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDatabaseDatabaseGetProductWithHistoryConstMeta =>
-      const TaskConstMeta(
-        debugName: "Database_get_product_with_history",
-        argNames: ["that", "productId"],
-      );
-
-  @override
-  Future<void> crateApiDatabaseDatabaseInsertPriceEntry(
-      {required Database that, required ProductDetails details}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-            that, serializer);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            details, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 20, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiDatabaseDatabaseInsertPriceEntryConstMeta,
-      argValues: [that, details],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDatabaseDatabaseInsertPriceEntryConstMeta =>
-      const TaskConstMeta(
-        debugName: "Database_insert_price_entry",
-        argNames: ["that", "details"],
-      );
-
-  @override
-  Future<Database> crateApiDatabaseDatabaseNew({required String dbPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(dbPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 21, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiDatabaseDatabaseNewConstMeta,
-      argValues: [dbPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDatabaseDatabaseNewConstMeta =>
-      const TaskConstMeta(
-        debugName: "Database_new",
-        argNames: ["dbPath"],
-      );
-
-  @override
-  Future<void> crateApiDatabaseDatabaseRemoveProduct(
-      {required Database that, required String productId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-            that, serializer);
-        sse_encode_String(productId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 22, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiDatabaseDatabaseRemoveProductConstMeta,
-      argValues: [that, productId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDatabaseDatabaseRemoveProductConstMeta =>
-      const TaskConstMeta(
-        debugName: "Database_remove_product",
-        argNames: ["that", "productId"],
-      );
-
-  @override
-  Future<void> crateApiDatabaseDatabaseRestoreFromBackup(
-      {required Database that,
-      required String backupPath,
-      required bool replaceExisting}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-            that, serializer);
-        sse_encode_String(backupPath, serializer);
-        sse_encode_bool(replaceExisting, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 23, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiDatabaseDatabaseRestoreFromBackupConstMeta,
-      argValues: [that, backupPath, replaceExisting],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDatabaseDatabaseRestoreFromBackupConstMeta =>
-      const TaskConstMeta(
-        debugName: "Database_restore_from_backup",
-        argNames: ["that", "backupPath", "replaceExisting"],
-      );
-
-  @override
-  Future<void> crateApiDatabaseDatabaseUpsertProduct(
-      {required Database that, required ProductDetails details}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-            that, serializer);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            details, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 24, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiDatabaseDatabaseUpsertProductConstMeta,
-      argValues: [that, details],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiDatabaseDatabaseUpsertProductConstMeta =>
-      const TaskConstMeta(
-        debugName: "Database_upsert_product",
-        argNames: ["that", "details"],
-      );
-
-  @override
-  Future<PriceEngineStatus> crateApiPriceEnginePriceEngineCheckServiceStatus(
-      {required PriceEngine that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 25, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_price_engine_status,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiPriceEnginePriceEngineCheckServiceStatusConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiPriceEnginePriceEngineCheckServiceStatusConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<List<SealedCookieJar>> crateApiDatabaseDatabaseGetAllSessionCookies({required Database that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_sealed_cookie_jar,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseGetAllSessionCookiesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseGetAllSessionCookiesConstMeta => const TaskConstMeta(
+            debugName: "Database_get_all_session_cookies",
+            argNames: ["that"],
+        );
+        
+
+@override Future<CachedImage?> crateApiDatabaseDatabaseGetCachedImageByUrl({required Database that , required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_cached_image,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseGetCachedImageByUrlConstMeta,
+            argValues: [that, url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseGetCachedImageByUrlConstMeta => const TaskConstMeta(
+            debugName: "Database_get_cached_image_by_url",
+            argNames: ["that", "url"],
+        );
+        
+
+@override Future<List<FetchPathStats>> crateApiDatabaseDatabaseGetFetchPathStats({required Database that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_fetch_path_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseGetFetchPathStatsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseGetFetchPathStatsConstMeta => const TaskConstMeta(
+            debugName: "Database_get_fetch_path_stats",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<FetchTimingStats>> crateApiDatabaseDatabaseGetFetchTimingStats({required Database that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_fetch_timing_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseGetFetchTimingStatsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseGetFetchTimingStatsConstMeta => const TaskConstMeta(
+            debugName: "Database_get_fetch_timing_stats",
+            argNames: ["that"],
+        );
+        
+
+@override Future<ProductRecord?> crateApiDatabaseDatabaseGetProductWithHistory({required Database that , required String productId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+sse_encode_String(productId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_product_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseGetProductWithHistoryConstMeta,
+            argValues: [that, productId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseGetProductWithHistoryConstMeta => const TaskConstMeta(
+            debugName: "Database_get_product_with_history",
+            argNames: ["that", "productId"],
+        );
+        
+
+@override Future<Set<String>> crateApiDatabaseDatabaseGetReferencedImageHashes({required Database that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Set_String_None,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseGetReferencedImageHashesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseGetReferencedImageHashesConstMeta => const TaskConstMeta(
+            debugName: "Database_get_referenced_image_hashes",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiDatabaseDatabaseInsertPriceEntry({required Database that , required ProductDetails details })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(details, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseInsertPriceEntryConstMeta,
+            argValues: [that, details],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseInsertPriceEntryConstMeta => const TaskConstMeta(
+            debugName: "Database_insert_price_entry",
+            argNames: ["that", "details"],
+        );
+        
+
+@override Future<Database> crateApiDatabaseDatabaseNew({required String dbPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dbPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseNewConstMeta,
+            argValues: [dbPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseNewConstMeta => const TaskConstMeta(
+            debugName: "Database_new",
+            argNames: ["dbPath"],
+        );
+        
+
+@override Future<void> crateApiDatabaseDatabaseRecordFetchCheck({required Database that , required String site , required String url , required FetcherKind fetcher , required bool fellBack })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+sse_encode_String(site, serializer);
+sse_encode_String(url, serializer);
+sse_encode_fetcher_kind(fetcher, serializer);
+sse_encode_bool(fellBack, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseRecordFetchCheckConstMeta,
+            argValues: [that, site, url, fetcher, fellBack],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseRecordFetchCheckConstMeta => const TaskConstMeta(
+            debugName: "Database_record_fetch_check",
+            argNames: ["that", "site", "url", "fetcher", "fellBack"],
+        );
+        
+
+@override Future<void> crateApiDatabaseDatabaseRecordFetchTiming({required Database that , required String site , required String url , required FetcherKind fetcher , required String blockedResources , required BigInt durationMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+sse_encode_String(site, serializer);
+sse_encode_String(url, serializer);
+sse_encode_fetcher_kind(fetcher, serializer);
+sse_encode_String(blockedResources, serializer);
+sse_encode_u_64(durationMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseRecordFetchTimingConstMeta,
+            argValues: [that, site, url, fetcher, blockedResources, durationMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseRecordFetchTimingConstMeta => const TaskConstMeta(
+            debugName: "Database_record_fetch_timing",
+            argNames: ["that", "site", "url", "fetcher", "blockedResources", "durationMs"],
+        );
+        
+
+@override Future<void> crateApiDatabaseDatabaseRemoveProduct({required Database that , required String productId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+sse_encode_String(productId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseRemoveProductConstMeta,
+            argValues: [that, productId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseRemoveProductConstMeta => const TaskConstMeta(
+            debugName: "Database_remove_product",
+            argNames: ["that", "productId"],
+        );
+        
+
+@override Future<BigInt> crateApiDatabaseDatabaseRemoveStaleCachedImages({required Database that , required String productId , required List<String> currentUrls })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+sse_encode_String(productId, serializer);
+sse_encode_list_String(currentUrls, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseRemoveStaleCachedImagesConstMeta,
+            argValues: [that, productId, currentUrls],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseRemoveStaleCachedImagesConstMeta => const TaskConstMeta(
+            debugName: "Database_remove_stale_cached_images",
+            argNames: ["that", "productId", "currentUrls"],
+        );
+        
+
+@override Future<void> crateApiDatabaseDatabaseRestoreFromBackup({required Database that , required String backupPath , required bool replaceExisting })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+sse_encode_String(backupPath, serializer);
+sse_encode_bool(replaceExisting, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseRestoreFromBackupConstMeta,
+            argValues: [that, backupPath, replaceExisting],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseRestoreFromBackupConstMeta => const TaskConstMeta(
+            debugName: "Database_restore_from_backup",
+            argNames: ["that", "backupPath", "replaceExisting"],
+        );
+        
+
+@override Future<void> crateApiDatabaseDatabaseSaveSessionCookies({required Database that , required String domain , required List<int> nonce , required List<int> ciphertext })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_list_prim_u_8_loose(nonce, serializer);
+sse_encode_list_prim_u_8_loose(ciphertext, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseSaveSessionCookiesConstMeta,
+            argValues: [that, domain, nonce, ciphertext],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseSaveSessionCookiesConstMeta => const TaskConstMeta(
+            debugName: "Database_save_session_cookies",
+            argNames: ["that", "domain", "nonce", "ciphertext"],
+        );
+        
+
+@override Future<void> crateApiDatabaseDatabaseUpsertCachedImage({required Database that , required String productId , required BigInt position , required CachedImage image })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+sse_encode_String(productId, serializer);
+sse_encode_usize(position, serializer);
+sse_encode_box_autoadd_cached_image(image, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseUpsertCachedImageConstMeta,
+            argValues: [that, productId, position, image],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseUpsertCachedImageConstMeta => const TaskConstMeta(
+            debugName: "Database_upsert_cached_image",
+            argNames: ["that", "productId", "position", "image"],
+        );
+        
+
+@override Future<void> crateApiDatabaseDatabaseUpsertProduct({required Database that , required ProductDetails details })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(details, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiDatabaseDatabaseUpsertProductConstMeta,
+            argValues: [that, details],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDatabaseDatabaseUpsertProductConstMeta => const TaskConstMeta(
+            debugName: "Database_upsert_product",
+            argNames: ["that", "details"],
+        );
+        
+
+@override Future<PriceEngineStatus> crateApiPriceEnginePriceEngineCheckServiceStatus({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_price_engine_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineCheckServiceStatusConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineCheckServiceStatusConstMeta => const TaskConstMeta(
             debugName: "PriceEngine_check_service_status",
             argNames: ["that"],
-          );
-
-  @override
-  Future<void> crateApiPriceEnginePriceEngineCreateBackup(
-      {required PriceEngine that, required String backupPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            that, serializer);
-        sse_encode_String(backupPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 26, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiPriceEnginePriceEngineCreateBackupConstMeta,
-      argValues: [that, backupPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiPriceEnginePriceEngineCreateBackupConstMeta =>
-      const TaskConstMeta(
-        debugName: "PriceEngine_create_backup",
-        argNames: ["that", "backupPath"],
-      );
-
-  @override
-  Future<ProductRecord> crateApiPriceEnginePriceEngineFetchAndUpdateProduct(
-      {required PriceEngine that, required String url}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            that, serializer);
-        sse_encode_String(url, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 27, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_product_record,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiPriceEnginePriceEngineFetchAndUpdateProductConstMeta,
-      argValues: [that, url],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiPriceEnginePriceEngineFetchAndUpdateProductConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<ImageCacheStats> crateApiPriceEnginePriceEngineCleanupImageCache({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_image_cache_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineCleanupImageCacheConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineCleanupImageCacheConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_cleanup_image_cache",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineCreateBackup({required PriceEngine that , required String backupPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(backupPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineCreateBackupConstMeta,
+            argValues: [that, backupPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineCreateBackupConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_create_backup",
+            argNames: ["that", "backupPath"],
+        );
+        
+
+@override Future<String> crateApiPriceEnginePriceEngineExportCookies({required PriceEngine that , required String domain , required CookieFormat format })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_cookie_format(format, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineExportCookiesConstMeta,
+            argValues: [that, domain, format],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineExportCookiesConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_export_cookies",
+            argNames: ["that", "domain", "format"],
+        );
+        
+
+@override Future<ProductRecord> crateApiPriceEnginePriceEngineFetchAndUpdateProduct({required PriceEngine that , required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_product_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineFetchAndUpdateProductConstMeta,
+            argValues: [that, url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineFetchAndUpdateProductConstMeta => const TaskConstMeta(
             debugName: "PriceEngine_fetch_and_update_product",
             argNames: ["that", "url"],
-          );
-
-  @override
-  Future<List<ProductRecord>> crateApiPriceEnginePriceEngineGetAllProductsInDb(
-      {required PriceEngine that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 28, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_product_record,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiPriceEnginePriceEngineGetAllProductsInDbConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiPriceEnginePriceEngineGetAllProductsInDbConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<List<ProductRecord>> crateApiPriceEnginePriceEngineGetAllProductsInDb({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_product_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineGetAllProductsInDbConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineGetAllProductsInDbConstMeta => const TaskConstMeta(
             debugName: "PriceEngine_get_all_products_in_db",
             argNames: ["that"],
-          );
-
-  @override
-  Future<int> crateApiPriceEnginePriceEngineGetCurrentPort(
-      {required PriceEngine that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 29, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_u_16,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiPriceEnginePriceEngineGetCurrentPortConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiPriceEnginePriceEngineGetCurrentPortConstMeta =>
-      const TaskConstMeta(
-        debugName: "PriceEngine_get_current_port",
-        argNames: ["that"],
-      );
-
-  @override
-  Future<PriceEngine> crateApiPriceEnginePriceEngineNew(
-      {required int port,
-      required String browserPath,
-      required String dbPath,
-      required String driverPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_u_16(port, serializer);
-        sse_encode_String(browserPath, serializer);
-        sse_encode_String(dbPath, serializer);
-        sse_encode_String(driverPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 30, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiPriceEnginePriceEngineNewConstMeta,
-      argValues: [port, browserPath, dbPath, driverPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiPriceEnginePriceEngineNewConstMeta =>
-      const TaskConstMeta(
-        debugName: "PriceEngine_new",
-        argNames: ["port", "browserPath", "dbPath", "driverPath"],
-      );
-
-  @override
-  Future<void> crateApiPriceEnginePriceEngineRemoveProductById(
-      {required PriceEngine that, required String productId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            that, serializer);
-        sse_encode_String(productId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 31, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiPriceEnginePriceEngineRemoveProductByIdConstMeta,
-      argValues: [that, productId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiPriceEnginePriceEngineRemoveProductByIdConstMeta =>
-      const TaskConstMeta(
-        debugName: "PriceEngine_remove_product_by_id",
-        argNames: ["that", "productId"],
-      );
-
-  @override
-  Future<ServiceStatus> crateApiPriceEnginePriceEngineRestartBrowserService(
-      {required PriceEngine that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 32, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_service_status,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiPriceEnginePriceEngineRestartBrowserServiceConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiPriceEnginePriceEngineRestartBrowserServiceConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<DiscoveryReport> crateApiPriceEnginePriceEngineGetBrowserBinaries({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_discovery_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineGetBrowserBinariesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineGetBrowserBinariesConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_get_browser_binaries",
+            argNames: ["that"],
+        );
+        
+
+@override Future<int> crateApiPriceEnginePriceEngineGetCurrentPort({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_16,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineGetCurrentPortConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineGetCurrentPortConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_get_current_port",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<DiagnosticCapture>> crateApiPriceEnginePriceEngineGetDiagnosticCaptures({required PriceEngine that , required String productId , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(productId, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_diagnostic_capture,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineGetDiagnosticCapturesConstMeta,
+            argValues: [that, productId, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineGetDiagnosticCapturesConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_get_diagnostic_captures",
+            argNames: ["that", "productId", "limit"],
+        );
+        
+
+@override Future<List<DiagnosticCapture>> crateApiPriceEnginePriceEngineGetDiagnosticCapturesForUrl({required PriceEngine that , String? url , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_opt_String(url, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_diagnostic_capture,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineGetDiagnosticCapturesForUrlConstMeta,
+            argValues: [that, url, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineGetDiagnosticCapturesForUrlConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_get_diagnostic_captures_for_url",
+            argNames: ["that", "url", "limit"],
+        );
+        
+
+@override Future<List<String>> crateApiPriceEnginePriceEngineGetDriverLogTail({required PriceEngine that , required int lines })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_u_32(lines, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineGetDriverLogTailConstMeta,
+            argValues: [that, lines],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineGetDriverLogTailConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_get_driver_log_tail",
+            argNames: ["that", "lines"],
+        );
+        
+
+@override Future<List<FetchPathStats>> crateApiPriceEnginePriceEngineGetFetchPathStats({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_fetch_path_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineGetFetchPathStatsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineGetFetchPathStatsConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_get_fetch_path_stats",
+            argNames: ["that"],
+        );
+        
+
+@override Future<FetchTimeouts> crateApiPriceEnginePriceEngineGetFetchTimeouts({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fetch_timeouts,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineGetFetchTimeoutsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineGetFetchTimeoutsConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_get_fetch_timeouts",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<FetchTimingStats>> crateApiPriceEnginePriceEngineGetFetchTimingStats({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_fetch_timing_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineGetFetchTimingStatsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineGetFetchTimingStatsConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_get_fetch_timing_stats",
+            argNames: ["that"],
+        );
+        
+
+@override Future<ImageCacheStats> crateApiPriceEnginePriceEngineGetImageCacheStats({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_image_cache_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineGetImageCacheStatsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineGetImageCacheStatsConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_get_image_cache_stats",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<ProxyStatus>> crateApiPriceEnginePriceEngineGetProxyStatus({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_proxy_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineGetProxyStatusConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineGetProxyStatusConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_get_proxy_status",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<SiteConfig>> crateApiPriceEnginePriceEngineGetSiteConfigs({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_site_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineGetSiteConfigsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineGetSiteConfigsConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_get_site_configs",
+            argNames: ["that"],
+        );
+        
+
+@override Future<int> crateApiPriceEnginePriceEngineImportCookies({required PriceEngine that , required String domain , required String content , required CookieFormat format })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_String(content, serializer);
+sse_encode_cookie_format(format, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineImportCookiesConstMeta,
+            argValues: [that, domain, content, format],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineImportCookiesConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_import_cookies",
+            argNames: ["that", "domain", "content", "format"],
+        );
+        
+
+@override Future<PriceEngine> crateApiPriceEnginePriceEngineNew({required int port , required String browserPath , required String dbPath , required String driverPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_16(port, serializer);
+sse_encode_String(browserPath, serializer);
+sse_encode_String(dbPath, serializer);
+sse_encode_String(driverPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineNewConstMeta,
+            argValues: [port, browserPath, dbPath, driverPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineNewConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_new",
+            argNames: ["port", "browserPath", "dbPath", "driverPath"],
+        );
+        
+
+@override Future<PriceEngine> crateApiPriceEnginePriceEngineNewHttpOnly({required String dbPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dbPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineNewHttpOnlyConstMeta,
+            argValues: [dbPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineNewHttpOnlyConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_new_http_only",
+            argNames: ["dbPath"],
+        );
+        
+
+@override Future<PriceEngine> crateApiPriceEnginePriceEngineNewRemote({required String webdriverUrl , required BrowserKind browserKind , required BrowserOptions options , required String dbPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(webdriverUrl, serializer);
+sse_encode_browser_kind(browserKind, serializer);
+sse_encode_box_autoadd_browser_options(options, serializer);
+sse_encode_String(dbPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineNewRemoteConstMeta,
+            argValues: [webdriverUrl, browserKind, options, dbPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineNewRemoteConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_new_remote",
+            argNames: ["webdriverUrl", "browserKind", "options", "dbPath"],
+        );
+        
+
+@override Future<PriceEngine> crateApiPriceEnginePriceEngineNewWithBrowserKind({required int port , required BrowserKind browserKind , required String browserPath , required String dbPath , required String driverPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_16(port, serializer);
+sse_encode_browser_kind(browserKind, serializer);
+sse_encode_String(browserPath, serializer);
+sse_encode_String(dbPath, serializer);
+sse_encode_String(driverPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineNewWithBrowserKindConstMeta,
+            argValues: [port, browserKind, browserPath, dbPath, driverPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineNewWithBrowserKindConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_new_with_browser_kind",
+            argNames: ["port", "browserKind", "browserPath", "dbPath", "driverPath"],
+        );
+        
+
+@override Future<PriceEngine> crateApiPriceEnginePriceEngineNewWithOptions({required int port , required BrowserKind browserKind , required BrowserOptions options , required String browserPath , required String dbPath , required String driverPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_16(port, serializer);
+sse_encode_browser_kind(browserKind, serializer);
+sse_encode_box_autoadd_browser_options(options, serializer);
+sse_encode_String(browserPath, serializer);
+sse_encode_String(dbPath, serializer);
+sse_encode_String(driverPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineNewWithOptionsConstMeta,
+            argValues: [port, browserKind, options, browserPath, dbPath, driverPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineNewWithOptionsConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_new_with_options",
+            argNames: ["port", "browserKind", "options", "browserPath", "dbPath", "driverPath"],
+        );
+        
+
+@override Future<DiagnosticCaptureContent> crateApiPriceEnginePriceEngineOpenDiagnosticCapture({required PriceEngine that , required String captureId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(captureId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_diagnostic_capture_content,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineOpenDiagnosticCaptureConstMeta,
+            argValues: [that, captureId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineOpenDiagnosticCaptureConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_open_diagnostic_capture",
+            argNames: ["that", "captureId"],
+        );
+        
+
+@override Future<List<RefreshOutcome>> crateApiPriceEnginePriceEngineRefreshAllProducts({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_refresh_outcome,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineRefreshAllProductsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineRefreshAllProductsConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_refresh_all_products",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineRemoveProductById({required PriceEngine that , required String productId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(productId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineRemoveProductByIdConstMeta,
+            argValues: [that, productId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineRemoveProductByIdConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_remove_product_by_id",
+            argNames: ["that", "productId"],
+        );
+        
+
+@override Future<ServiceStatus> crateApiPriceEnginePriceEngineRestartBrowserService({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_service_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineRestartBrowserServiceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineRestartBrowserServiceConstMeta => const TaskConstMeta(
             debugName: "PriceEngine_restart_browser_service",
             argNames: ["that"],
-          );
-
-  @override
-  Future<void> crateApiPriceEnginePriceEngineRestoreFromBackup(
-      {required PriceEngine that,
-      required String backupPath,
-      required bool replaceExisting}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            that, serializer);
-        sse_encode_String(backupPath, serializer);
-        sse_encode_bool(replaceExisting, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 33, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiPriceEnginePriceEngineRestoreFromBackupConstMeta,
-      argValues: [that, backupPath, replaceExisting],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiPriceEnginePriceEngineRestoreFromBackupConstMeta =>
-      const TaskConstMeta(
-        debugName: "PriceEngine_restore_from_backup",
-        argNames: ["that", "backupPath", "replaceExisting"],
-      );
-
-  @override
-  Future<void> crateApiPriceEnginePriceEngineShutdown(
-      {required PriceEngine that}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            that, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 34, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiPriceEnginePriceEngineShutdownConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiPriceEnginePriceEngineShutdownConstMeta =>
-      const TaskConstMeta(
-        debugName: "PriceEngine_shutdown",
-        argNames: ["that"],
-      );
-
-  @override
-  List<String> crateApiModelsProductDetailsAutoAccessorGetFeatures(
-      {required ProductDetails that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorGetFeaturesConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorGetFeaturesConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineRestoreFromBackup({required PriceEngine that , required String backupPath , required bool replaceExisting })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(backupPath, serializer);
+sse_encode_bool(replaceExisting, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineRestoreFromBackupConstMeta,
+            argValues: [that, backupPath, replaceExisting],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineRestoreFromBackupConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_restore_from_backup",
+            argNames: ["that", "backupPath", "replaceExisting"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineSetBrowserPoolSize({required PriceEngine that , required int poolSize })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_u_32(poolSize, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineSetBrowserPoolSizeConstMeta,
+            argValues: [that, poolSize],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineSetBrowserPoolSizeConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_set_browser_pool_size",
+            argNames: ["that", "poolSize"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineSetBrowserRecyclePolicy({required PriceEngine that , required RecyclePolicy policy })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_box_autoadd_recycle_policy(policy, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineSetBrowserRecyclePolicyConstMeta,
+            argValues: [that, policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineSetBrowserRecyclePolicyConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_set_browser_recycle_policy",
+            argNames: ["that", "policy"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineSetDefaultProxies({required PriceEngine that , required List<String> proxies })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_list_String(proxies, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineSetDefaultProxiesConstMeta,
+            argValues: [that, proxies],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineSetDefaultProxiesConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_set_default_proxies",
+            argNames: ["that", "proxies"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineSetDiagnosticsRetention({required PriceEngine that , required int maxCaptures })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_u_32(maxCaptures, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineSetDiagnosticsRetentionConstMeta,
+            argValues: [that, maxCaptures],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineSetDiagnosticsRetentionConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_set_diagnostics_retention",
+            argNames: ["that", "maxCaptures"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineSetFetchTimeouts({required PriceEngine that , required FetchTimeouts timeouts })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_box_autoadd_fetch_timeouts(timeouts, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineSetFetchTimeoutsConstMeta,
+            argValues: [that, timeouts],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineSetFetchTimeoutsConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_set_fetch_timeouts",
+            argNames: ["that", "timeouts"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineSetSiteAllowedResources({required PriceEngine that , required String domain , required List<ResourceType> allowed })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_list_resource_type(allowed, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineSetSiteAllowedResourcesConstMeta,
+            argValues: [that, domain, allowed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineSetSiteAllowedResourcesConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_set_site_allowed_resources",
+            argNames: ["that", "domain", "allowed"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineSetSiteBlockCooldown({required PriceEngine that , required String domain , required int cooldownMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_u_32(cooldownMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineSetSiteBlockCooldownConstMeta,
+            argValues: [that, domain, cooldownMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineSetSiteBlockCooldownConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_set_site_block_cooldown",
+            argNames: ["that", "domain", "cooldownMs"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineSetSiteFallback({required PriceEngine that , required String domain , required bool enabled })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_bool(enabled, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineSetSiteFallbackConstMeta,
+            argValues: [that, domain, enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineSetSiteFallbackConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_set_site_fallback",
+            argNames: ["that", "domain", "enabled"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineSetSiteFetcher({required PriceEngine that , required String domain , required FetcherKind fetcher })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_fetcher_kind(fetcher, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineSetSiteFetcherConstMeta,
+            argValues: [that, domain, fetcher],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineSetSiteFetcherConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_set_site_fetcher",
+            argNames: ["that", "domain", "fetcher"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineSetSiteProxies({required PriceEngine that , required String domain , required List<String> proxies })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_list_String(proxies, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineSetSiteProxiesConstMeta,
+            argValues: [that, domain, proxies],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineSetSiteProxiesConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_set_site_proxies",
+            argNames: ["that", "domain", "proxies"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineSetSiteRateLimit({required PriceEngine that , required String domain , required RateLimit rateLimit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_box_autoadd_rate_limit(rateLimit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineSetSiteRateLimitConstMeta,
+            argValues: [that, domain, rateLimit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineSetSiteRateLimitConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_set_site_rate_limit",
+            argNames: ["that", "domain", "rateLimit"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineSetSiteReadyCondition({required PriceEngine that , required String domain , required ReadyCondition condition , required int timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_box_autoadd_ready_condition(condition, serializer);
+sse_encode_u_32(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineSetSiteReadyConditionConstMeta,
+            argValues: [that, domain, condition, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineSetSiteReadyConditionConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_set_site_ready_condition",
+            argNames: ["that", "domain", "condition", "timeoutMs"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineSetSiteRetryPolicy({required PriceEngine that , required String domain , required RetryPolicy retryPolicy })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_box_autoadd_retry_policy(retryPolicy, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineSetSiteRetryPolicyConstMeta,
+            argValues: [that, domain, retryPolicy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineSetSiteRetryPolicyConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_set_site_retry_policy",
+            argNames: ["that", "domain", "retryPolicy"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineShutdown({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineShutdownConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineShutdownConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_shutdown",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineStartServiceWatchdog({required PriceEngine that , required WatchdogConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+sse_encode_box_autoadd_watchdog_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineStartServiceWatchdogConstMeta,
+            argValues: [that, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineStartServiceWatchdogConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_start_service_watchdog",
+            argNames: ["that", "config"],
+        );
+        
+
+@override Future<void> crateApiPriceEnginePriceEngineStopServiceWatchdog({required PriceEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiPriceEnginePriceEngineStopServiceWatchdogConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiPriceEnginePriceEngineStopServiceWatchdogConstMeta => const TaskConstMeta(
+            debugName: "PriceEngine_stop_service_watchdog",
+            argNames: ["that"],
+        );
+        
+
+@override List<String> crateApiModelsProductDetailsAutoAccessorGetFeatures({required ProductDetails that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorGetFeaturesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorGetFeaturesConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_get_features",
             argNames: ["that"],
-          );
-
-  @override
-  String crateApiModelsProductDetailsAutoAccessorGetId(
-      {required ProductDetails that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorGetIdConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorGetIdConstMeta =>
-      const TaskConstMeta(
-        debugName: "ProductDetails_auto_accessor_get_id",
-        argNames: ["that"],
-      );
-
-  @override
-  List<String> crateApiModelsProductDetailsAutoAccessorGetImages(
-      {required ProductDetails that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorGetImagesConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorGetImagesConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override String crateApiModelsProductDetailsAutoAccessorGetId({required ProductDetails that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorGetIdConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorGetIdConstMeta => const TaskConstMeta(
+            debugName: "ProductDetails_auto_accessor_get_id",
+            argNames: ["that"],
+        );
+        
+
+@override List<String> crateApiModelsProductDetailsAutoAccessorGetImages({required ProductDetails that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorGetImagesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorGetImagesConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_get_images",
             argNames: ["that"],
-          );
-
-  @override
-  bool crateApiModelsProductDetailsAutoAccessorGetInStock(
-      {required ProductDetails that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_bool,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorGetInStockConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorGetInStockConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override bool crateApiModelsProductDetailsAutoAccessorGetInStock({required ProductDetails that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorGetInStockConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorGetInStockConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_get_in_stock",
             argNames: ["that"],
-          );
-
-  @override
-  int? crateApiModelsProductDetailsAutoAccessorGetPrice(
-      {required ProductDetails that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_box_autoadd_i_32,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorGetPriceConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorGetPriceConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override Money? crateApiModelsProductDetailsAutoAccessorGetPrice({required ProductDetails that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_money,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorGetPriceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorGetPriceConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_get_price",
             argNames: ["that"],
-          );
-
-  @override
-  String crateApiModelsProductDetailsAutoAccessorGetRating(
-      {required ProductDetails that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorGetRatingConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorGetRatingConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override String crateApiModelsProductDetailsAutoAccessorGetRating({required ProductDetails that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorGetRatingConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorGetRatingConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_get_rating",
             argNames: ["that"],
-          );
-
-  @override
-  String? crateApiModelsProductDetailsAutoAccessorGetSeller(
-      {required ProductDetails that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_opt_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorGetSellerConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorGetSellerConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override String? crateApiModelsProductDetailsAutoAccessorGetSeller({required ProductDetails that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorGetSellerConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorGetSellerConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_get_seller",
             argNames: ["that"],
-          );
-
-  @override
-  String crateApiModelsProductDetailsAutoAccessorGetSite(
-      {required ProductDetails that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorGetSiteConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorGetSiteConstMeta =>
-      const TaskConstMeta(
-        debugName: "ProductDetails_auto_accessor_get_site",
-        argNames: ["that"],
-      );
-
-  @override
-  Value crateApiModelsProductDetailsAutoAccessorGetSpecifications(
-      {required ProductDetails that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue,
-        decodeErrorData: null,
-      ),
-      constMeta:
-          kCrateApiModelsProductDetailsAutoAccessorGetSpecificationsConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorGetSpecificationsConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override String crateApiModelsProductDetailsAutoAccessorGetSite({required ProductDetails that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorGetSiteConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorGetSiteConstMeta => const TaskConstMeta(
+            debugName: "ProductDetails_auto_accessor_get_site",
+            argNames: ["that"],
+        );
+        
+
+@override Value crateApiModelsProductDetailsAutoAccessorGetSpecifications({required ProductDetails that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorGetSpecificationsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorGetSpecificationsConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_get_specifications",
             argNames: ["that"],
-          );
-
-  @override
-  String crateApiModelsProductDetailsAutoAccessorGetTitle(
-      {required ProductDetails that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorGetTitleConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorGetTitleConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override String crateApiModelsProductDetailsAutoAccessorGetTitle({required ProductDetails that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorGetTitleConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorGetTitleConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_get_title",
             argNames: ["that"],
-          );
-
-  @override
-  String crateApiModelsProductDetailsAutoAccessorGetUrl(
-      {required ProductDetails that}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorGetUrlConstMeta,
-      argValues: [that],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorGetUrlConstMeta =>
-      const TaskConstMeta(
-        debugName: "ProductDetails_auto_accessor_get_url",
-        argNames: ["that"],
-      );
-
-  @override
-  void crateApiModelsProductDetailsAutoAccessorSetFeatures(
-      {required ProductDetails that, required List<String> features}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        sse_encode_list_String(features, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorSetFeaturesConstMeta,
-      argValues: [that, features],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorSetFeaturesConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override String crateApiModelsProductDetailsAutoAccessorGetUrl({required ProductDetails that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorGetUrlConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorGetUrlConstMeta => const TaskConstMeta(
+            debugName: "ProductDetails_auto_accessor_get_url",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiModelsProductDetailsAutoAccessorSetFeatures({required ProductDetails that , required List<String> features })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+sse_encode_list_String(features, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorSetFeaturesConstMeta,
+            argValues: [that, features],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorSetFeaturesConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_set_features",
             argNames: ["that", "features"],
-          );
-
-  @override
-  void crateApiModelsProductDetailsAutoAccessorSetId(
-      {required ProductDetails that, required String id}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        sse_encode_String(id, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorSetIdConstMeta,
-      argValues: [that, id],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorSetIdConstMeta =>
-      const TaskConstMeta(
-        debugName: "ProductDetails_auto_accessor_set_id",
-        argNames: ["that", "id"],
-      );
-
-  @override
-  void crateApiModelsProductDetailsAutoAccessorSetImages(
-      {required ProductDetails that, required List<String> images}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        sse_encode_list_String(images, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorSetImagesConstMeta,
-      argValues: [that, images],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorSetImagesConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override void crateApiModelsProductDetailsAutoAccessorSetId({required ProductDetails that , required String id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+sse_encode_String(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorSetIdConstMeta,
+            argValues: [that, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorSetIdConstMeta => const TaskConstMeta(
+            debugName: "ProductDetails_auto_accessor_set_id",
+            argNames: ["that", "id"],
+        );
+        
+
+@override void crateApiModelsProductDetailsAutoAccessorSetImages({required ProductDetails that , required List<String> images })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+sse_encode_list_String(images, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorSetImagesConstMeta,
+            argValues: [that, images],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorSetImagesConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_set_images",
             argNames: ["that", "images"],
-          );
-
-  @override
-  void crateApiModelsProductDetailsAutoAccessorSetInStock(
-      {required ProductDetails that, required bool inStock}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        sse_encode_bool(inStock, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorSetInStockConstMeta,
-      argValues: [that, inStock],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorSetInStockConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override void crateApiModelsProductDetailsAutoAccessorSetInStock({required ProductDetails that , required bool inStock })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+sse_encode_bool(inStock, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorSetInStockConstMeta,
+            argValues: [that, inStock],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorSetInStockConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_set_in_stock",
             argNames: ["that", "inStock"],
-          );
-
-  @override
-  void crateApiModelsProductDetailsAutoAccessorSetPrice(
-      {required ProductDetails that, int? price}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        sse_encode_opt_box_autoadd_i_32(price, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorSetPriceConstMeta,
-      argValues: [that, price],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorSetPriceConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override void crateApiModelsProductDetailsAutoAccessorSetPrice({required ProductDetails that , Money? price })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+sse_encode_opt_box_autoadd_money(price, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorSetPriceConstMeta,
+            argValues: [that, price],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorSetPriceConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_set_price",
             argNames: ["that", "price"],
-          );
-
-  @override
-  void crateApiModelsProductDetailsAutoAccessorSetRating(
-      {required ProductDetails that, required String rating}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        sse_encode_String(rating, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorSetRatingConstMeta,
-      argValues: [that, rating],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorSetRatingConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override void crateApiModelsProductDetailsAutoAccessorSetRating({required ProductDetails that , required String rating })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+sse_encode_String(rating, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorSetRatingConstMeta,
+            argValues: [that, rating],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorSetRatingConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_set_rating",
             argNames: ["that", "rating"],
-          );
-
-  @override
-  void crateApiModelsProductDetailsAutoAccessorSetSeller(
-      {required ProductDetails that, String? seller}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        sse_encode_opt_String(seller, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorSetSellerConstMeta,
-      argValues: [that, seller],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorSetSellerConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override void crateApiModelsProductDetailsAutoAccessorSetSeller({required ProductDetails that , String? seller })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+sse_encode_opt_String(seller, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorSetSellerConstMeta,
+            argValues: [that, seller],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorSetSellerConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_set_seller",
             argNames: ["that", "seller"],
-          );
-
-  @override
-  void crateApiModelsProductDetailsAutoAccessorSetSite(
-      {required ProductDetails that, required String site}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        sse_encode_String(site, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorSetSiteConstMeta,
-      argValues: [that, site],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorSetSiteConstMeta =>
-      const TaskConstMeta(
-        debugName: "ProductDetails_auto_accessor_set_site",
-        argNames: ["that", "site"],
-      );
-
-  @override
-  void crateApiModelsProductDetailsAutoAccessorSetSpecifications(
-      {required ProductDetails that, required Value specifications}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-            specifications, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta:
-          kCrateApiModelsProductDetailsAutoAccessorSetSpecificationsConstMeta,
-      argValues: [that, specifications],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorSetSpecificationsConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override void crateApiModelsProductDetailsAutoAccessorSetSite({required ProductDetails that , required String site })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+sse_encode_String(site, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorSetSiteConstMeta,
+            argValues: [that, site],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorSetSiteConstMeta => const TaskConstMeta(
+            debugName: "ProductDetails_auto_accessor_set_site",
+            argNames: ["that", "site"],
+        );
+        
+
+@override void crateApiModelsProductDetailsAutoAccessorSetSpecifications({required ProductDetails that , required Value specifications })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(specifications, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorSetSpecificationsConstMeta,
+            argValues: [that, specifications],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorSetSpecificationsConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_set_specifications",
             argNames: ["that", "specifications"],
-          );
-
-  @override
-  void crateApiModelsProductDetailsAutoAccessorSetTitle(
-      {required ProductDetails that, required String title}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        sse_encode_String(title, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorSetTitleConstMeta,
-      argValues: [that, title],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsProductDetailsAutoAccessorSetTitleConstMeta =>
-          const TaskConstMeta(
+        );
+        
+
+@override void crateApiModelsProductDetailsAutoAccessorSetTitle({required ProductDetails that , required String title })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+sse_encode_String(title, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorSetTitleConstMeta,
+            argValues: [that, title],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorSetTitleConstMeta => const TaskConstMeta(
             debugName: "ProductDetails_auto_accessor_set_title",
             argNames: ["that", "title"],
-          );
-
-  @override
-  void crateApiModelsProductDetailsAutoAccessorSetUrl(
-      {required ProductDetails that, required String url}) {
-    return handler.executeSync(SyncTask(
-      callFfi: () {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-            that, serializer);
-        sse_encode_String(url, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: null,
-      ),
-      constMeta: kCrateApiModelsProductDetailsAutoAccessorSetUrlConstMeta,
-      argValues: [that, url],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorSetUrlConstMeta =>
-      const TaskConstMeta(
-        debugName: "ProductDetails_auto_accessor_set_url",
-        argNames: ["that", "url"],
-      );
-
-  @override
-  Future<PriceEngineStatus> crateApiApinCheckServiceStatus(
-      {required PriceEngine engine}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            engine, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 57, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_price_engine_status,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiApinCheckServiceStatusConstMeta,
-      argValues: [engine],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiApinCheckServiceStatusConstMeta =>
-      const TaskConstMeta(
-        debugName: "check_service_status",
-        argNames: ["engine"],
-      );
-
-  @override
-  Future<void> crateApiApinCreateBackup(
-      {required PriceEngine engine, required String backupPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            engine, serializer);
-        sse_encode_String(backupPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 58, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiApinCreateBackupConstMeta,
-      argValues: [engine, backupPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiApinCreateBackupConstMeta => const TaskConstMeta(
-        debugName: "create_backup",
-        argNames: ["engine", "backupPath"],
-      );
-
-  @override
-  Future<ProductDetails> crateApiAmazonParserExtractDetails(
-      {required String htmlSource, required String url}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(htmlSource, serializer);
-        sse_encode_String(url, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 59, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiAmazonParserExtractDetailsConstMeta,
-      argValues: [htmlSource, url],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiAmazonParserExtractDetailsConstMeta =>
-      const TaskConstMeta(
-        debugName: "extract_details",
-        argNames: ["htmlSource", "url"],
-      );
-
-  @override
-  Future<ProductDetails> crateApiFlipkartParserExtractDetails(
-      {required String htmlSource, required String url}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(htmlSource, serializer);
-        sse_encode_String(url, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 60, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiFlipkartParserExtractDetailsConstMeta,
-      argValues: [htmlSource, url],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiFlipkartParserExtractDetailsConstMeta =>
-      const TaskConstMeta(
-        debugName: "extract_details",
-        argNames: ["htmlSource", "url"],
-      );
-
-  @override
-  Future<ProductRecord> crateApiApinFetchAndUpdateProduct(
-      {required PriceEngine engine, required String url}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            engine, serializer);
-        sse_encode_String(url, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 61, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_product_record,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiApinFetchAndUpdateProductConstMeta,
-      argValues: [engine, url],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiApinFetchAndUpdateProductConstMeta =>
-      const TaskConstMeta(
-        debugName: "fetch_and_update_product",
-        argNames: ["engine", "url"],
-      );
-
-  @override
-  Future<List<ProductRecord>> crateApiApinGetAllProductsInDb(
-      {required PriceEngine engine}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            engine, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 62, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_list_product_record,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiApinGetAllProductsInDbConstMeta,
-      argValues: [engine],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiApinGetAllProductsInDbConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_all_products_in_db",
-        argNames: ["engine"],
-      );
-
-  @override
-  Future<int> crateApiApinGetCurrentPort({required PriceEngine engine}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            engine, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 63, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_u_16,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiApinGetCurrentPortConstMeta,
-      argValues: [engine],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiApinGetCurrentPortConstMeta => const TaskConstMeta(
-        debugName: "get_current_port",
-        argNames: ["engine"],
-      );
-
-  @override
-  Future<PriceEngine> crateApiApinGetPriceEngine(
-      {required int port,
-      required String browserPath,
-      required String dbPath,
-      required String driverPath}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_u_16(port, serializer);
-        sse_encode_String(browserPath, serializer);
-        sse_encode_String(dbPath, serializer);
-        sse_encode_String(driverPath, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 64, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData:
-            sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiApinGetPriceEngineConstMeta,
-      argValues: [port, browserPath, dbPath, driverPath],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiApinGetPriceEngineConstMeta => const TaskConstMeta(
-        debugName: "get_price_engine",
-        argNames: ["port", "browserPath", "dbPath", "driverPath"],
-      );
-
-  @override
-  Future<void> crateApiApinRemoveProductById(
-      {required PriceEngine engine, required String productId}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            engine, serializer);
-        sse_encode_String(productId, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 65, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiApinRemoveProductByIdConstMeta,
-      argValues: [engine, productId],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiApinRemoveProductByIdConstMeta =>
-      const TaskConstMeta(
-        debugName: "remove_product_by_id",
-        argNames: ["engine", "productId"],
-      );
-
-  @override
-  Future<String> crateApiApinRestartBrowserService(
-      {required PriceEngine engine}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            engine, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 66, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiApinRestartBrowserServiceConstMeta,
-      argValues: [engine],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiApinRestartBrowserServiceConstMeta =>
-      const TaskConstMeta(
-        debugName: "restart_browser_service",
-        argNames: ["engine"],
-      );
-
-  @override
-  Future<void> crateApiApinRestoreFromBackup(
-      {required PriceEngine engine,
-      required String backupPath,
-      required bool replaceExisting}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            engine, serializer);
-        sse_encode_String(backupPath, serializer);
-        sse_encode_bool(replaceExisting, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 67, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiApinRestoreFromBackupConstMeta,
-      argValues: [engine, backupPath, replaceExisting],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiApinRestoreFromBackupConstMeta =>
-      const TaskConstMeta(
-        debugName: "restore_from_backup",
-        argNames: ["engine", "backupPath", "replaceExisting"],
-      );
-
-  @override
-  Future<void> crateApiApinShutdownPriceEngine({required PriceEngine engine}) {
-    return handler.executeNormal(NormalTask(
-      callFfi: (port_) {
-        final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-            engine, serializer);
-        pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 68, port: port_);
-      },
-      codec: SseCodec(
-        decodeSuccessData: sse_decode_unit,
-        decodeErrorData: sse_decode_AnyhowException,
-      ),
-      constMeta: kCrateApiApinShutdownPriceEngineConstMeta,
-      argValues: [engine],
-      apiImpl: this,
-    ));
-  }
-
-  TaskConstMeta get kCrateApiApinShutdownPriceEngineConstMeta =>
-      const TaskConstMeta(
-        debugName: "shutdown_price_engine",
-        argNames: ["engine"],
-      );
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_BrowserEngine => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_BrowserEngine => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_BrowserServiceManager => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_BrowserServiceManager => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_Database => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_Database => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_PriceEngine => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_PriceEngine => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_ProductDetails => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_ProductDetails => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails;
-
-  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Value =>
-      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue;
-
-  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Value =>
-      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  BrowserEngine
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BrowserEngineImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  BrowserServiceManager
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BrowserServiceManagerImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Database
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DatabaseImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  PriceEngine
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PriceEngineImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ProductDetails
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ProductDetailsImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Value
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ValueImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  BrowserEngine
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BrowserEngineImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  BrowserServiceManager
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BrowserServiceManagerImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  PriceEngine
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PriceEngineImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ProductDetails
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ProductDetailsImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  BrowserEngine
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BrowserEngineImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  BrowserServiceManager
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BrowserServiceManagerImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Database
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DatabaseImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  PriceEngine
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PriceEngineImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ProductDetails
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ProductDetailsImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  BrowserEngine
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BrowserEngineImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  BrowserServiceManager
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return BrowserServiceManagerImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Database
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DatabaseImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  PriceEngine
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return PriceEngineImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  ProductDetails
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ProductDetailsImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  Value
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ValueImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  ProductRecord dco_decode_box_autoadd_product_record(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_product_record(raw);
-  }
-
-  @protected
-  BrowserEngineStatus dco_decode_browser_engine_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return BrowserEngineStatus(
-      isRunning: dco_decode_bool(arr[0]),
-      currentPort: dco_decode_u_16(arr[1]),
-      message: dco_decode_String(arr[2]),
-      requiresRestart: dco_decode_bool(arr[3]),
-    );
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<PriceEntry> dco_decode_list_price_entry(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_price_entry).toList();
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<ProductRecord> dco_decode_list_product_record(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_product_record).toList();
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
-  }
-
-  @protected
-  ProductRecord? dco_decode_opt_box_autoadd_product_record(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_product_record(raw);
-  }
-
-  @protected
-  PriceEngineStatus dco_decode_price_engine_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return PriceEngineStatus(
-      isHealthy: dco_decode_bool(arr[0]),
-      currentPort: dco_decode_u_16(arr[1]),
-      message: dco_decode_String(arr[2]),
-      lastCheck: dco_decode_String(arr[3]),
-    );
-  }
-
-  @protected
-  PriceEntry dco_decode_price_entry(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return PriceEntry(
-      price: dco_decode_i_32(arr[0]),
-      inStock: dco_decode_bool(arr[1]),
-      timestamp: dco_decode_String(arr[2]),
-    );
-  }
-
-  @protected
-  ProductRecord dco_decode_product_record(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return ProductRecord(
-      id: dco_decode_String(arr[0]),
-      site: dco_decode_String(arr[1]),
-      url: dco_decode_String(arr[2]),
-      title: dco_decode_String(arr[3]),
-      seller: dco_decode_opt_String(arr[4]),
-      images: dco_decode_list_String(arr[5]),
-      specifications: dco_decode_String(arr[6]),
-      features: dco_decode_list_String(arr[7]),
-      priceHistory: dco_decode_list_price_entry(arr[8]),
-    );
-  }
-
-  @protected
-  (
-    BrowserEngine,
-    ServiceStatus
-  ) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_browser_engine_service_status(
-      dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          arr[0]),
-      dco_decode_service_status(arr[1]),
-    );
-  }
-
-  @protected
-  ServiceStatus dco_decode_service_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return ServiceStatus(
-      isHealthy: dco_decode_bool(arr[0]),
-      port: dco_decode_u_16(arr[1]),
-      errorMessage: dco_decode_opt_String(arr[2]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  BrowserEngine
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return BrowserEngineImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  BrowserServiceManager
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return BrowserServiceManagerImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Database
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return DatabaseImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  PriceEngine
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return PriceEngineImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  ProductDetails
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ProductDetailsImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Value
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ValueImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  BrowserEngine
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return BrowserEngineImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  BrowserServiceManager
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return BrowserServiceManagerImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  PriceEngine
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return PriceEngineImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  ProductDetails
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ProductDetailsImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  BrowserEngine
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return BrowserEngineImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  BrowserServiceManager
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return BrowserServiceManagerImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Database
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return DatabaseImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  PriceEngine
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return PriceEngineImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  ProductDetails
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ProductDetailsImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  BrowserEngine
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return BrowserEngineImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  BrowserServiceManager
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return BrowserServiceManagerImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Database
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return DatabaseImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  PriceEngine
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return PriceEngineImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  ProductDetails
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ProductDetailsImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  Value
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return ValueImpl.frbInternalSseDecode(
-        sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_i_32(deserializer));
-  }
-
-  @protected
-  ProductRecord sse_decode_box_autoadd_product_record(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_product_record(deserializer));
-  }
-
-  @protected
-  BrowserEngineStatus sse_decode_browser_engine_status(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_isRunning = sse_decode_bool(deserializer);
-    var var_currentPort = sse_decode_u_16(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    var var_requiresRestart = sse_decode_bool(deserializer);
-    return BrowserEngineStatus(
-        isRunning: var_isRunning,
-        currentPort: var_currentPort,
-        message: var_message,
-        requiresRestart: var_requiresRestart);
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<PriceEntry> sse_decode_list_price_entry(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <PriceEntry>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_price_entry(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<ProductRecord> sse_decode_list_product_record(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ProductRecord>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_product_record(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_i_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  ProductRecord? sse_decode_opt_box_autoadd_product_record(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_product_record(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  PriceEngineStatus sse_decode_price_engine_status(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_isHealthy = sse_decode_bool(deserializer);
-    var var_currentPort = sse_decode_u_16(deserializer);
-    var var_message = sse_decode_String(deserializer);
-    var var_lastCheck = sse_decode_String(deserializer);
-    return PriceEngineStatus(
-        isHealthy: var_isHealthy,
-        currentPort: var_currentPort,
-        message: var_message,
-        lastCheck: var_lastCheck);
-  }
-
-  @protected
-  PriceEntry sse_decode_price_entry(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_price = sse_decode_i_32(deserializer);
-    var var_inStock = sse_decode_bool(deserializer);
-    var var_timestamp = sse_decode_String(deserializer);
-    return PriceEntry(
-        price: var_price, inStock: var_inStock, timestamp: var_timestamp);
-  }
-
-  @protected
-  ProductRecord sse_decode_product_record(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_site = sse_decode_String(deserializer);
-    var var_url = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_seller = sse_decode_opt_String(deserializer);
-    var var_images = sse_decode_list_String(deserializer);
-    var var_specifications = sse_decode_String(deserializer);
-    var var_features = sse_decode_list_String(deserializer);
-    var var_priceHistory = sse_decode_list_price_entry(deserializer);
-    return ProductRecord(
-        id: var_id,
-        site: var_site,
-        url: var_url,
-        title: var_title,
-        seller: var_seller,
-        images: var_images,
-        specifications: var_specifications,
-        features: var_features,
-        priceHistory: var_priceHistory);
-  }
-
-  @protected
-  (
-    BrowserEngine,
-    ServiceStatus
-  ) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_browser_engine_service_status(
-      SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-            deserializer);
-    var var_field1 = sse_decode_service_status(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  ServiceStatus sse_decode_service_status(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_isHealthy = sse_decode_bool(deserializer);
-    var var_port = sse_decode_u_16(deserializer);
-    var var_errorMessage = sse_decode_opt_String(deserializer);
-    return ServiceStatus(
-        isHealthy: var_isHealthy,
-        port: var_port,
-        errorMessage: var_errorMessage);
-  }
-
-  @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          BrowserEngine self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as BrowserEngineImpl).frbInternalSseEncode(move: true),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          BrowserServiceManager self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as BrowserServiceManagerImpl).frbInternalSseEncode(move: true),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          Database self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as DatabaseImpl).frbInternalSseEncode(move: true), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          PriceEngine self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as PriceEngineImpl).frbInternalSseEncode(move: true), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          ProductDetails self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ProductDetailsImpl).frbInternalSseEncode(move: true),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          Value self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ValueImpl).frbInternalSseEncode(move: true), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          BrowserEngine self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as BrowserEngineImpl).frbInternalSseEncode(move: false),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          BrowserServiceManager self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as BrowserServiceManagerImpl).frbInternalSseEncode(move: false),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          PriceEngine self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as PriceEngineImpl).frbInternalSseEncode(move: false),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          ProductDetails self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ProductDetailsImpl).frbInternalSseEncode(move: false),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          BrowserEngine self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as BrowserEngineImpl).frbInternalSseEncode(move: false),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          BrowserServiceManager self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as BrowserServiceManagerImpl).frbInternalSseEncode(move: false),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          Database self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as DatabaseImpl).frbInternalSseEncode(move: false), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          PriceEngine self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as PriceEngineImpl).frbInternalSseEncode(move: false),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          ProductDetails self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ProductDetailsImpl).frbInternalSseEncode(move: false),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          BrowserEngine self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as BrowserEngineImpl).frbInternalSseEncode(move: null),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          BrowserServiceManager self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as BrowserServiceManagerImpl).frbInternalSseEncode(move: null),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          Database self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as DatabaseImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          PriceEngine self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as PriceEngineImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          ProductDetails self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ProductDetailsImpl).frbInternalSseEncode(move: null),
-        serializer);
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          Value self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-        (self as ValueImpl).frbInternalSseEncode(move: null), serializer);
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_product_record(
-      ProductRecord self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_product_record(self, serializer);
-  }
-
-  @protected
-  void sse_encode_browser_engine_status(
-      BrowserEngineStatus self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.isRunning, serializer);
-    sse_encode_u_16(self.currentPort, serializer);
-    sse_encode_String(self.message, serializer);
-    sse_encode_bool(self.requiresRestart, serializer);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_price_entry(
-      List<PriceEntry> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_price_entry(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_product_record(
-      List<ProductRecord> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_product_record(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_i_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_product_record(
-      ProductRecord? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_product_record(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_price_engine_status(
-      PriceEngineStatus self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.isHealthy, serializer);
-    sse_encode_u_16(self.currentPort, serializer);
-    sse_encode_String(self.message, serializer);
-    sse_encode_String(self.lastCheck, serializer);
-  }
-
-  @protected
-  void sse_encode_price_entry(PriceEntry self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.price, serializer);
-    sse_encode_bool(self.inStock, serializer);
-    sse_encode_String(self.timestamp, serializer);
-  }
-
-  @protected
-  void sse_encode_product_record(ProductRecord self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.site, serializer);
-    sse_encode_String(self.url, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_opt_String(self.seller, serializer);
-    sse_encode_list_String(self.images, serializer);
-    sse_encode_String(self.specifications, serializer);
-    sse_encode_list_String(self.features, serializer);
-    sse_encode_list_price_entry(self.priceHistory, serializer);
-  }
-
-  @protected
-  void
-      sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_browser_engine_service_status(
-          (BrowserEngine, ServiceStatus) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-        self.$1, serializer);
-    sse_encode_service_status(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_service_status(ServiceStatus self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.isHealthy, serializer);
-    sse_encode_u_16(self.port, serializer);
-    sse_encode_opt_String(self.errorMessage, serializer);
-  }
-
-  @protected
-  void sse_encode_u_16(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint16(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-}
-
-@sealed
-class BrowserEngineImpl extends RustOpaque implements BrowserEngine {
-  // Not to be used by end users
-  BrowserEngineImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  BrowserEngineImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_BrowserEngine,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_BrowserEngine,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_BrowserEnginePtr,
-  );
-
-  /// Checks the health of the browser service
-  Future<BrowserEngineStatus> checkServiceStatus() =>
-      RustLib.instance.api.crateApiScraperEngineBrowserEngineCheckServiceStatus(
-        that: this,
-      );
-
-  /// Closes the currently active tab/window.
-  Future<void> closeCurrentTab() =>
-      RustLib.instance.api.crateApiScraperEngineBrowserEngineCloseCurrentTab(
-        that: this,
-      );
-
-  /// Gets the current port being used
-  Future<int> getCurrentPort() =>
-      RustLib.instance.api.crateApiScraperEngineBrowserEngineGetCurrentPort(
-        that: this,
-      );
-
-  /// Navigates to a URL and returns the page source with automatic retry
-  Future<String> getPageSource({required String url}) => RustLib.instance.api
-      .crateApiScraperEngineBrowserEngineGetPageSource(that: this, url: url);
-
-  /// Restarts the browser service with fallback mechanisms
-  Future<ServiceStatus> restartWithFallback() => RustLib.instance.api
-          .crateApiScraperEngineBrowserEngineRestartWithFallback(
-        that: this,
-      );
-}
-
-@sealed
-class BrowserServiceManagerImpl extends RustOpaque
-    implements BrowserServiceManager {
-  // Not to be used by end users
-  BrowserServiceManagerImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  BrowserServiceManagerImpl.frbInternalSseDecode(
-      BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib
-        .instance.api.rust_arc_increment_strong_count_BrowserServiceManager,
-    rustArcDecrementStrongCount: RustLib
-        .instance.api.rust_arc_decrement_strong_count_BrowserServiceManager,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance.api.rust_arc_decrement_strong_count_BrowserServiceManagerPtr,
-  );
-
-  /// Checks if the browser service is responsive at the current port
-  Future<ServiceStatus> checkServiceHealth() => RustLib.instance.api
-          .crateApiBrowserServiceManagerBrowserServiceManagerCheckServiceHealth(
-        that: this,
-      );
-
-  /// Finds the next available port starting from the current port
-  Future<int> findAvailablePort() => RustLib.instance.api
-          .crateApiBrowserServiceManagerBrowserServiceManagerFindAvailablePort(
-        that: this,
-      );
-
-  /// Gets the current port
-  Future<int> getCurrentPort() => RustLib.instance.api
-          .crateApiBrowserServiceManagerBrowserServiceManagerGetCurrentPort(
-        that: this,
-      );
-
-  /// Restarts the service with fallback to new port if needed
-  Future<ServiceStatus> restartService() => RustLib.instance.api
-          .crateApiBrowserServiceManagerBrowserServiceManagerRestartService(
-        that: this,
-      );
-
-  /// Sets a new port range
-  Future<void> setPortRange({required int minPort, required int maxPort}) =>
-      RustLib.instance.api
-          .crateApiBrowserServiceManagerBrowserServiceManagerSetPortRange(
-              that: this, minPort: minPort, maxPort: maxPort);
-
-  /// Starts the chromedriver service on the current port
-  Future<ServiceStatus> startService() => RustLib.instance.api
-          .crateApiBrowserServiceManagerBrowserServiceManagerStartService(
-        that: this,
-      );
-
-  /// Stops the chromedriver service
-  Future<void> stopService() => RustLib.instance.api
-          .crateApiBrowserServiceManagerBrowserServiceManagerStopService(
-        that: this,
-      );
-}
-
-@sealed
-class DatabaseImpl extends RustOpaque implements Database {
-  // Not to be used by end users
-  DatabaseImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  DatabaseImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Database,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Database,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_DatabasePtr,
-  );
-
-  /// Creates a backup of all data in JSON format
-  Future<void> createBackup({required String backupPath}) => RustLib
-      .instance.api
-      .crateApiDatabaseDatabaseCreateBackup(that: this, backupPath: backupPath);
-
-  Future<List<ProductRecord>> getAllProductsWithHistory() =>
-      RustLib.instance.api.crateApiDatabaseDatabaseGetAllProductsWithHistory(
-        that: this,
-      );
-
-  Future<ProductRecord?> getProductWithHistory({required String productId}) =>
-      RustLib.instance.api.crateApiDatabaseDatabaseGetProductWithHistory(
-          that: this, productId: productId);
-
-  Future<void> insertPriceEntry({required ProductDetails details}) => RustLib
-      .instance.api
-      .crateApiDatabaseDatabaseInsertPriceEntry(that: this, details: details);
-
-  Future<void> removeProduct({required String productId}) => RustLib
-      .instance.api
-      .crateApiDatabaseDatabaseRemoveProduct(that: this, productId: productId);
-
-  /// Restores data from a JSON backup file
-  Future<void> restoreFromBackup(
-          {required String backupPath, required bool replaceExisting}) =>
-      RustLib.instance.api.crateApiDatabaseDatabaseRestoreFromBackup(
-          that: this, backupPath: backupPath, replaceExisting: replaceExisting);
-
-  /// Inserts or updates a product's static details.
-  Future<void> upsertProduct({required ProductDetails details}) =>
-      RustLib.instance.api
-          .crateApiDatabaseDatabaseUpsertProduct(that: this, details: details);
-}
-
-@sealed
-class PriceEngineImpl extends RustOpaque implements PriceEngine {
-  // Not to be used by end users
-  PriceEngineImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  PriceEngineImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_PriceEngine,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_PriceEngine,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_PriceEnginePtr,
-  );
-
-  /// Checks the health status of the browser service
-  Future<PriceEngineStatus> checkServiceStatus() =>
-      RustLib.instance.api.crateApiPriceEnginePriceEngineCheckServiceStatus(
-        that: this,
-      );
-
-  /// Creates a backup of the database in JSON format
-  Future<void> createBackup({required String backupPath}) =>
-      RustLib.instance.api.crateApiPriceEnginePriceEngineCreateBackup(
-          that: this, backupPath: backupPath);
-
-  /// Fetches product details with automatic retry and fallback mechanisms
-  Future<ProductRecord> fetchAndUpdateProduct({required String url}) =>
-      RustLib.instance.api.crateApiPriceEnginePriceEngineFetchAndUpdateProduct(
-          that: this, url: url);
-
-  Future<List<ProductRecord>> getAllProductsInDb() =>
-      RustLib.instance.api.crateApiPriceEnginePriceEngineGetAllProductsInDb(
-        that: this,
-      );
-
-  /// Gets the current port being used by the browser service
-  Future<int> getCurrentPort() =>
-      RustLib.instance.api.crateApiPriceEnginePriceEngineGetCurrentPort(
-        that: this,
-      );
-
-  /// Removes a product from the database by its ID.
-  Future<void> removeProductById({required String productId}) =>
-      RustLib.instance.api.crateApiPriceEnginePriceEngineRemoveProductById(
-          that: this, productId: productId);
-
-  /// Restarts the browser service if needed
-  Future<ServiceStatus> restartBrowserService() =>
-      RustLib.instance.api.crateApiPriceEnginePriceEngineRestartBrowserService(
-        that: this,
-      );
-
-  /// Restores database from a JSON backup file
-  Future<void> restoreFromBackup(
-          {required String backupPath, required bool replaceExisting}) =>
-      RustLib.instance.api.crateApiPriceEnginePriceEngineRestoreFromBackup(
-          that: this, backupPath: backupPath, replaceExisting: replaceExisting);
-
-  /// Shuts down the browser engine gracefully.
-  Future<void> shutdown() =>
-      RustLib.instance.api.crateApiPriceEnginePriceEngineShutdown(
-        that: this,
-      );
-}
-
-@sealed
-class ProductDetailsImpl extends RustOpaque implements ProductDetails {
-  // Not to be used by end users
-  ProductDetailsImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  ProductDetailsImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_ProductDetails,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ProductDetails,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ProductDetailsPtr,
-  );
-
-  List<String> get features =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetFeatures(
-        that: this,
-      );
-
-  String get id =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetId(
-        that: this,
-      );
-
-  List<String> get images =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetImages(
-        that: this,
-      );
-
-  bool get inStock =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetInStock(
-        that: this,
-      );
-
-  int? get price =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetPrice(
-        that: this,
-      );
-
-  String get rating =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetRating(
-        that: this,
-      );
-
-  String? get seller =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetSeller(
-        that: this,
-      );
-
-  String get site =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetSite(
-        that: this,
-      );
-
-  Value get specifications => RustLib.instance.api
-          .crateApiModelsProductDetailsAutoAccessorGetSpecifications(
-        that: this,
-      );
-
-  String get title =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetTitle(
-        that: this,
-      );
-
-  String get url =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetUrl(
-        that: this,
-      );
-
-  set features(List<String> features) =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetFeatures(
-          that: this, features: features);
-
-  set id(String id) => RustLib.instance.api
-      .crateApiModelsProductDetailsAutoAccessorSetId(that: this, id: id);
-
-  set images(List<String> images) =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetImages(
-          that: this, images: images);
-
-  set inStock(bool inStock) =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetInStock(
-          that: this, inStock: inStock);
-
-  set price(int? price) =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetPrice(
-          that: this, price: price);
-
-  set rating(String rating) =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetRating(
-          that: this, rating: rating);
-
-  set seller(String? seller) =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetSeller(
-          that: this, seller: seller);
-
-  set site(String site) => RustLib.instance.api
-      .crateApiModelsProductDetailsAutoAccessorSetSite(that: this, site: site);
-
-  set specifications(Value specifications) => RustLib.instance.api
-      .crateApiModelsProductDetailsAutoAccessorSetSpecifications(
-          that: this, specifications: specifications);
-
-  set title(String title) =>
-      RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetTitle(
-          that: this, title: title);
-
-  set url(String url) => RustLib.instance.api
-      .crateApiModelsProductDetailsAutoAccessorSetUrl(that: this, url: url);
-}
-
-@sealed
-class ValueImpl extends RustOpaque implements Value {
-  // Not to be used by end users
-  ValueImpl.frbInternalDcoDecode(List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  ValueImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-      : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_Value,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_Value,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_ValuePtr,
-  );
-}
+        );
+        
+
+@override void crateApiModelsProductDetailsAutoAccessorSetUrl({required ProductDetails that , required String url })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(that, serializer);
+sse_encode_String(url, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsProductDetailsAutoAccessorSetUrlConstMeta,
+            argValues: [that, url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsProductDetailsAutoAccessorSetUrlConstMeta => const TaskConstMeta(
+            debugName: "ProductDetails_auto_accessor_set_url",
+            argNames: ["that", "url"],
+        );
+        
+
+@override Future<BrowserKind> crateApiBrowserServiceManagerBrowserKindDetect({required String driverPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(driverPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_browser_kind,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserKindDetectConstMeta,
+            argValues: [driverPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserKindDetectConstMeta => const TaskConstMeta(
+            debugName: "browser_kind_detect",
+            argNames: ["driverPath"],
+        );
+        
+
+@override Future<BrowserOptions> crateApiBrowserServiceManagerBrowserOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_browser_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBrowserServiceManagerBrowserOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBrowserServiceManagerBrowserOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "browser_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<DiscoveryReport> crateApiApinCheckBrowserBinaries({required BrowserKind browserKind , required String browserPath , required String driverPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_browser_kind(browserKind, serializer);
+sse_encode_String(browserPath, serializer);
+sse_encode_String(driverPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_discovery_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinCheckBrowserBinariesConstMeta,
+            argValues: [browserKind, browserPath, driverPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinCheckBrowserBinariesConstMeta => const TaskConstMeta(
+            debugName: "check_browser_binaries",
+            argNames: ["browserKind", "browserPath", "driverPath"],
+        );
+        
+
+@override Future<PriceEngineStatus> crateApiApinCheckServiceStatus({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_price_engine_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinCheckServiceStatusConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinCheckServiceStatusConstMeta => const TaskConstMeta(
+            debugName: "check_service_status",
+            argNames: ["engine"],
+        );
+        
+
+@override Future<ImageCacheStats> crateApiApinCleanupImageCache({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_image_cache_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinCleanupImageCacheConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinCleanupImageCacheConstMeta => const TaskConstMeta(
+            debugName: "cleanup_image_cache",
+            argNames: ["engine"],
+        );
+        
+
+@override Future<void> crateApiApinCreateBackup({required PriceEngine engine , required String backupPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(backupPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinCreateBackupConstMeta,
+            argValues: [engine, backupPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinCreateBackupConstMeta => const TaskConstMeta(
+            debugName: "create_backup",
+            argNames: ["engine", "backupPath"],
+        );
+        
+
+@override Future<DiscoveryReport> crateApiBinaryDiscoveryDiscover({required BrowserKind kind })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_browser_kind(kind, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_discovery_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBinaryDiscoveryDiscoverConstMeta,
+            argValues: [kind],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBinaryDiscoveryDiscoverConstMeta => const TaskConstMeta(
+            debugName: "discover",
+            argNames: ["kind"],
+        );
+        
+
+@override Future<DiscoveryReport> crateApiApinDiscoverBrowserBinaries({required BrowserKind browserKind })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_browser_kind(browserKind, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_discovery_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinDiscoverBrowserBinariesConstMeta,
+            argValues: [browserKind],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinDiscoverBrowserBinariesConstMeta => const TaskConstMeta(
+            debugName: "discover_browser_binaries",
+            argNames: ["browserKind"],
+        );
+        
+
+@override Future<String> crateApiApinExportCookies({required PriceEngine engine , required String domain , required CookieFormat format })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_cookie_format(format, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinExportCookiesConstMeta,
+            argValues: [engine, domain, format],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinExportCookiesConstMeta => const TaskConstMeta(
+            debugName: "export_cookies",
+            argNames: ["engine", "domain", "format"],
+        );
+        
+
+@override Future<ProductDetails> crateApiAmazonParserExtractDetails({required String htmlSource , required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(htmlSource, serializer);
+sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiAmazonParserExtractDetailsConstMeta,
+            argValues: [htmlSource, url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAmazonParserExtractDetailsConstMeta => const TaskConstMeta(
+            debugName: "extract_details",
+            argNames: ["htmlSource", "url"],
+        );
+        
+
+@override Future<ProductDetails> crateApiFlipkartParserExtractDetails({required String htmlSource , required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(htmlSource, serializer);
+sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiFlipkartParserExtractDetailsConstMeta,
+            argValues: [htmlSource, url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFlipkartParserExtractDetailsConstMeta => const TaskConstMeta(
+            debugName: "extract_details",
+            argNames: ["htmlSource", "url"],
+        );
+        
+
+@override Future<ProductRecord> crateApiApinFetchAndUpdateProduct({required PriceEngine engine , required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_product_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinFetchAndUpdateProductConstMeta,
+            argValues: [engine, url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinFetchAndUpdateProductConstMeta => const TaskConstMeta(
+            debugName: "fetch_and_update_product",
+            argNames: ["engine", "url"],
+        );
+        
+
+@override Future<bool> crateApiFetchErrorFetchErrorKindIsRetryable({required FetchErrorKind that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_fetch_error_kind(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiFetchErrorFetchErrorKindIsRetryableConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFetchErrorFetchErrorKindIsRetryableConstMeta => const TaskConstMeta(
+            debugName: "fetch_error_kind_is_retryable",
+            argNames: ["that"],
+        );
+        
+
+@override Future<FetchTimeouts> crateApiTimeoutsFetchTimeoutsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fetch_timeouts,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiTimeoutsFetchTimeoutsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiTimeoutsFetchTimeoutsDefaultConstMeta => const TaskConstMeta(
+            debugName: "fetch_timeouts_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<ProductRecord>> crateApiApinGetAllProductsInDb({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_product_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetAllProductsInDbConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetAllProductsInDbConstMeta => const TaskConstMeta(
+            debugName: "get_all_products_in_db",
+            argNames: ["engine"],
+        );
+        
+
+@override Future<DiscoveryReport> crateApiApinGetBrowserBinaries({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_discovery_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetBrowserBinariesConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetBrowserBinariesConstMeta => const TaskConstMeta(
+            debugName: "get_browser_binaries",
+            argNames: ["engine"],
+        );
+        
+
+@override Future<int> crateApiApinGetCurrentPort({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_16,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetCurrentPortConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetCurrentPortConstMeta => const TaskConstMeta(
+            debugName: "get_current_port",
+            argNames: ["engine"],
+        );
+        
+
+@override Future<List<DiagnosticCapture>> crateApiApinGetDiagnosticCaptures({required PriceEngine engine , required String productId , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(productId, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_diagnostic_capture,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetDiagnosticCapturesConstMeta,
+            argValues: [engine, productId, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetDiagnosticCapturesConstMeta => const TaskConstMeta(
+            debugName: "get_diagnostic_captures",
+            argNames: ["engine", "productId", "limit"],
+        );
+        
+
+@override Future<List<DiagnosticCapture>> crateApiApinGetDiagnosticCapturesForUrl({required PriceEngine engine , String? url , required int limit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_opt_String(url, serializer);
+sse_encode_u_32(limit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_diagnostic_capture,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetDiagnosticCapturesForUrlConstMeta,
+            argValues: [engine, url, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetDiagnosticCapturesForUrlConstMeta => const TaskConstMeta(
+            debugName: "get_diagnostic_captures_for_url",
+            argNames: ["engine", "url", "limit"],
+        );
+        
+
+@override Future<List<String>> crateApiApinGetDriverLogTail({required PriceEngine engine , required int lines })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_u_32(lines, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetDriverLogTailConstMeta,
+            argValues: [engine, lines],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetDriverLogTailConstMeta => const TaskConstMeta(
+            debugName: "get_driver_log_tail",
+            argNames: ["engine", "lines"],
+        );
+        
+
+@override Future<List<FetchPathStats>> crateApiApinGetFetchPathStats({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_fetch_path_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetFetchPathStatsConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetFetchPathStatsConstMeta => const TaskConstMeta(
+            debugName: "get_fetch_path_stats",
+            argNames: ["engine"],
+        );
+        
+
+@override Future<FetchTimeouts> crateApiApinGetFetchTimeouts({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fetch_timeouts,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetFetchTimeoutsConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetFetchTimeoutsConstMeta => const TaskConstMeta(
+            debugName: "get_fetch_timeouts",
+            argNames: ["engine"],
+        );
+        
+
+@override Future<List<FetchTimingStats>> crateApiApinGetFetchTimingStats({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_fetch_timing_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetFetchTimingStatsConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetFetchTimingStatsConstMeta => const TaskConstMeta(
+            debugName: "get_fetch_timing_stats",
+            argNames: ["engine"],
+        );
+        
+
+@override Future<PriceEngine> crateApiApinGetHttpOnlyPriceEngine({required String dbPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(dbPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetHttpOnlyPriceEngineConstMeta,
+            argValues: [dbPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetHttpOnlyPriceEngineConstMeta => const TaskConstMeta(
+            debugName: "get_http_only_price_engine",
+            argNames: ["dbPath"],
+        );
+        
+
+@override Future<ImageCacheStats> crateApiApinGetImageCacheStats({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_image_cache_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetImageCacheStatsConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetImageCacheStatsConstMeta => const TaskConstMeta(
+            debugName: "get_image_cache_stats",
+            argNames: ["engine"],
+        );
+        
+
+@override Future<PriceEngine> crateApiApinGetPriceEngine({required int port , required String browserPath , required String dbPath , required String driverPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_16(port, serializer);
+sse_encode_String(browserPath, serializer);
+sse_encode_String(dbPath, serializer);
+sse_encode_String(driverPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetPriceEngineConstMeta,
+            argValues: [port, browserPath, dbPath, driverPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetPriceEngineConstMeta => const TaskConstMeta(
+            debugName: "get_price_engine",
+            argNames: ["port", "browserPath", "dbPath", "driverPath"],
+        );
+        
+
+@override Future<PriceEngine> crateApiApinGetPriceEngineForBrowser({required int port , required BrowserKind browserKind , required String browserPath , required String dbPath , required String driverPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_16(port, serializer);
+sse_encode_browser_kind(browserKind, serializer);
+sse_encode_String(browserPath, serializer);
+sse_encode_String(dbPath, serializer);
+sse_encode_String(driverPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetPriceEngineForBrowserConstMeta,
+            argValues: [port, browserKind, browserPath, dbPath, driverPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetPriceEngineForBrowserConstMeta => const TaskConstMeta(
+            debugName: "get_price_engine_for_browser",
+            argNames: ["port", "browserKind", "browserPath", "dbPath", "driverPath"],
+        );
+        
+
+@override Future<PriceEngine> crateApiApinGetPriceEngineWithOptions({required int port , required BrowserKind browserKind , required BrowserOptions options , required String browserPath , required String dbPath , required String driverPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_16(port, serializer);
+sse_encode_browser_kind(browserKind, serializer);
+sse_encode_box_autoadd_browser_options(options, serializer);
+sse_encode_String(browserPath, serializer);
+sse_encode_String(dbPath, serializer);
+sse_encode_String(driverPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetPriceEngineWithOptionsConstMeta,
+            argValues: [port, browserKind, options, browserPath, dbPath, driverPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetPriceEngineWithOptionsConstMeta => const TaskConstMeta(
+            debugName: "get_price_engine_with_options",
+            argNames: ["port", "browserKind", "options", "browserPath", "dbPath", "driverPath"],
+        );
+        
+
+@override Future<List<ProxyStatus>> crateApiApinGetProxyStatus({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_proxy_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetProxyStatusConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetProxyStatusConstMeta => const TaskConstMeta(
+            debugName: "get_proxy_status",
+            argNames: ["engine"],
+        );
+        
+
+@override Future<PriceEngine> crateApiApinGetRemotePriceEngine({required String webdriverUrl , required BrowserKind browserKind , required BrowserOptions options , required String dbPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(webdriverUrl, serializer);
+sse_encode_browser_kind(browserKind, serializer);
+sse_encode_box_autoadd_browser_options(options, serializer);
+sse_encode_String(dbPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetRemotePriceEngineConstMeta,
+            argValues: [webdriverUrl, browserKind, options, dbPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetRemotePriceEngineConstMeta => const TaskConstMeta(
+            debugName: "get_remote_price_engine",
+            argNames: ["webdriverUrl", "browserKind", "options", "dbPath"],
+        );
+        
+
+@override Future<List<SiteConfig>> crateApiApinGetSiteConfigs({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_site_config,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinGetSiteConfigsConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinGetSiteConfigsConstMeta => const TaskConstMeta(
+            debugName: "get_site_configs",
+            argNames: ["engine"],
+        );
+        
+
+@override Future<int> crateApiApinImportCookies({required PriceEngine engine , required String domain , required String content , required CookieFormat format })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_String(content, serializer);
+sse_encode_cookie_format(format, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinImportCookiesConstMeta,
+            argValues: [engine, domain, content, format],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinImportCookiesConstMeta => const TaskConstMeta(
+            debugName: "import_cookies",
+            argNames: ["engine", "domain", "content", "format"],
+        );
+        
+
+@override Future<DiscoveryReport> crateApiBinaryDiscoveryInspect({required BrowserKind kind , required String browserPath , required String driverPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_browser_kind(kind, serializer);
+sse_encode_String(browserPath, serializer);
+sse_encode_String(driverPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_discovery_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiBinaryDiscoveryInspectConstMeta,
+            argValues: [kind, browserPath, driverPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiBinaryDiscoveryInspectConstMeta => const TaskConstMeta(
+            debugName: "inspect",
+            argNames: ["kind", "browserPath", "driverPath"],
+        );
+        
+
+@override Future<int> crateApiMigrationsLatestVersion()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 152, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMigrationsLatestVersionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMigrationsLatestVersionConstMeta => const TaskConstMeta(
+            debugName: "latest_version",
+            argNames: [],
+        );
+        
+
+@override double crateApiMoneyMoneyAsMajor({required Money that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_money(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 153)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMoneyMoneyAsMajorConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoneyMoneyAsMajorConstMeta => const TaskConstMeta(
+            debugName: "money_as_major",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiMoneyMoneyDisplay({required Money that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_money(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 154)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMoneyMoneyDisplayConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoneyMoneyDisplayConstMeta => const TaskConstMeta(
+            debugName: "money_display",
+            argNames: ["that"],
+        );
+        
+
+@override Future<Money> crateApiMoneyMoneyFromMajor({required PlatformInt64 major , required String currency })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(major, serializer);
+sse_encode_String(currency, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 155, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_money,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMoneyMoneyFromMajorConstMeta,
+            argValues: [major, currency],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoneyMoneyFromMajorConstMeta => const TaskConstMeta(
+            debugName: "money_from_major",
+            argNames: ["major", "currency"],
+        );
+        
+
+@override Future<Money> crateApiMoneyMoneyNew({required PlatformInt64 minorUnits , required String currency })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_i_64(minorUnits, serializer);
+sse_encode_String(currency, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 156, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_money,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMoneyMoneyNewConstMeta,
+            argValues: [minorUnits, currency],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoneyMoneyNewConstMeta => const TaskConstMeta(
+            debugName: "money_new",
+            argNames: ["minorUnits", "currency"],
+        );
+        
+
+@override Future<Money?> crateApiMoneyMoneyParse({required String text , required String defaultCurrency })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(text, serializer);
+sse_encode_String(defaultCurrency, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 157, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_money,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMoneyMoneyParseConstMeta,
+            argValues: [text, defaultCurrency],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoneyMoneyParseConstMeta => const TaskConstMeta(
+            debugName: "money_parse",
+            argNames: ["text", "defaultCurrency"],
+        );
+        
+
+@override Future<DiagnosticCaptureContent> crateApiApinOpenDiagnosticCapture({required PriceEngine engine , required String captureId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(captureId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 158, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_diagnostic_capture_content,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinOpenDiagnosticCaptureConstMeta,
+            argValues: [engine, captureId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinOpenDiagnosticCaptureConstMeta => const TaskConstMeta(
+            debugName: "open_diagnostic_capture",
+            argNames: ["engine", "captureId"],
+        );
+        
+
+@override Future<RateLimit> crateApiRateLimiterRateLimitDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 159, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_rate_limit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRateLimiterRateLimitDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRateLimiterRateLimitDefaultConstMeta => const TaskConstMeta(
+            debugName: "rate_limit_default",
+            argNames: [],
+        );
+        
+
+@override Future<RecyclePolicy> crateApiRecyclePolicyRecyclePolicyDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 160, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_recycle_policy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRecyclePolicyRecyclePolicyDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRecyclePolicyRecyclePolicyDefaultConstMeta => const TaskConstMeta(
+            debugName: "recycle_policy_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<RefreshOutcome>> crateApiApinRefreshAllProducts({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 161, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_refresh_outcome,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinRefreshAllProductsConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinRefreshAllProductsConstMeta => const TaskConstMeta(
+            debugName: "refresh_all_products",
+            argNames: ["engine"],
+        );
+        
+
+@override Future<void> crateApiApinRemoveProductById({required PriceEngine engine , required String productId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(productId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 162, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinRemoveProductByIdConstMeta,
+            argValues: [engine, productId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinRemoveProductByIdConstMeta => const TaskConstMeta(
+            debugName: "remove_product_by_id",
+            argNames: ["engine", "productId"],
+        );
+        
+
+@override Future<String> crateApiApinRestartBrowserService({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 163, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinRestartBrowserServiceConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinRestartBrowserServiceConstMeta => const TaskConstMeta(
+            debugName: "restart_browser_service",
+            argNames: ["engine"],
+        );
+        
+
+@override Future<void> crateApiApinRestoreFromBackup({required PriceEngine engine , required String backupPath , required bool replaceExisting })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(backupPath, serializer);
+sse_encode_bool(replaceExisting, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 164, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinRestoreFromBackupConstMeta,
+            argValues: [engine, backupPath, replaceExisting],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinRestoreFromBackupConstMeta => const TaskConstMeta(
+            debugName: "restore_from_backup",
+            argNames: ["engine", "backupPath", "replaceExisting"],
+        );
+        
+
+@override Future<RetryPolicy> crateApiRetryPolicyRetryPolicyDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 165, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_retry_policy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiRetryPolicyRetryPolicyDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiRetryPolicyRetryPolicyDefaultConstMeta => const TaskConstMeta(
+            debugName: "retry_policy_default",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiApinSetBrowserPoolSize({required PriceEngine engine , required int poolSize })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_u_32(poolSize, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 166, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinSetBrowserPoolSizeConstMeta,
+            argValues: [engine, poolSize],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinSetBrowserPoolSizeConstMeta => const TaskConstMeta(
+            debugName: "set_browser_pool_size",
+            argNames: ["engine", "poolSize"],
+        );
+        
+
+@override Future<void> crateApiApinSetBrowserRecyclePolicy({required PriceEngine engine , required RecyclePolicy policy })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_box_autoadd_recycle_policy(policy, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 167, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinSetBrowserRecyclePolicyConstMeta,
+            argValues: [engine, policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinSetBrowserRecyclePolicyConstMeta => const TaskConstMeta(
+            debugName: "set_browser_recycle_policy",
+            argNames: ["engine", "policy"],
+        );
+        
+
+@override Future<void> crateApiApinSetDefaultProxies({required PriceEngine engine , required List<String> proxies })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_list_String(proxies, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 168, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinSetDefaultProxiesConstMeta,
+            argValues: [engine, proxies],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinSetDefaultProxiesConstMeta => const TaskConstMeta(
+            debugName: "set_default_proxies",
+            argNames: ["engine", "proxies"],
+        );
+        
+
+@override Future<void> crateApiApinSetDiagnosticsRetention({required PriceEngine engine , required int maxCaptures })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_u_32(maxCaptures, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 169, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinSetDiagnosticsRetentionConstMeta,
+            argValues: [engine, maxCaptures],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinSetDiagnosticsRetentionConstMeta => const TaskConstMeta(
+            debugName: "set_diagnostics_retention",
+            argNames: ["engine", "maxCaptures"],
+        );
+        
+
+@override Future<void> crateApiApinSetFetchTimeouts({required PriceEngine engine , required FetchTimeouts timeouts })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_box_autoadd_fetch_timeouts(timeouts, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 170, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinSetFetchTimeoutsConstMeta,
+            argValues: [engine, timeouts],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinSetFetchTimeoutsConstMeta => const TaskConstMeta(
+            debugName: "set_fetch_timeouts",
+            argNames: ["engine", "timeouts"],
+        );
+        
+
+@override Future<void> crateApiApinSetSiteAllowedResources({required PriceEngine engine , required String domain , required List<ResourceType> allowed })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_list_resource_type(allowed, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 171, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinSetSiteAllowedResourcesConstMeta,
+            argValues: [engine, domain, allowed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinSetSiteAllowedResourcesConstMeta => const TaskConstMeta(
+            debugName: "set_site_allowed_resources",
+            argNames: ["engine", "domain", "allowed"],
+        );
+        
+
+@override Future<void> crateApiApinSetSiteBlockCooldown({required PriceEngine engine , required String domain , required int cooldownMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_u_32(cooldownMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 172, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinSetSiteBlockCooldownConstMeta,
+            argValues: [engine, domain, cooldownMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinSetSiteBlockCooldownConstMeta => const TaskConstMeta(
+            debugName: "set_site_block_cooldown",
+            argNames: ["engine", "domain", "cooldownMs"],
+        );
+        
+
+@override Future<void> crateApiApinSetSiteFallback({required PriceEngine engine , required String domain , required bool enabled })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_bool(enabled, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 173, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinSetSiteFallbackConstMeta,
+            argValues: [engine, domain, enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinSetSiteFallbackConstMeta => const TaskConstMeta(
+            debugName: "set_site_fallback",
+            argNames: ["engine", "domain", "enabled"],
+        );
+        
+
+@override Future<void> crateApiApinSetSiteFetcher({required PriceEngine engine , required String domain , required FetcherKind fetcher })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_fetcher_kind(fetcher, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 174, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinSetSiteFetcherConstMeta,
+            argValues: [engine, domain, fetcher],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinSetSiteFetcherConstMeta => const TaskConstMeta(
+            debugName: "set_site_fetcher",
+            argNames: ["engine", "domain", "fetcher"],
+        );
+        
+
+@override Future<void> crateApiApinSetSiteProxies({required PriceEngine engine , required String domain , required List<String> proxies })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_list_String(proxies, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 175, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinSetSiteProxiesConstMeta,
+            argValues: [engine, domain, proxies],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinSetSiteProxiesConstMeta => const TaskConstMeta(
+            debugName: "set_site_proxies",
+            argNames: ["engine", "domain", "proxies"],
+        );
+        
+
+@override Future<void> crateApiApinSetSiteRateLimit({required PriceEngine engine , required String domain , required RateLimit rateLimit })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_box_autoadd_rate_limit(rateLimit, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 176, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinSetSiteRateLimitConstMeta,
+            argValues: [engine, domain, rateLimit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinSetSiteRateLimitConstMeta => const TaskConstMeta(
+            debugName: "set_site_rate_limit",
+            argNames: ["engine", "domain", "rateLimit"],
+        );
+        
+
+@override Future<void> crateApiApinSetSiteReadyCondition({required PriceEngine engine , required String domain , required ReadyCondition condition , required int timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_box_autoadd_ready_condition(condition, serializer);
+sse_encode_u_32(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 177, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinSetSiteReadyConditionConstMeta,
+            argValues: [engine, domain, condition, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinSetSiteReadyConditionConstMeta => const TaskConstMeta(
+            debugName: "set_site_ready_condition",
+            argNames: ["engine", "domain", "condition", "timeoutMs"],
+        );
+        
+
+@override Future<void> crateApiApinSetSiteRetryPolicy({required PriceEngine engine , required String domain , required RetryPolicy retryPolicy })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_String(domain, serializer);
+sse_encode_box_autoadd_retry_policy(retryPolicy, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 178, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinSetSiteRetryPolicyConstMeta,
+            argValues: [engine, domain, retryPolicy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinSetSiteRetryPolicyConstMeta => const TaskConstMeta(
+            debugName: "set_site_retry_policy",
+            argNames: ["engine", "domain", "retryPolicy"],
+        );
+        
+
+@override Future<void> crateApiApinShutdownPriceEngine({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 179, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinShutdownPriceEngineConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinShutdownPriceEngineConstMeta => const TaskConstMeta(
+            debugName: "shutdown_price_engine",
+            argNames: ["engine"],
+        );
+        
+
+@override Future<bool> crateApiSiteConfigSiteConfigMatches({required SiteConfig that , required String url })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_site_config(that, serializer);
+sse_encode_String(url, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 180, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSiteConfigSiteConfigMatchesConstMeta,
+            argValues: [that, url],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSiteConfigSiteConfigMatchesConstMeta => const TaskConstMeta(
+            debugName: "site_config_matches",
+            argNames: ["that", "url"],
+        );
+        
+
+@override Future<SiteConfig> crateApiSiteConfigSiteConfigNew({required String domain })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(domain, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 181, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_site_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSiteConfigSiteConfigNewConstMeta,
+            argValues: [domain],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSiteConfigSiteConfigNewConstMeta => const TaskConstMeta(
+            debugName: "site_config_new",
+            argNames: ["domain"],
+        );
+        
+
+@override Future<void> crateApiApinStartServiceWatchdog({required PriceEngine engine , required WatchdogConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_box_autoadd_watchdog_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 182, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinStartServiceWatchdogConstMeta,
+            argValues: [engine, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinStartServiceWatchdogConstMeta => const TaskConstMeta(
+            debugName: "start_service_watchdog",
+            argNames: ["engine", "config"],
+        );
+        
+
+@override Future<void> crateApiApinStopServiceWatchdog({required PriceEngine engine })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 183, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinStopServiceWatchdogConstMeta,
+            argValues: [engine],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiApinStopServiceWatchdogConstMeta => const TaskConstMeta(
+            debugName: "stop_service_watchdog",
+            argNames: ["engine"],
+        );
+        
+
+@override Stream<EngineEvent> crateApiApinSubscribeEngineEvents({required PriceEngine engine })  { 
+            final sink = RustStreamSink<EngineEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(engine, serializer);
+sse_encode_StreamSink_engine_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 184, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        )
+        ,
+            constMeta: kCrateApiApinSubscribeEngineEventsConstMeta,
+            argValues: [engine, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiApinSubscribeEngineEventsConstMeta => const TaskConstMeta(
+            debugName: "subscribe_engine_events",
+            argNames: ["engine", "sink"],
+        );
+        
+
+@override Future<WatchdogConfig> crateApiWatchdogWatchdogConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 185, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_watchdog_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiWatchdogWatchdogConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiWatchdogWatchdogConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "watchdog_config_default",
+            argNames: [],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BrowserEngine => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_BrowserEngine => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_BrowserServiceManager => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_BrowserServiceManager => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Database => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Database => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PriceEngine => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PriceEngine => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ProductDetails => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ProductDetails => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Value => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_Value => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected BrowserEngine dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BrowserEngineImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected BrowserServiceManager dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BrowserServiceManagerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Database dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DatabaseImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PriceEngine dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PriceEngineImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ProductDetails dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProductDetailsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Value dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ValueImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected BrowserEngine dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BrowserEngineImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected BrowserServiceManager dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BrowserServiceManagerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PriceEngine dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PriceEngineImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ProductDetails dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProductDetailsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected BrowserEngine dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BrowserEngineImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected BrowserServiceManager dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BrowserServiceManagerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Database dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DatabaseImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PriceEngine dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PriceEngineImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ProductDetails dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProductDetailsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected BrowserEngine dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BrowserEngineImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected BrowserServiceManager dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BrowserServiceManagerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Database dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DatabaseImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PriceEngine dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PriceEngineImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ProductDetails dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProductDetailsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Value dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ValueImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected Set<String> dco_decode_Set_String_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Set.from(dco_decode_list_String(raw)); }
+
+@protected RustStreamSink<EngineEvent> dco_decode_StreamSink_engine_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected BinaryInfo dco_decode_binary_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return BinaryInfo(path: dco_decode_String(arr[0]),
+version: dco_decode_opt_String(arr[1]),
+majorVersion: dco_decode_opt_box_autoadd_u_32(arr[2]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected BinaryInfo dco_decode_box_autoadd_binary_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_binary_info(raw); }
+
+@protected BrowserOptions dco_decode_box_autoadd_browser_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_browser_options(raw); }
+
+@protected CachedImage dco_decode_box_autoadd_cached_image(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_cached_image(raw); }
+
+@protected FetchTimeouts dco_decode_box_autoadd_fetch_timeouts(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_fetch_timeouts(raw); }
+
+@protected Money dco_decode_box_autoadd_money(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_money(raw); }
+
+@protected ProductRecord dco_decode_box_autoadd_product_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_product_record(raw); }
+
+@protected RateLimit dco_decode_box_autoadd_rate_limit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_rate_limit(raw); }
+
+@protected ReadyCondition dco_decode_box_autoadd_ready_condition(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ready_condition(raw); }
+
+@protected RecyclePolicy dco_decode_box_autoadd_recycle_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_recycle_policy(raw); }
+
+@protected RetryPolicy dco_decode_box_autoadd_retry_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_retry_policy(raw); }
+
+@protected SiteConfig dco_decode_box_autoadd_site_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_site_config(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected WatchdogConfig dco_decode_box_autoadd_watchdog_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_watchdog_config(raw); }
+
+@protected BrowserEngineStatus dco_decode_browser_engine_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BrowserEngineStatus(isRunning: dco_decode_bool(arr[0]),
+currentPort: dco_decode_u_16(arr[1]),
+message: dco_decode_String(arr[2]),
+requiresRestart: dco_decode_bool(arr[3]),); }
+
+@protected BrowserKind dco_decode_browser_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BrowserKind.values[raw as int]; }
+
+@protected BrowserOptions dco_decode_browser_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return BrowserOptions(userDataDir: dco_decode_opt_String(arr[0]),
+extraArgs: dco_decode_list_String(arr[1]),
+windowWidth: dco_decode_u_32(arr[2]),
+windowHeight: dco_decode_u_32(arr[3]),
+language: dco_decode_opt_String(arr[4]),
+userAgent: dco_decode_opt_String(arr[5]),
+headless: dco_decode_bool(arr[6]),
+blockedResources: dco_decode_list_resource_type(arr[7]),); }
+
+@protected CachedImage dco_decode_cached_image(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return CachedImage(url: dco_decode_String(arr[0]),
+contentHash: dco_decode_String(arr[1]),
+localPath: dco_decode_String(arr[2]),
+thumbnailPath: dco_decode_String(arr[3]),); }
+
+@protected CookieFormat dco_decode_cookie_format(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CookieFormat.values[raw as int]; }
+
+@protected DiagnosticCapture dco_decode_diagnostic_capture(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return DiagnosticCapture(id: dco_decode_String(arr[0]),
+url: dco_decode_String(arr[1]),
+fetcher: dco_decode_String(arr[2]),
+reason: dco_decode_String(arr[3]),
+timestamp: dco_decode_String(arr[4]),
+htmlPath: dco_decode_opt_String(arr[5]),
+screenshotPath: dco_decode_opt_String(arr[6]),); }
+
+@protected DiagnosticCaptureContent dco_decode_diagnostic_capture_content(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return DiagnosticCaptureContent(capture: dco_decode_diagnostic_capture(arr[0]),
+html: dco_decode_opt_String(arr[1]),
+screenshotPng: dco_decode_opt_list_prim_u_8_strict(arr[2]),); }
+
+@protected DiscoveryReport dco_decode_discovery_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return DiscoveryReport(browserKind: dco_decode_browser_kind(arr[0]),
+browser: dco_decode_opt_box_autoadd_binary_info(arr[1]),
+driver: dco_decode_opt_box_autoadd_binary_info(arr[2]),
+compatible: dco_decode_bool(arr[3]),
+message: dco_decode_String(arr[4]),); }
+
+@protected DomainRateStatus dco_decode_domain_rate_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return DomainRateStatus(domain: dco_decode_String(arr[0]),
+waiting: dco_decode_u_32(arr[1]),
+nextSlotInMs: dco_decode_u_64(arr[2]),
+cooldownRemainingMs: dco_decode_u_64(arr[3]),); }
+
+@protected EngineEvent dco_decode_engine_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return EngineEvent_SiteBlocked(domain: dco_decode_String(raw[1]),url: dco_decode_String(raw[2]),reason: dco_decode_String(raw[3]),cooldownMs: dco_decode_u_64(raw[4]),timestamp: dco_decode_String(raw[5]),);
+case 1: return EngineEvent_ServiceStatusChanged(isHealthy: dco_decode_bool(raw[1]),port: dco_decode_u_16(raw[2]),message: dco_decode_String(raw[3]),timestamp: dco_decode_String(raw[4]),);
+case 2: return EngineEvent_ServiceRestartsExhausted(restarts: dco_decode_u_32(raw[1]),windowMs: dco_decode_u_64(raw[2]),timestamp: dco_decode_String(raw[3]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FetchErrorKind dco_decode_fetch_error_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FetchErrorKind.values[raw as int]; }
+
+@protected FetchPathStats dco_decode_fetch_path_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FetchPathStats(site: dco_decode_String(arr[0]),
+fetcher: dco_decode_String(arr[1]),
+checks: dco_decode_u_32(arr[2]),
+fallbacks: dco_decode_u_32(arr[3]),
+lastUsed: dco_decode_String(arr[4]),); }
+
+@protected FetchTimeouts dco_decode_fetch_timeouts(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FetchTimeouts(pageLoadMs: dco_decode_u_64(arr[0]),
+scriptMs: dco_decode_u_64(arr[1]),
+implicitWaitMs: dco_decode_u_64(arr[2]),
+fetchMs: dco_decode_u_64(arr[3]),
+statusCheckMs: dco_decode_u_64(arr[4]),); }
+
+@protected FetchTimingStats dco_decode_fetch_timing_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return FetchTimingStats(site: dco_decode_String(arr[0]),
+fetcher: dco_decode_String(arr[1]),
+blockedResources: dco_decode_String(arr[2]),
+fetches: dco_decode_u_32(arr[3]),
+avgDurationMs: dco_decode_u_64(arr[4]),
+maxDurationMs: dco_decode_u_64(arr[5]),); }
+
+@protected FetcherKind dco_decode_fetcher_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FetcherKind.values[raw as int]; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected ImageCacheStats dco_decode_image_cache_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ImageCacheStats(fileCount: dco_decode_u_32(arr[0]),
+totalBytes: dco_decode_u_64(arr[1]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<CachedImage> dco_decode_list_cached_image(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_cached_image).toList(); }
+
+@protected List<DiagnosticCapture> dco_decode_list_diagnostic_capture(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_diagnostic_capture).toList(); }
+
+@protected List<DomainRateStatus> dco_decode_list_domain_rate_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_domain_rate_status).toList(); }
+
+@protected List<FetchPathStats> dco_decode_list_fetch_path_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_fetch_path_stats).toList(); }
+
+@protected List<FetchTimingStats> dco_decode_list_fetch_timing_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_fetch_timing_stats).toList(); }
+
+@protected List<PriceEntry> dco_decode_list_price_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_price_entry).toList(); }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<ProductRecord> dco_decode_list_product_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_product_record).toList(); }
+
+@protected List<ProxyStatus> dco_decode_list_proxy_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_proxy_status).toList(); }
+
+@protected List<RefreshOutcome> dco_decode_list_refresh_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_refresh_outcome).toList(); }
+
+@protected List<ResourceType> dco_decode_list_resource_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_resource_type).toList(); }
+
+@protected List<SealedCookieJar> dco_decode_list_sealed_cookie_jar(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_sealed_cookie_jar).toList(); }
+
+@protected List<SiteConfig> dco_decode_list_site_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_site_config).toList(); }
+
+@protected Money dco_decode_money(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return Money(minorUnits: dco_decode_i_64(arr[0]),
+currency: dco_decode_String(arr[1]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected BinaryInfo? dco_decode_opt_box_autoadd_binary_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_binary_info(raw); }
+
+@protected CachedImage? dco_decode_opt_box_autoadd_cached_image(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_cached_image(raw); }
+
+@protected Money? dco_decode_opt_box_autoadd_money(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_money(raw); }
+
+@protected ProductRecord? dco_decode_opt_box_autoadd_product_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_product_record(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_u_8_strict(raw); }
+
+@protected PriceEngineStatus dco_decode_price_engine_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return PriceEngineStatus(isHealthy: dco_decode_bool(arr[0]),
+currentPort: dco_decode_u_16(arr[1]),
+message: dco_decode_String(arr[2]),
+lastCheck: dco_decode_String(arr[3]),
+rateLimits: dco_decode_list_domain_rate_status(arr[4]),); }
+
+@protected PriceEntry dco_decode_price_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PriceEntry(price: dco_decode_money(arr[0]),
+inStock: dco_decode_bool(arr[1]),
+timestamp: dco_decode_String(arr[2]),); }
+
+@protected ProductRecord dco_decode_product_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return ProductRecord(id: dco_decode_String(arr[0]),
+site: dco_decode_String(arr[1]),
+url: dco_decode_String(arr[2]),
+title: dco_decode_String(arr[3]),
+seller: dco_decode_opt_String(arr[4]),
+images: dco_decode_list_String(arr[5]),
+specifications: dco_decode_String(arr[6]),
+features: dco_decode_list_String(arr[7]),
+priceHistory: dco_decode_list_price_entry(arr[8]),
+cachedImages: dco_decode_list_cached_image(arr[9]),); }
+
+@protected ProxyStatus dco_decode_proxy_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ProxyStatus(url: dco_decode_String(arr[0]),
+healthy: dco_decode_bool(arr[1]),
+consecutiveFailures: dco_decode_u_32(arr[2]),
+retryInMs: dco_decode_u_64(arr[3]),); }
+
+@protected RateLimit dco_decode_rate_limit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return RateLimit(requestsPerMinute: dco_decode_u_32(arr[0]),
+burst: dco_decode_u_32(arr[1]),
+minSpacingMs: dco_decode_u_32(arr[2]),
+jitterMs: dco_decode_u_32(arr[3]),); }
+
+@protected ReadyCondition dco_decode_ready_condition(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ReadyCondition_DocumentComplete();
+case 1: return ReadyCondition_ElementPresent(dco_decode_String(raw[1]),);
+case 2: return ReadyCondition_NetworkIdle(quietMs: dco_decode_u_32(raw[1]),);
+case 3: return ReadyCondition_Script(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected (BrowserEngine,ServiceStatus) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_browser_engine_service_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(arr[0]),dco_decode_service_status(arr[1]),); }
+
+@protected RecyclePolicy dco_decode_recycle_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RecyclePolicy(maxNavigations: dco_decode_u_32(arr[0]),
+maxSessionAgeMs: dco_decode_u_64(arr[1]),
+maxMemoryMb: dco_decode_u_32(arr[2]),); }
+
+@protected RefreshOutcome dco_decode_refresh_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return RefreshOutcome(url: dco_decode_String(arr[0]),
+product: dco_decode_opt_box_autoadd_product_record(arr[1]),
+error: dco_decode_opt_String(arr[2]),); }
+
+@protected ResourceType dco_decode_resource_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ResourceType.values[raw as int]; }
+
+@protected RetryPolicy dco_decode_retry_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return RetryPolicy(maxAttempts: dco_decode_u_32(arr[0]),
+initialBackoffMs: dco_decode_u_32(arr[1]),
+maxBackoffMs: dco_decode_u_32(arr[2]),
+backoffMultiplier: dco_decode_f_64(arr[3]),); }
+
+@protected SealedCookieJar dco_decode_sealed_cookie_jar(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SealedCookieJar(domain: dco_decode_String(arr[0]),
+nonce: dco_decode_list_prim_u_8_strict(arr[1]),
+ciphertext: dco_decode_list_prim_u_8_strict(arr[2]),); }
+
+@protected ServiceStatus dco_decode_service_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ServiceStatus(isHealthy: dco_decode_bool(arr[0]),
+port: dco_decode_u_16(arr[1]),
+errorMessage: dco_decode_opt_String(arr[2]),
+startupMs: dco_decode_u_64(arr[3]),); }
+
+@protected SiteConfig dco_decode_site_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return SiteConfig(domain: dco_decode_String(arr[0]),
+fetcher: dco_decode_fetcher_kind(arr[1]),
+fallback: dco_decode_bool(arr[2]),
+readyCondition: dco_decode_ready_condition(arr[3]),
+readyTimeoutMs: dco_decode_u_32(arr[4]),
+rateLimit: dco_decode_rate_limit(arr[5]),
+retryPolicy: dco_decode_retry_policy(arr[6]),
+blockCooldownMs: dco_decode_u_32(arr[7]),
+proxies: dco_decode_list_String(arr[8]),
+allowedResources: dco_decode_list_resource_type(arr[9]),); }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected WatchdogConfig dco_decode_watchdog_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return WatchdogConfig(intervalMs: dco_decode_u_64(arr[0]),
+maxRestarts: dco_decode_u_32(arr[1]),
+restartWindowMs: dco_decode_u_64(arr[2]),); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected BrowserEngine sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return BrowserEngineImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected BrowserServiceManager sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return BrowserServiceManagerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Database sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return DatabaseImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PriceEngine sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PriceEngineImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ProductDetails sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProductDetailsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Value sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ValueImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected BrowserEngine sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return BrowserEngineImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected BrowserServiceManager sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return BrowserServiceManagerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PriceEngine sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PriceEngineImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ProductDetails sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProductDetailsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected BrowserEngine sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return BrowserEngineImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected BrowserServiceManager sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return BrowserServiceManagerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Database sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return DatabaseImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PriceEngine sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PriceEngineImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ProductDetails sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProductDetailsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected BrowserEngine sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return BrowserEngineImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected BrowserServiceManager sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return BrowserServiceManagerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Database sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return DatabaseImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PriceEngine sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PriceEngineImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ProductDetails sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ProductDetailsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Value sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ValueImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Set<String> sse_decode_Set_String_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_String(deserializer);
+        return Set.from(inner); }
+
+@protected RustStreamSink<EngineEvent> sse_decode_StreamSink_engine_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected BinaryInfo sse_decode_binary_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_version = sse_decode_opt_String(deserializer);
+var var_majorVersion = sse_decode_opt_box_autoadd_u_32(deserializer);
+return BinaryInfo(path: var_path, version: var_version, majorVersion: var_majorVersion); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected BinaryInfo sse_decode_box_autoadd_binary_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_binary_info(deserializer)); }
+
+@protected BrowserOptions sse_decode_box_autoadd_browser_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_browser_options(deserializer)); }
+
+@protected CachedImage sse_decode_box_autoadd_cached_image(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_cached_image(deserializer)); }
+
+@protected FetchTimeouts sse_decode_box_autoadd_fetch_timeouts(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_fetch_timeouts(deserializer)); }
+
+@protected Money sse_decode_box_autoadd_money(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_money(deserializer)); }
+
+@protected ProductRecord sse_decode_box_autoadd_product_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_product_record(deserializer)); }
+
+@protected RateLimit sse_decode_box_autoadd_rate_limit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_rate_limit(deserializer)); }
+
+@protected ReadyCondition sse_decode_box_autoadd_ready_condition(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ready_condition(deserializer)); }
+
+@protected RecyclePolicy sse_decode_box_autoadd_recycle_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_recycle_policy(deserializer)); }
+
+@protected RetryPolicy sse_decode_box_autoadd_retry_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_retry_policy(deserializer)); }
+
+@protected SiteConfig sse_decode_box_autoadd_site_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_site_config(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected WatchdogConfig sse_decode_box_autoadd_watchdog_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_watchdog_config(deserializer)); }
+
+@protected BrowserEngineStatus sse_decode_browser_engine_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_isRunning = sse_decode_bool(deserializer);
+var var_currentPort = sse_decode_u_16(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_requiresRestart = sse_decode_bool(deserializer);
+return BrowserEngineStatus(isRunning: var_isRunning, currentPort: var_currentPort, message: var_message, requiresRestart: var_requiresRestart); }
+
+@protected BrowserKind sse_decode_browser_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BrowserKind.values[inner]; }
+
+@protected BrowserOptions sse_decode_browser_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_userDataDir = sse_decode_opt_String(deserializer);
+var var_extraArgs = sse_decode_list_String(deserializer);
+var var_windowWidth = sse_decode_u_32(deserializer);
+var var_windowHeight = sse_decode_u_32(deserializer);
+var var_language = sse_decode_opt_String(deserializer);
+var var_userAgent = sse_decode_opt_String(deserializer);
+var var_headless = sse_decode_bool(deserializer);
+var var_blockedResources = sse_decode_list_resource_type(deserializer);
+return BrowserOptions(userDataDir: var_userDataDir, extraArgs: var_extraArgs, windowWidth: var_windowWidth, windowHeight: var_windowHeight, language: var_language, userAgent: var_userAgent, headless: var_headless, blockedResources: var_blockedResources); }
+
+@protected CachedImage sse_decode_cached_image(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_contentHash = sse_decode_String(deserializer);
+var var_localPath = sse_decode_String(deserializer);
+var var_thumbnailPath = sse_decode_String(deserializer);
+return CachedImage(url: var_url, contentHash: var_contentHash, localPath: var_localPath, thumbnailPath: var_thumbnailPath); }
+
+@protected CookieFormat sse_decode_cookie_format(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CookieFormat.values[inner]; }
+
+@protected DiagnosticCapture sse_decode_diagnostic_capture(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_fetcher = sse_decode_String(deserializer);
+var var_reason = sse_decode_String(deserializer);
+var var_timestamp = sse_decode_String(deserializer);
+var var_htmlPath = sse_decode_opt_String(deserializer);
+var var_screenshotPath = sse_decode_opt_String(deserializer);
+return DiagnosticCapture(id: var_id, url: var_url, fetcher: var_fetcher, reason: var_reason, timestamp: var_timestamp, htmlPath: var_htmlPath, screenshotPath: var_screenshotPath); }
+
+@protected DiagnosticCaptureContent sse_decode_diagnostic_capture_content(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_capture = sse_decode_diagnostic_capture(deserializer);
+var var_html = sse_decode_opt_String(deserializer);
+var var_screenshotPng = sse_decode_opt_list_prim_u_8_strict(deserializer);
+return DiagnosticCaptureContent(capture: var_capture, html: var_html, screenshotPng: var_screenshotPng); }
+
+@protected DiscoveryReport sse_decode_discovery_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_browserKind = sse_decode_browser_kind(deserializer);
+var var_browser = sse_decode_opt_box_autoadd_binary_info(deserializer);
+var var_driver = sse_decode_opt_box_autoadd_binary_info(deserializer);
+var var_compatible = sse_decode_bool(deserializer);
+var var_message = sse_decode_String(deserializer);
+return DiscoveryReport(browserKind: var_browserKind, browser: var_browser, driver: var_driver, compatible: var_compatible, message: var_message); }
+
+@protected DomainRateStatus sse_decode_domain_rate_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_domain = sse_decode_String(deserializer);
+var var_waiting = sse_decode_u_32(deserializer);
+var var_nextSlotInMs = sse_decode_u_64(deserializer);
+var var_cooldownRemainingMs = sse_decode_u_64(deserializer);
+return DomainRateStatus(domain: var_domain, waiting: var_waiting, nextSlotInMs: var_nextSlotInMs, cooldownRemainingMs: var_cooldownRemainingMs); }
+
+@protected EngineEvent sse_decode_engine_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_domain = sse_decode_String(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_reason = sse_decode_String(deserializer);
+var var_cooldownMs = sse_decode_u_64(deserializer);
+var var_timestamp = sse_decode_String(deserializer);
+return EngineEvent_SiteBlocked(domain: var_domain, url: var_url, reason: var_reason, cooldownMs: var_cooldownMs, timestamp: var_timestamp);case 1: var var_isHealthy = sse_decode_bool(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_timestamp = sse_decode_String(deserializer);
+return EngineEvent_ServiceStatusChanged(isHealthy: var_isHealthy, port: var_port, message: var_message, timestamp: var_timestamp);case 2: var var_restarts = sse_decode_u_32(deserializer);
+var var_windowMs = sse_decode_u_64(deserializer);
+var var_timestamp = sse_decode_String(deserializer);
+return EngineEvent_ServiceRestartsExhausted(restarts: var_restarts, windowMs: var_windowMs, timestamp: var_timestamp); default: throw UnimplementedError(''); }
+             }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FetchErrorKind sse_decode_fetch_error_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FetchErrorKind.values[inner]; }
+
+@protected FetchPathStats sse_decode_fetch_path_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_site = sse_decode_String(deserializer);
+var var_fetcher = sse_decode_String(deserializer);
+var var_checks = sse_decode_u_32(deserializer);
+var var_fallbacks = sse_decode_u_32(deserializer);
+var var_lastUsed = sse_decode_String(deserializer);
+return FetchPathStats(site: var_site, fetcher: var_fetcher, checks: var_checks, fallbacks: var_fallbacks, lastUsed: var_lastUsed); }
+
+@protected FetchTimeouts sse_decode_fetch_timeouts(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pageLoadMs = sse_decode_u_64(deserializer);
+var var_scriptMs = sse_decode_u_64(deserializer);
+var var_implicitWaitMs = sse_decode_u_64(deserializer);
+var var_fetchMs = sse_decode_u_64(deserializer);
+var var_statusCheckMs = sse_decode_u_64(deserializer);
+return FetchTimeouts(pageLoadMs: var_pageLoadMs, scriptMs: var_scriptMs, implicitWaitMs: var_implicitWaitMs, fetchMs: var_fetchMs, statusCheckMs: var_statusCheckMs); }
+
+@protected FetchTimingStats sse_decode_fetch_timing_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_site = sse_decode_String(deserializer);
+var var_fetcher = sse_decode_String(deserializer);
+var var_blockedResources = sse_decode_String(deserializer);
+var var_fetches = sse_decode_u_32(deserializer);
+var var_avgDurationMs = sse_decode_u_64(deserializer);
+var var_maxDurationMs = sse_decode_u_64(deserializer);
+return FetchTimingStats(site: var_site, fetcher: var_fetcher, blockedResources: var_blockedResources, fetches: var_fetches, avgDurationMs: var_avgDurationMs, maxDurationMs: var_maxDurationMs); }
+
+@protected FetcherKind sse_decode_fetcher_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FetcherKind.values[inner]; }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected ImageCacheStats sse_decode_image_cache_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fileCount = sse_decode_u_32(deserializer);
+var var_totalBytes = sse_decode_u_64(deserializer);
+return ImageCacheStats(fileCount: var_fileCount, totalBytes: var_totalBytes); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CachedImage> sse_decode_list_cached_image(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CachedImage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_cached_image(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DiagnosticCapture> sse_decode_list_diagnostic_capture(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DiagnosticCapture>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_diagnostic_capture(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DomainRateStatus> sse_decode_list_domain_rate_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DomainRateStatus>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_domain_rate_status(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FetchPathStats> sse_decode_list_fetch_path_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FetchPathStats>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_fetch_path_stats(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FetchTimingStats> sse_decode_list_fetch_timing_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FetchTimingStats>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_fetch_timing_stats(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PriceEntry> sse_decode_list_price_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PriceEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_price_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<ProductRecord> sse_decode_list_product_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ProductRecord>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_product_record(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ProxyStatus> sse_decode_list_proxy_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ProxyStatus>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_proxy_status(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RefreshOutcome> sse_decode_list_refresh_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RefreshOutcome>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_refresh_outcome(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ResourceType> sse_decode_list_resource_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ResourceType>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_resource_type(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SealedCookieJar> sse_decode_list_sealed_cookie_jar(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SealedCookieJar>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_sealed_cookie_jar(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SiteConfig> sse_decode_list_site_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SiteConfig>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_site_config(deserializer)); }
+        return ans_;
+         }
+
+@protected Money sse_decode_money(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_minorUnits = sse_decode_i_64(deserializer);
+var var_currency = sse_decode_String(deserializer);
+return Money(minorUnits: var_minorUnits, currency: var_currency); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BinaryInfo? sse_decode_opt_box_autoadd_binary_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_binary_info(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CachedImage? sse_decode_opt_box_autoadd_cached_image(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_cached_image(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Money? sse_decode_opt_box_autoadd_money(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_money(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ProductRecord? sse_decode_opt_box_autoadd_product_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_product_record(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_u_8_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PriceEngineStatus sse_decode_price_engine_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_isHealthy = sse_decode_bool(deserializer);
+var var_currentPort = sse_decode_u_16(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_lastCheck = sse_decode_String(deserializer);
+var var_rateLimits = sse_decode_list_domain_rate_status(deserializer);
+return PriceEngineStatus(isHealthy: var_isHealthy, currentPort: var_currentPort, message: var_message, lastCheck: var_lastCheck, rateLimits: var_rateLimits); }
+
+@protected PriceEntry sse_decode_price_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_price = sse_decode_money(deserializer);
+var var_inStock = sse_decode_bool(deserializer);
+var var_timestamp = sse_decode_String(deserializer);
+return PriceEntry(price: var_price, inStock: var_inStock, timestamp: var_timestamp); }
+
+@protected ProductRecord sse_decode_product_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_site = sse_decode_String(deserializer);
+var var_url = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_seller = sse_decode_opt_String(deserializer);
+var var_images = sse_decode_list_String(deserializer);
+var var_specifications = sse_decode_String(deserializer);
+var var_features = sse_decode_list_String(deserializer);
+var var_priceHistory = sse_decode_list_price_entry(deserializer);
+var var_cachedImages = sse_decode_list_cached_image(deserializer);
+return ProductRecord(id: var_id, site: var_site, url: var_url, title: var_title, seller: var_seller, images: var_images, specifications: var_specifications, features: var_features, priceHistory: var_priceHistory, cachedImages: var_cachedImages); }
+
+@protected ProxyStatus sse_decode_proxy_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_healthy = sse_decode_bool(deserializer);
+var var_consecutiveFailures = sse_decode_u_32(deserializer);
+var var_retryInMs = sse_decode_u_64(deserializer);
+return ProxyStatus(url: var_url, healthy: var_healthy, consecutiveFailures: var_consecutiveFailures, retryInMs: var_retryInMs); }
+
+@protected RateLimit sse_decode_rate_limit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_requestsPerMinute = sse_decode_u_32(deserializer);
+var var_burst = sse_decode_u_32(deserializer);
+var var_minSpacingMs = sse_decode_u_32(deserializer);
+var var_jitterMs = sse_decode_u_32(deserializer);
+return RateLimit(requestsPerMinute: var_requestsPerMinute, burst: var_burst, minSpacingMs: var_minSpacingMs, jitterMs: var_jitterMs); }
+
+@protected ReadyCondition sse_decode_ready_condition(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return ReadyCondition_DocumentComplete();case 1: var var_field0 = sse_decode_String(deserializer);
+return ReadyCondition_ElementPresent(var_field0);case 2: var var_quietMs = sse_decode_u_32(deserializer);
+return ReadyCondition_NetworkIdle(quietMs: var_quietMs);case 3: var var_field0 = sse_decode_String(deserializer);
+return ReadyCondition_Script(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected (BrowserEngine,ServiceStatus) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_browser_engine_service_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(deserializer);
+var var_field1 = sse_decode_service_status(deserializer);
+return (var_field0, var_field1); }
+
+@protected RecyclePolicy sse_decode_recycle_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxNavigations = sse_decode_u_32(deserializer);
+var var_maxSessionAgeMs = sse_decode_u_64(deserializer);
+var var_maxMemoryMb = sse_decode_u_32(deserializer);
+return RecyclePolicy(maxNavigations: var_maxNavigations, maxSessionAgeMs: var_maxSessionAgeMs, maxMemoryMb: var_maxMemoryMb); }
+
+@protected RefreshOutcome sse_decode_refresh_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_url = sse_decode_String(deserializer);
+var var_product = sse_decode_opt_box_autoadd_product_record(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return RefreshOutcome(url: var_url, product: var_product, error: var_error); }
+
+@protected ResourceType sse_decode_resource_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ResourceType.values[inner]; }
+
+@protected RetryPolicy sse_decode_retry_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxAttempts = sse_decode_u_32(deserializer);
+var var_initialBackoffMs = sse_decode_u_32(deserializer);
+var var_maxBackoffMs = sse_decode_u_32(deserializer);
+var var_backoffMultiplier = sse_decode_f_64(deserializer);
+return RetryPolicy(maxAttempts: var_maxAttempts, initialBackoffMs: var_initialBackoffMs, maxBackoffMs: var_maxBackoffMs, backoffMultiplier: var_backoffMultiplier); }
+
+@protected SealedCookieJar sse_decode_sealed_cookie_jar(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_domain = sse_decode_String(deserializer);
+var var_nonce = sse_decode_list_prim_u_8_strict(deserializer);
+var var_ciphertext = sse_decode_list_prim_u_8_strict(deserializer);
+return SealedCookieJar(domain: var_domain, nonce: var_nonce, ciphertext: var_ciphertext); }
+
+@protected ServiceStatus sse_decode_service_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_isHealthy = sse_decode_bool(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+var var_errorMessage = sse_decode_opt_String(deserializer);
+var var_startupMs = sse_decode_u_64(deserializer);
+return ServiceStatus(isHealthy: var_isHealthy, port: var_port, errorMessage: var_errorMessage, startupMs: var_startupMs); }
+
+@protected SiteConfig sse_decode_site_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_domain = sse_decode_String(deserializer);
+var var_fetcher = sse_decode_fetcher_kind(deserializer);
+var var_fallback = sse_decode_bool(deserializer);
+var var_readyCondition = sse_decode_ready_condition(deserializer);
+var var_readyTimeoutMs = sse_decode_u_32(deserializer);
+var var_rateLimit = sse_decode_rate_limit(deserializer);
+var var_retryPolicy = sse_decode_retry_policy(deserializer);
+var var_blockCooldownMs = sse_decode_u_32(deserializer);
+var var_proxies = sse_decode_list_String(deserializer);
+var var_allowedResources = sse_decode_list_resource_type(deserializer);
+return SiteConfig(domain: var_domain, fetcher: var_fetcher, fallback: var_fallback, readyCondition: var_readyCondition, readyTimeoutMs: var_readyTimeoutMs, rateLimit: var_rateLimit, retryPolicy: var_retryPolicy, blockCooldownMs: var_blockCooldownMs, proxies: var_proxies, allowedResources: var_allowedResources); }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected WatchdogConfig sse_decode_watchdog_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_intervalMs = sse_decode_u_64(deserializer);
+var var_maxRestarts = sse_decode_u_32(deserializer);
+var var_restartWindowMs = sse_decode_u_64(deserializer);
+return WatchdogConfig(intervalMs: var_intervalMs, maxRestarts: var_maxRestarts, restartWindowMs: var_restartWindowMs); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(BrowserEngine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as BrowserEngineImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(BrowserServiceManager self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as BrowserServiceManagerImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(Database self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as DatabaseImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(PriceEngine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PriceEngineImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(ProductDetails self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProductDetailsImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(Value self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ValueImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(BrowserEngine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as BrowserEngineImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(BrowserServiceManager self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as BrowserServiceManagerImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(PriceEngine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PriceEngineImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(ProductDetails self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProductDetailsImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(BrowserEngine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as BrowserEngineImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(BrowserServiceManager self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as BrowserServiceManagerImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(Database self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as DatabaseImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(PriceEngine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PriceEngineImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(ProductDetails self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProductDetailsImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(BrowserEngine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as BrowserEngineImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(BrowserServiceManager self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as BrowserServiceManagerImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(Database self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as DatabaseImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(PriceEngine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PriceEngineImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(ProductDetails self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ProductDetailsImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(Value self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ValueImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_Set_String_None(Set<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.toList(), serializer); }
+
+@protected void sse_encode_StreamSink_engine_event_Sse(RustStreamSink<EngineEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_engine_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_binary_info(BinaryInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_opt_String(self.version, serializer);
+sse_encode_opt_box_autoadd_u_32(self.majorVersion, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_binary_info(BinaryInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_binary_info(self, serializer); }
+
+@protected void sse_encode_box_autoadd_browser_options(BrowserOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_browser_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_cached_image(CachedImage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_cached_image(self, serializer); }
+
+@protected void sse_encode_box_autoadd_fetch_timeouts(FetchTimeouts self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_fetch_timeouts(self, serializer); }
+
+@protected void sse_encode_box_autoadd_money(Money self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_money(self, serializer); }
+
+@protected void sse_encode_box_autoadd_product_record(ProductRecord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_product_record(self, serializer); }
+
+@protected void sse_encode_box_autoadd_rate_limit(RateLimit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_rate_limit(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ready_condition(ReadyCondition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ready_condition(self, serializer); }
+
+@protected void sse_encode_box_autoadd_recycle_policy(RecyclePolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_recycle_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_retry_policy(RetryPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_retry_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_site_config(SiteConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_site_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_watchdog_config(WatchdogConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_watchdog_config(self, serializer); }
+
+@protected void sse_encode_browser_engine_status(BrowserEngineStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.isRunning, serializer);
+sse_encode_u_16(self.currentPort, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_bool(self.requiresRestart, serializer);
+ }
+
+@protected void sse_encode_browser_kind(BrowserKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_browser_options(BrowserOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.userDataDir, serializer);
+sse_encode_list_String(self.extraArgs, serializer);
+sse_encode_u_32(self.windowWidth, serializer);
+sse_encode_u_32(self.windowHeight, serializer);
+sse_encode_opt_String(self.language, serializer);
+sse_encode_opt_String(self.userAgent, serializer);
+sse_encode_bool(self.headless, serializer);
+sse_encode_list_resource_type(self.blockedResources, serializer);
+ }
+
+@protected void sse_encode_cached_image(CachedImage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.contentHash, serializer);
+sse_encode_String(self.localPath, serializer);
+sse_encode_String(self.thumbnailPath, serializer);
+ }
+
+@protected void sse_encode_cookie_format(CookieFormat self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_diagnostic_capture(DiagnosticCapture self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.fetcher, serializer);
+sse_encode_String(self.reason, serializer);
+sse_encode_String(self.timestamp, serializer);
+sse_encode_opt_String(self.htmlPath, serializer);
+sse_encode_opt_String(self.screenshotPath, serializer);
+ }
+
+@protected void sse_encode_diagnostic_capture_content(DiagnosticCaptureContent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_diagnostic_capture(self.capture, serializer);
+sse_encode_opt_String(self.html, serializer);
+sse_encode_opt_list_prim_u_8_strict(self.screenshotPng, serializer);
+ }
+
+@protected void sse_encode_discovery_report(DiscoveryReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_browser_kind(self.browserKind, serializer);
+sse_encode_opt_box_autoadd_binary_info(self.browser, serializer);
+sse_encode_opt_box_autoadd_binary_info(self.driver, serializer);
+sse_encode_bool(self.compatible, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_domain_rate_status(DomainRateStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.domain, serializer);
+sse_encode_u_32(self.waiting, serializer);
+sse_encode_u_64(self.nextSlotInMs, serializer);
+sse_encode_u_64(self.cooldownRemainingMs, serializer);
+ }
+
+@protected void sse_encode_engine_event(EngineEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case EngineEvent_SiteBlocked(domain: final domain,url: final url,reason: final reason,cooldownMs: final cooldownMs,timestamp: final timestamp): sse_encode_i_32(0, serializer); sse_encode_String(domain, serializer);
+sse_encode_String(url, serializer);
+sse_encode_String(reason, serializer);
+sse_encode_u_64(cooldownMs, serializer);
+sse_encode_String(timestamp, serializer);
+case EngineEvent_ServiceStatusChanged(isHealthy: final isHealthy,port: final port,message: final message,timestamp: final timestamp): sse_encode_i_32(1, serializer); sse_encode_bool(isHealthy, serializer);
+sse_encode_u_16(port, serializer);
+sse_encode_String(message, serializer);
+sse_encode_String(timestamp, serializer);
+case EngineEvent_ServiceRestartsExhausted(restarts: final restarts,windowMs: final windowMs,timestamp: final timestamp): sse_encode_i_32(2, serializer); sse_encode_u_32(restarts, serializer);
+sse_encode_u_64(windowMs, serializer);
+sse_encode_String(timestamp, serializer);
+  } }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_fetch_error_kind(FetchErrorKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_fetch_path_stats(FetchPathStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.site, serializer);
+sse_encode_String(self.fetcher, serializer);
+sse_encode_u_32(self.checks, serializer);
+sse_encode_u_32(self.fallbacks, serializer);
+sse_encode_String(self.lastUsed, serializer);
+ }
+
+@protected void sse_encode_fetch_timeouts(FetchTimeouts self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.pageLoadMs, serializer);
+sse_encode_u_64(self.scriptMs, serializer);
+sse_encode_u_64(self.implicitWaitMs, serializer);
+sse_encode_u_64(self.fetchMs, serializer);
+sse_encode_u_64(self.statusCheckMs, serializer);
+ }
+
+@protected void sse_encode_fetch_timing_stats(FetchTimingStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.site, serializer);
+sse_encode_String(self.fetcher, serializer);
+sse_encode_String(self.blockedResources, serializer);
+sse_encode_u_32(self.fetches, serializer);
+sse_encode_u_64(self.avgDurationMs, serializer);
+sse_encode_u_64(self.maxDurationMs, serializer);
+ }
+
+@protected void sse_encode_fetcher_kind(FetcherKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_image_cache_stats(ImageCacheStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.fileCount, serializer);
+sse_encode_u_64(self.totalBytes, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_cached_image(List<CachedImage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_cached_image(item, serializer); } }
+
+@protected void sse_encode_list_diagnostic_capture(List<DiagnosticCapture> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_diagnostic_capture(item, serializer); } }
+
+@protected void sse_encode_list_domain_rate_status(List<DomainRateStatus> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_domain_rate_status(item, serializer); } }
+
+@protected void sse_encode_list_fetch_path_stats(List<FetchPathStats> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_fetch_path_stats(item, serializer); } }
+
+@protected void sse_encode_list_fetch_timing_stats(List<FetchTimingStats> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_fetch_timing_stats(item, serializer); } }
+
+@protected void sse_encode_list_price_entry(List<PriceEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_price_entry(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_product_record(List<ProductRecord> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_product_record(item, serializer); } }
+
+@protected void sse_encode_list_proxy_status(List<ProxyStatus> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_proxy_status(item, serializer); } }
+
+@protected void sse_encode_list_refresh_outcome(List<RefreshOutcome> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_refresh_outcome(item, serializer); } }
+
+@protected void sse_encode_list_resource_type(List<ResourceType> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_resource_type(item, serializer); } }
+
+@protected void sse_encode_list_sealed_cookie_jar(List<SealedCookieJar> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_sealed_cookie_jar(item, serializer); } }
+
+@protected void sse_encode_list_site_config(List<SiteConfig> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_site_config(item, serializer); } }
+
+@protected void sse_encode_money(Money self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.minorUnits, serializer);
+sse_encode_String(self.currency, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_binary_info(BinaryInfo? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_binary_info(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_cached_image(CachedImage? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_cached_image(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_money(Money? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_money(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_product_record(ProductRecord? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_product_record(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_u_8_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_price_engine_status(PriceEngineStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.isHealthy, serializer);
+sse_encode_u_16(self.currentPort, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_String(self.lastCheck, serializer);
+sse_encode_list_domain_rate_status(self.rateLimits, serializer);
+ }
+
+@protected void sse_encode_price_entry(PriceEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_money(self.price, serializer);
+sse_encode_bool(self.inStock, serializer);
+sse_encode_String(self.timestamp, serializer);
+ }
+
+@protected void sse_encode_product_record(ProductRecord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.site, serializer);
+sse_encode_String(self.url, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_opt_String(self.seller, serializer);
+sse_encode_list_String(self.images, serializer);
+sse_encode_String(self.specifications, serializer);
+sse_encode_list_String(self.features, serializer);
+sse_encode_list_price_entry(self.priceHistory, serializer);
+sse_encode_list_cached_image(self.cachedImages, serializer);
+ }
+
+@protected void sse_encode_proxy_status(ProxyStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_bool(self.healthy, serializer);
+sse_encode_u_32(self.consecutiveFailures, serializer);
+sse_encode_u_64(self.retryInMs, serializer);
+ }
+
+@protected void sse_encode_rate_limit(RateLimit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.requestsPerMinute, serializer);
+sse_encode_u_32(self.burst, serializer);
+sse_encode_u_32(self.minSpacingMs, serializer);
+sse_encode_u_32(self.jitterMs, serializer);
+ }
+
+@protected void sse_encode_ready_condition(ReadyCondition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ReadyCondition_DocumentComplete(): sse_encode_i_32(0, serializer); case ReadyCondition_ElementPresent(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_String(field0, serializer);
+case ReadyCondition_NetworkIdle(quietMs: final quietMs): sse_encode_i_32(2, serializer); sse_encode_u_32(quietMs, serializer);
+case ReadyCondition_Script(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_browser_engine_service_status((BrowserEngine,ServiceStatus) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(self.$1, serializer);
+sse_encode_service_status(self.$2, serializer);
+ }
+
+@protected void sse_encode_recycle_policy(RecyclePolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxNavigations, serializer);
+sse_encode_u_64(self.maxSessionAgeMs, serializer);
+sse_encode_u_32(self.maxMemoryMb, serializer);
+ }
+
+@protected void sse_encode_refresh_outcome(RefreshOutcome self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.url, serializer);
+sse_encode_opt_box_autoadd_product_record(self.product, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_resource_type(ResourceType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_retry_policy(RetryPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxAttempts, serializer);
+sse_encode_u_32(self.initialBackoffMs, serializer);
+sse_encode_u_32(self.maxBackoffMs, serializer);
+sse_encode_f_64(self.backoffMultiplier, serializer);
+ }
+
+@protected void sse_encode_sealed_cookie_jar(SealedCookieJar self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.domain, serializer);
+sse_encode_list_prim_u_8_strict(self.nonce, serializer);
+sse_encode_list_prim_u_8_strict(self.ciphertext, serializer);
+ }
+
+@protected void sse_encode_service_status(ServiceStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.isHealthy, serializer);
+sse_encode_u_16(self.port, serializer);
+sse_encode_opt_String(self.errorMessage, serializer);
+sse_encode_u_64(self.startupMs, serializer);
+ }
+
+@protected void sse_encode_site_config(SiteConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.domain, serializer);
+sse_encode_fetcher_kind(self.fetcher, serializer);
+sse_encode_bool(self.fallback, serializer);
+sse_encode_ready_condition(self.readyCondition, serializer);
+sse_encode_u_32(self.readyTimeoutMs, serializer);
+sse_encode_rate_limit(self.rateLimit, serializer);
+sse_encode_retry_policy(self.retryPolicy, serializer);
+sse_encode_u_32(self.blockCooldownMs, serializer);
+sse_encode_list_String(self.proxies, serializer);
+sse_encode_list_resource_type(self.allowedResources, serializer);
+ }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_watchdog_config(WatchdogConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.intervalMs, serializer);
+sse_encode_u_32(self.maxRestarts, serializer);
+sse_encode_u_64(self.restartWindowMs, serializer);
+ }
+                }
+                
+
+            @sealed class BrowserEngineImpl extends RustOpaque implements BrowserEngine {
+                // Not to be used by end users
+                BrowserEngineImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                BrowserEngineImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_BrowserEngine,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_BrowserEngine,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_BrowserEnginePtr,
+                );
+
+                /// Checks the health of the browser service
+ Future<BrowserEngineStatus>  checkServiceStatus()=>RustLib.instance.api.crateApiScraperEngineBrowserEngineCheckServiceStatus(that: this, );
+
+
+/// Closes the currently active tab/window.
+ Future<void>  closeCurrentTab()=>RustLib.instance.api.crateApiScraperEngineBrowserEngineCloseCurrentTab(that: this, );
+
+
+/// Gets the browser kind the engine drives
+ Future<BrowserKind>  getBrowserKind()=>RustLib.instance.api.crateApiScraperEngineBrowserEngineGetBrowserKind(that: this, );
+
+
+/// Gets the current port being used
+ Future<int>  getCurrentPort()=>RustLib.instance.api.crateApiScraperEngineBrowserEngineGetCurrentPort(that: this, );
+
+
+/// Navigates to a URL and returns the page source with automatic retry
+ Future<String>  getPageSource({required String url })=>RustLib.instance.api.crateApiScraperEngineBrowserEngineGetPageSource(that: this, url: url);
+
+
+/// Gets the configured session pool size
+ Future<int>  getPoolSize()=>RustLib.instance.api.crateApiScraperEngineBrowserEngineGetPoolSize(that: this, );
+
+
+ Future<RecyclePolicy>  getRecyclePolicy()=>RustLib.instance.api.crateApiScraperEngineBrowserEngineGetRecyclePolicy(that: this, );
+
+
+ Future<FetchTimeouts>  getTimeouts()=>RustLib.instance.api.crateApiScraperEngineBrowserEngineGetTimeouts(that: this, );
+
+
+/// Whether the engine uses an external WebDriver server rather than its own driver
+ Future<bool>  isRemote()=>RustLib.instance.api.crateApiScraperEngineBrowserEngineIsRemote(that: this, );
+
+
+/// Restarts the browser service with fallback mechanisms
+ Future<ServiceStatus>  restartWithFallback()=>RustLib.instance.api.crateApiScraperEngineBrowserEngineRestartWithFallback(that: this, );
+
+
+/// Sets how many browser sessions `fetch_pages_concurrently` may use at once
+ Future<void>  setPoolSize({required int poolSize })=>RustLib.instance.api.crateApiScraperEngineBrowserEngineSetPoolSize(that: this, poolSize: poolSize);
+
+
+/// Sets when sessions are replaced by fresh ones. Pooled sessions are held to the
+/// navigation and age limits; the memory limit is checked on the main session only.
+ Future<void>  setRecyclePolicy({required RecyclePolicy policy })=>RustLib.instance.api.crateApiScraperEngineBrowserEngineSetRecyclePolicy(that: this, policy: policy);
+
+
+/// Applies `timeouts` to the open sessions and to every session opened later
+ Future<void>  setTimeouts({required FetchTimeouts timeouts })=>RustLib.instance.api.crateApiScraperEngineBrowserEngineSetTimeouts(that: this, timeouts: timeouts);
+
+
+            }
+            @sealed class BrowserServiceManagerImpl extends RustOpaque implements BrowserServiceManager {
+                // Not to be used by end users
+                BrowserServiceManagerImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                BrowserServiceManagerImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_BrowserServiceManager,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_BrowserServiceManager,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_BrowserServiceManagerPtr,
+                );
+
+                /// Checks if the browser service is responsive at the current port. A driver that
+/// does not answer within the status check timeout is unhealthy.
+ Future<ServiceStatus>  checkServiceHealth()=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerCheckServiceHealth(that: this, );
+
+
+/// Finds the next available port starting from the current port
+ Future<int>  findAvailablePort()=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerFindAvailablePort(that: this, );
+
+
+/// Gets the browser kind being driven
+ Future<BrowserKind>  getBrowserKind()=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerGetBrowserKind(that: this, );
+
+
+/// Gets the options browser sessions are launched with
+ Future<BrowserOptions>  getBrowserOptions()=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerGetBrowserOptions(that: this, );
+
+
+/// Gets the current port
+ Future<int>  getCurrentPort()=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerGetCurrentPort(that: this, );
+
+
+/// Gets the proxy new browser sessions are routed through
+ Future<String?>  getProxy()=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerGetProxy(that: this, );
+
+
+/// Gets the timeouts browser sessions are created with
+ Future<FetchTimeouts>  getTimeouts()=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerGetTimeouts(that: this, );
+
+
+/// Whether the manager attaches to an external WebDriver server instead of running its own driver
+ Future<bool>  isRemote()=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerIsRemote(that: this, );
+
+
+/// Restarts the service with fallback to new port if needed
+ Future<ServiceStatus>  restartService()=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerRestartService(that: this, );
+
+
+/// Overrides the browser kind guessed from the driver name
+ Future<void>  setBrowserKind({required BrowserKind browserKind })=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerSetBrowserKind(that: this, browserKind: browserKind);
+
+
+/// Sets the options used for browser sessions created from now on
+ Future<void>  setBrowserOptions({required BrowserOptions options })=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerSetBrowserOptions(that: this, options: options);
+
+
+/// Sets a new port range
+ Future<void>  setPortRange({required int minPort , required int maxPort })=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerSetPortRange(that: this, minPort: minPort, maxPort: maxPort);
+
+
+/// Routes browser sessions created from now on through `proxy` (e.g. `http://host:3128`
+/// or `socks5://host:1080`), or directly when `None`.
+ Future<void>  setProxy({String? proxy })=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerSetProxy(that: this, proxy: proxy);
+
+
+/// Sets the timeouts used for browser sessions created from now on
+ Future<void>  setTimeouts({required FetchTimeouts timeouts })=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerSetTimeouts(that: this, timeouts: timeouts);
+
+
+/// Starts the driver service on the current port, moving on to the next free port
+/// when the driver cannot bind it. Polls `/status` until the driver is ready or
+/// the startup timeout passes.
+ Future<ServiceStatus>  startService()=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerStartService(that: this, );
+
+
+/// Stops the driver service
+ Future<void>  stopService()=>RustLib.instance.api.crateApiBrowserServiceManagerBrowserServiceManagerStopService(that: this, );
+
+
+            }
+            @sealed class DatabaseImpl extends RustOpaque implements Database {
+                // Not to be used by end users
+                DatabaseImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                DatabaseImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Database,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Database,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_DatabasePtr,
+                );
+
+                /// Creates a backup of all data in JSON format
+ Future<void>  createBackup({required String backupPath })=>RustLib.instance.api.crateApiDatabaseDatabaseCreateBackup(that: this, backupPath: backupPath);
+
+
+ Future<List<ProductRecord>>  getAllProductsWithHistory()=>RustLib.instance.api.crateApiDatabaseDatabaseGetAllProductsWithHistory(that: this, );
+
+
+/// Returns every stored encrypted cookie jar.
+ Future<List<SealedCookieJar>>  getAllSessionCookies()=>RustLib.instance.api.crateApiDatabaseDatabaseGetAllSessionCookies(that: this, );
+
+
+/// Looks up a cached image by its remote URL, regardless of which product owns it.
+ Future<CachedImage?>  getCachedImageByUrl({required String url })=>RustLib.instance.api.crateApiDatabaseDatabaseGetCachedImageByUrl(that: this, url: url);
+
+
+/// Aggregates recorded checks per site and fetcher.
+ Future<List<FetchPathStats>>  getFetchPathStats()=>RustLib.instance.api.crateApiDatabaseDatabaseGetFetchPathStats(that: this, );
+
+
+/// Aggregates recorded fetch timings per site, fetcher and set of blocked resources,
+/// so runs with and without blocking can be compared.
+ Future<List<FetchTimingStats>>  getFetchTimingStats()=>RustLib.instance.api.crateApiDatabaseDatabaseGetFetchTimingStats(that: this, );
+
+
+ Future<ProductRecord?>  getProductWithHistory({required String productId })=>RustLib.instance.api.crateApiDatabaseDatabaseGetProductWithHistory(that: this, productId: productId);
+
+
+/// Returns the content hashes of every image still referenced by a product.
+ Future<Set<String>>  getReferencedImageHashes()=>RustLib.instance.api.crateApiDatabaseDatabaseGetReferencedImageHashes(that: this, );
+
+
+ Future<void>  insertPriceEntry({required ProductDetails details })=>RustLib.instance.api.crateApiDatabaseDatabaseInsertPriceEntry(that: this, details: details);
+
+
+/// Records which fetcher served a product check and whether it was a fallback.
+ Future<void>  recordFetchCheck({required String site , required String url , required FetcherKind fetcher , required bool fellBack })=>RustLib.instance.api.crateApiDatabaseDatabaseRecordFetchCheck(that: this, site: site, url: url, fetcher: fetcher, fellBack: fellBack);
+
+
+/// Records how long a successful page fetch took and which resources were blocked during it.
+ Future<void>  recordFetchTiming({required String site , required String url , required FetcherKind fetcher , required String blockedResources , required BigInt durationMs })=>RustLib.instance.api.crateApiDatabaseDatabaseRecordFetchTiming(that: this, site: site, url: url, fetcher: fetcher, blockedResources: blockedResources, durationMs: durationMs);
+
+
+ Future<void>  removeProduct({required String productId })=>RustLib.instance.api.crateApiDatabaseDatabaseRemoveProduct(that: this, productId: productId);
+
+
+/// Forgets the cached images of `product_id` whose URL is not in `current_urls`, so
+/// images the product no longer shows can be cleaned up. Returns how many were dropped.
+ Future<BigInt>  removeStaleCachedImages({required String productId , required List<String> currentUrls })=>RustLib.instance.api.crateApiDatabaseDatabaseRemoveStaleCachedImages(that: this, productId: productId, currentUrls: currentUrls);
+
+
+/// Restores data from a JSON backup file
+ Future<void>  restoreFromBackup({required String backupPath , required bool replaceExisting })=>RustLib.instance.api.crateApiDatabaseDatabaseRestoreFromBackup(that: this, backupPath: backupPath, replaceExisting: replaceExisting);
+
+
+/// Stores the encrypted cookie jar of one domain, replacing any earlier one.
+ Future<void>  saveSessionCookies({required String domain , required List<int> nonce , required List<int> ciphertext })=>RustLib.instance.api.crateApiDatabaseDatabaseSaveSessionCookies(that: this, domain: domain, nonce: nonce, ciphertext: ciphertext);
+
+
+/// Records a locally cached copy of one of a product's images.
+ Future<void>  upsertCachedImage({required String productId , required BigInt position , required CachedImage image })=>RustLib.instance.api.crateApiDatabaseDatabaseUpsertCachedImage(that: this, productId: productId, position: position, image: image);
+
+
+/// Inserts or updates a product's static details.
+ Future<void>  upsertProduct({required ProductDetails details })=>RustLib.instance.api.crateApiDatabaseDatabaseUpsertProduct(that: this, details: details);
+
+
+            }
+            @sealed class PriceEngineImpl extends RustOpaque implements PriceEngine {
+                // Not to be used by end users
+                PriceEngineImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                PriceEngineImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_PriceEngine,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_PriceEngine,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_PriceEnginePtr,
+                );
+
+                /// Checks the health status of the browser service
+ Future<PriceEngineStatus>  checkServiceStatus()=>RustLib.instance.api.crateApiPriceEnginePriceEngineCheckServiceStatus(that: this, );
+
+
+/// Deletes cached images no longer referenced by any product and returns what was freed.
+ Future<ImageCacheStats>  cleanupImageCache()=>RustLib.instance.api.crateApiPriceEnginePriceEngineCleanupImageCache(that: this, );
+
+
+/// Creates a backup of the database in JSON format
+ Future<void>  createBackup({required String backupPath })=>RustLib.instance.api.crateApiPriceEnginePriceEngineCreateBackup(that: this, backupPath: backupPath);
+
+
+/// Exports the cookies the session currently holds for `domain`, saving them as well
+/// so changes made by the site (refreshed logins) are kept.
+ Future<String>  exportCookies({required String domain , required CookieFormat format })=>RustLib.instance.api.crateApiPriceEnginePriceEngineExportCookies(that: this, domain: domain, format: format);
+
+
+/// Fetches product details with automatic retry and fallback mechanisms
+ Future<ProductRecord>  fetchAndUpdateProduct({required String url })=>RustLib.instance.api.crateApiPriceEnginePriceEngineFetchAndUpdateProduct(that: this, url: url);
+
+
+ Future<List<ProductRecord>>  getAllProductsInDb()=>RustLib.instance.api.crateApiPriceEnginePriceEngineGetAllProductsInDb(that: this, );
+
+
+/// Reports the browser and driver this engine runs, their versions and whether they match
+ Future<DiscoveryReport>  getBrowserBinaries()=>RustLib.instance.api.crateApiPriceEnginePriceEngineGetBrowserBinaries(that: this, );
+
+
+/// Gets the current port being used by the browser service
+ Future<int>  getCurrentPort()=>RustLib.instance.api.crateApiPriceEnginePriceEngineGetCurrentPort(that: this, );
+
+
+/// Returns the most recent diagnostic captures for a product, newest first.
+ Future<List<DiagnosticCapture>>  getDiagnosticCaptures({required String productId , required int limit })=>RustLib.instance.api.crateApiPriceEnginePriceEngineGetDiagnosticCaptures(that: this, productId: productId, limit: limit);
+
+
+/// Returns the most recent diagnostic captures of `url`, or of every page when `None`,
+/// newest first. Unlike [`Self::get_diagnostic_captures`] this needs no product, so it
+/// also lists pages whose first fetch failed before they were ever saved.
+ Future<List<DiagnosticCapture>>  getDiagnosticCapturesForUrl({String? url , required int limit })=>RustLib.instance.api.crateApiPriceEnginePriceEngineGetDiagnosticCapturesForUrl(that: this, url: url, limit: limit);
+
+
+/// Returns the newest `lines` lines of the browser driver's log, oldest first
+ Future<List<String>>  getDriverLogTail({required int lines })=>RustLib.instance.api.crateApiPriceEnginePriceEngineGetDriverLogTail(that: this, lines: lines);
+
+
+/// Returns how often each fetcher served each site, for tuning site settings.
+ Future<List<FetchPathStats>>  getFetchPathStats()=>RustLib.instance.api.crateApiPriceEnginePriceEngineGetFetchPathStats(that: this, );
+
+
+ Future<FetchTimeouts>  getFetchTimeouts()=>RustLib.instance.api.crateApiPriceEnginePriceEngineGetFetchTimeouts(that: this, );
+
+
+/// Returns how long successful fetches took, per site, fetcher and set of blocked
+/// resources, to compare page loads with and without resource blocking.
+ Future<List<FetchTimingStats>>  getFetchTimingStats()=>RustLib.instance.api.crateApiPriceEnginePriceEngineGetFetchTimingStats(that: this, );
+
+
+/// Returns the size of the local image cache.
+ Future<ImageCacheStats>  getImageCacheStats()=>RustLib.instance.api.crateApiPriceEnginePriceEngineGetImageCacheStats(that: this, );
+
+
+/// Returns the health of the configured proxies.
+ Future<List<ProxyStatus>>  getProxyStatus()=>RustLib.instance.api.crateApiPriceEnginePriceEngineGetProxyStatus(that: this, );
+
+
+/// Returns the per-site settings currently in use.
+ Future<List<SiteConfig>>  getSiteConfigs()=>RustLib.instance.api.crateApiPriceEnginePriceEngineGetSiteConfigs(that: this, );
+
+
+/// Imports cookies for `domain` from a cookies.txt or JSON export, e.g. to scrape
+/// while signed in. They are loaded into the browser and HTTP sessions and stored
+/// encrypted so they survive restarts. Returns how many cookies were imported.
+ Future<int>  importCookies({required String domain , required String content , required CookieFormat format })=>RustLib.instance.api.crateApiPriceEnginePriceEngineImportCookies(that: this, domain: domain, content: content, format: format);
+
+
+/// Loads the saved page and screenshot of a diagnostic capture.
+ Future<DiagnosticCaptureContent>  openDiagnosticCapture({required String captureId })=>RustLib.instance.api.crateApiPriceEnginePriceEngineOpenDiagnosticCapture(that: this, captureId: captureId);
+
+
+/// Re-checks every tracked product. Browser-served pages are loaded in parallel
+/// through the session pool; anything that fails there, or is served over HTTP,
+/// goes through the regular single-product path with its retries and fallback.
+ Future<List<RefreshOutcome>>  refreshAllProducts()=>RustLib.instance.api.crateApiPriceEnginePriceEngineRefreshAllProducts(that: this, );
+
+
+/// Removes a product from the database by its ID.
+ Future<void>  removeProductById({required String productId })=>RustLib.instance.api.crateApiPriceEnginePriceEngineRemoveProductById(that: this, productId: productId);
+
+
+/// Restarts the browser service if needed
+ Future<ServiceStatus>  restartBrowserService()=>RustLib.instance.api.crateApiPriceEnginePriceEngineRestartBrowserService(that: this, );
+
+
+/// Restores database from a JSON backup file
+ Future<void>  restoreFromBackup({required String backupPath , required bool replaceExisting })=>RustLib.instance.api.crateApiPriceEnginePriceEngineRestoreFromBackup(that: this, backupPath: backupPath, replaceExisting: replaceExisting);
+
+
+/// Sets how many browser sessions a refresh-all may run in parallel
+ Future<void>  setBrowserPoolSize({required int poolSize })=>RustLib.instance.api.crateApiPriceEnginePriceEngineSetBrowserPoolSize(that: this, poolSize: poolSize);
+
+
+/// Sets after how many page loads, how long, or how much memory the browser session is replaced
+ Future<void>  setBrowserRecyclePolicy({required RecyclePolicy policy })=>RustLib.instance.api.crateApiPriceEnginePriceEngineSetBrowserRecyclePolicy(that: this, policy: policy);
+
+
+/// Sets the proxies used by every site without a proxy list of its own; empty means direct.
+ Future<void>  setDefaultProxies({required List<String> proxies })=>RustLib.instance.api.crateApiPriceEnginePriceEngineSetDefaultProxies(that: this, proxies: proxies);
+
+
+/// Sets how many diagnostic captures are kept; the oldest are deleted first.
+ Future<void>  setDiagnosticsRetention({required int maxCaptures })=>RustLib.instance.api.crateApiPriceEnginePriceEngineSetDiagnosticsRetention(that: this, maxCaptures: maxCaptures);
+
+
+/// Sets how long page loads, scripts and whole fetches may take before they fail
+/// as timed out. Open browser sessions are updated immediately.
+ Future<void>  setFetchTimeouts({required FetchTimeouts timeouts })=>RustLib.instance.api.crateApiPriceEnginePriceEngineSetFetchTimeouts(that: this, timeouts: timeouts);
+
+
+/// Lets `domain`'s pages load the given resource types even though the browser blocks them.
+ Future<void>  setSiteAllowedResources({required String domain , required List<ResourceType> allowed })=>RustLib.instance.api.crateApiPriceEnginePriceEngineSetSiteAllowedResources(that: this, domain: domain, allowed: allowed);
+
+
+/// Sets how long fetches from `domain` pause after it served a captcha or access-denied page.
+ Future<void>  setSiteBlockCooldown({required String domain , required int cooldownMs })=>RustLib.instance.api.crateApiPriceEnginePriceEngineSetSiteBlockCooldown(that: this, domain: domain, cooldownMs: cooldownMs);
+
+
+/// Enables or disables falling back to the other fetcher for `domain`.
+ Future<void>  setSiteFallback({required String domain , required bool enabled })=>RustLib.instance.api.crateApiPriceEnginePriceEngineSetSiteFallback(that: this, domain: domain, enabled: enabled);
+
+
+/// Chooses which fetcher is used for `domain`, adding the site if it is not configured yet.
+ Future<void>  setSiteFetcher({required String domain , required FetcherKind fetcher })=>RustLib.instance.api.crateApiPriceEnginePriceEngineSetSiteFetcher(that: this, domain: domain, fetcher: fetcher);
+
+
+/// Assigns `domain` its own rotation of proxies; empty falls back to the engine-wide proxies.
+ Future<void>  setSiteProxies({required String domain , required List<String> proxies })=>RustLib.instance.api.crateApiPriceEnginePriceEngineSetSiteProxies(that: this, domain: domain, proxies: proxies);
+
+
+/// Sets the request throttling for `domain`.
+ Future<void>  setSiteRateLimit({required String domain , required RateLimit rateLimit })=>RustLib.instance.api.crateApiPriceEnginePriceEngineSetSiteRateLimit(that: this, domain: domain, rateLimit: rateLimit);
+
+
+/// Sets what the browser waits for on `domain` pages and for how long.
+ Future<void>  setSiteReadyCondition({required String domain , required ReadyCondition condition , required int timeoutMs })=>RustLib.instance.api.crateApiPriceEnginePriceEngineSetSiteReadyCondition(that: this, domain: domain, condition: condition, timeoutMs: timeoutMs);
+
+
+/// Sets how failed fetches of `domain` pages are retried.
+ Future<void>  setSiteRetryPolicy({required String domain , required RetryPolicy retryPolicy })=>RustLib.instance.api.crateApiPriceEnginePriceEngineSetSiteRetryPolicy(that: this, domain: domain, retryPolicy: retryPolicy);
+
+
+/// Shuts down the browser engine gracefully.
+ Future<void>  shutdown()=>RustLib.instance.api.crateApiPriceEnginePriceEngineShutdown(that: this, );
+
+
+/// Starts probing the browser service in the background, restarting it when it is
+/// unhealthy and emitting status changes as engine events. Replaces a running watchdog.
+ Future<void>  startServiceWatchdog({required WatchdogConfig config })=>RustLib.instance.api.crateApiPriceEnginePriceEngineStartServiceWatchdog(that: this, config: config);
+
+
+/// Stops the background service watchdog, if one is running.
+ Future<void>  stopServiceWatchdog()=>RustLib.instance.api.crateApiPriceEnginePriceEngineStopServiceWatchdog(that: this, );
+
+
+            }
+            @sealed class ProductDetailsImpl extends RustOpaque implements ProductDetails {
+                // Not to be used by end users
+                ProductDetailsImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ProductDetailsImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_ProductDetails,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_ProductDetails,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ProductDetailsPtr,
+                );
+
+                 List<String> get features=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetFeatures(that: this, );
+
+
+ String get id=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetId(that: this, );
+
+
+ List<String> get images=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetImages(that: this, );
+
+
+ bool get inStock=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetInStock(that: this, );
+
+
+ Money? get price=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetPrice(that: this, );
+
+
+ String get rating=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetRating(that: this, );
+
+
+ String? get seller=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetSeller(that: this, );
+
+
+ String get site=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetSite(that: this, );
+
+
+ Value get specifications=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetSpecifications(that: this, );
+
+
+ String get title=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetTitle(that: this, );
+
+
+ String get url=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorGetUrl(that: this, );
+
+
+  set features(List<String> features)=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetFeatures(that: this, features: features);
+
+
+  set id(String id)=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetId(that: this, id: id);
+
+
+  set images(List<String> images)=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetImages(that: this, images: images);
+
+
+  set inStock(bool inStock)=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetInStock(that: this, inStock: inStock);
+
+
+  set price(Money? price)=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetPrice(that: this, price: price);
+
+
+  set rating(String rating)=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetRating(that: this, rating: rating);
+
+
+  set seller(String? seller)=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetSeller(that: this, seller: seller);
+
+
+  set site(String site)=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetSite(that: this, site: site);
+
+
+  set specifications(Value specifications)=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetSpecifications(that: this, specifications: specifications);
+
+
+  set title(String title)=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetTitle(that: this, title: title);
+
+
+  set url(String url)=>RustLib.instance.api.crateApiModelsProductDetailsAutoAccessorSetUrl(that: this, url: url);
+
+
+            }
+            @sealed class ValueImpl extends RustOpaque implements Value {
+                // Not to be used by end users
+                ValueImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ValueImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_Value,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_Value,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ValuePtr,
+                );
+
+                
+            }
\ No newline at end of file
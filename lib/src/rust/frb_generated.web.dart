@@ -3,728 +3,741 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
 import 'api/amazon_parser.dart';
 import 'api/apin.dart';
+import 'api/binary_discovery.dart';
 import 'api/browser_service_manager.dart';
+import 'api/cookie_jar.dart';
 import 'api/database.dart';
+import 'api/diagnostics.dart';
+import 'api/events.dart';
+import 'api/fetch_error.dart';
+import 'api/fetcher.dart';
 import 'api/flipkart_parser.dart';
+import 'api/image_cache.dart';
+import 'api/migrations.dart';
 import 'api/models.dart';
+import 'api/money.dart';
 import 'api/price_engine.dart';
+import 'api/proxy.dart';
+import 'api/rate_limiter.dart';
+import 'api/recycle_policy.dart';
+import 'api/resource_blocking.dart';
+import 'api/retry_policy.dart';
 import 'api/scraper_engine.dart';
+import 'api/site_config.dart';
+import 'api/timeouts.dart';
+import 'api/watchdog.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'lib.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_BrowserEnginePtr => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_BrowserServiceManagerPtr => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DatabasePtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_PriceEnginePtr => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_ProductDetailsPtr => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
-
-  @protected
-  BrowserEngine
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          dynamic raw);
-
-  @protected
-  BrowserServiceManager
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          dynamic raw);
-
-  @protected
-  Database
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          dynamic raw);
-
-  @protected
-  PriceEngine
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          dynamic raw);
-
-  @protected
-  ProductDetails
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          dynamic raw);
-
-  @protected
-  Value
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          dynamic raw);
-
-  @protected
-  BrowserEngine
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          dynamic raw);
-
-  @protected
-  BrowserServiceManager
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          dynamic raw);
-
-  @protected
-  PriceEngine
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          dynamic raw);
-
-  @protected
-  ProductDetails
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          dynamic raw);
-
-  @protected
-  BrowserEngine
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          dynamic raw);
-
-  @protected
-  BrowserServiceManager
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          dynamic raw);
-
-  @protected
-  Database
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          dynamic raw);
-
-  @protected
-  PriceEngine
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          dynamic raw);
-
-  @protected
-  ProductDetails
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          dynamic raw);
 
-  @protected
-  BrowserEngine
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          dynamic raw);
 
-  @protected
-  BrowserServiceManager
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          dynamic raw);
 
-  @protected
-  Database
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_BrowserEnginePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_BrowserServiceManagerPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DatabasePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PriceEnginePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ProductDetailsPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ValuePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected BrowserEngine dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(dynamic raw);
+
+@protected BrowserServiceManager dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(dynamic raw);
+
+@protected Database dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(dynamic raw);
+
+@protected PriceEngine dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(dynamic raw);
+
+@protected ProductDetails dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(dynamic raw);
+
+@protected Value dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(dynamic raw);
+
+@protected BrowserEngine dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(dynamic raw);
+
+@protected BrowserServiceManager dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(dynamic raw);
+
+@protected PriceEngine dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(dynamic raw);
+
+@protected ProductDetails dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(dynamic raw);
+
+@protected BrowserEngine dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(dynamic raw);
+
+@protected BrowserServiceManager dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(dynamic raw);
+
+@protected Database dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(dynamic raw);
+
+@protected PriceEngine dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(dynamic raw);
+
+@protected ProductDetails dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(dynamic raw);
+
+@protected BrowserEngine dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(dynamic raw);
+
+@protected BrowserServiceManager dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(dynamic raw);
+
+@protected Database dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(dynamic raw);
+
+@protected PriceEngine dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(dynamic raw);
+
+@protected ProductDetails dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(dynamic raw);
+
+@protected Value dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(dynamic raw);
+
+@protected Set<String> dco_decode_Set_String_None(dynamic raw);
+
+@protected RustStreamSink<EngineEvent> dco_decode_StreamSink_engine_event_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected BinaryInfo dco_decode_binary_info(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected BinaryInfo dco_decode_box_autoadd_binary_info(dynamic raw);
+
+@protected BrowserOptions dco_decode_box_autoadd_browser_options(dynamic raw);
+
+@protected CachedImage dco_decode_box_autoadd_cached_image(dynamic raw);
+
+@protected FetchTimeouts dco_decode_box_autoadd_fetch_timeouts(dynamic raw);
+
+@protected Money dco_decode_box_autoadd_money(dynamic raw);
+
+@protected ProductRecord dco_decode_box_autoadd_product_record(dynamic raw);
+
+@protected RateLimit dco_decode_box_autoadd_rate_limit(dynamic raw);
+
+@protected ReadyCondition dco_decode_box_autoadd_ready_condition(dynamic raw);
+
+@protected RecyclePolicy dco_decode_box_autoadd_recycle_policy(dynamic raw);
+
+@protected RetryPolicy dco_decode_box_autoadd_retry_policy(dynamic raw);
+
+@protected SiteConfig dco_decode_box_autoadd_site_config(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
+
+@protected WatchdogConfig dco_decode_box_autoadd_watchdog_config(dynamic raw);
+
+@protected BrowserEngineStatus dco_decode_browser_engine_status(dynamic raw);
+
+@protected BrowserKind dco_decode_browser_kind(dynamic raw);
+
+@protected BrowserOptions dco_decode_browser_options(dynamic raw);
+
+@protected CachedImage dco_decode_cached_image(dynamic raw);
+
+@protected CookieFormat dco_decode_cookie_format(dynamic raw);
+
+@protected DiagnosticCapture dco_decode_diagnostic_capture(dynamic raw);
+
+@protected DiagnosticCaptureContent dco_decode_diagnostic_capture_content(dynamic raw);
+
+@protected DiscoveryReport dco_decode_discovery_report(dynamic raw);
+
+@protected DomainRateStatus dco_decode_domain_rate_status(dynamic raw);
+
+@protected EngineEvent dco_decode_engine_event(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected FetchErrorKind dco_decode_fetch_error_kind(dynamic raw);
+
+@protected FetchPathStats dco_decode_fetch_path_stats(dynamic raw);
+
+@protected FetchTimeouts dco_decode_fetch_timeouts(dynamic raw);
+
+@protected FetchTimingStats dco_decode_fetch_timing_stats(dynamic raw);
+
+@protected FetcherKind dco_decode_fetcher_kind(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected ImageCacheStats dco_decode_image_cache_stats(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<CachedImage> dco_decode_list_cached_image(dynamic raw);
+
+@protected List<DiagnosticCapture> dco_decode_list_diagnostic_capture(dynamic raw);
+
+@protected List<DomainRateStatus> dco_decode_list_domain_rate_status(dynamic raw);
+
+@protected List<FetchPathStats> dco_decode_list_fetch_path_stats(dynamic raw);
+
+@protected List<FetchTimingStats> dco_decode_list_fetch_timing_stats(dynamic raw);
+
+@protected List<PriceEntry> dco_decode_list_price_entry(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<ProductRecord> dco_decode_list_product_record(dynamic raw);
+
+@protected List<ProxyStatus> dco_decode_list_proxy_status(dynamic raw);
+
+@protected List<RefreshOutcome> dco_decode_list_refresh_outcome(dynamic raw);
+
+@protected List<ResourceType> dco_decode_list_resource_type(dynamic raw);
+
+@protected List<SealedCookieJar> dco_decode_list_sealed_cookie_jar(dynamic raw);
+
+@protected List<SiteConfig> dco_decode_list_site_config(dynamic raw);
+
+@protected Money dco_decode_money(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected BinaryInfo? dco_decode_opt_box_autoadd_binary_info(dynamic raw);
+
+@protected CachedImage? dco_decode_opt_box_autoadd_cached_image(dynamic raw);
+
+@protected Money? dco_decode_opt_box_autoadd_money(dynamic raw);
+
+@protected ProductRecord? dco_decode_opt_box_autoadd_product_record(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+@protected PriceEngineStatus dco_decode_price_engine_status(dynamic raw);
+
+@protected PriceEntry dco_decode_price_entry(dynamic raw);
+
+@protected ProductRecord dco_decode_product_record(dynamic raw);
+
+@protected ProxyStatus dco_decode_proxy_status(dynamic raw);
+
+@protected RateLimit dco_decode_rate_limit(dynamic raw);
+
+@protected ReadyCondition dco_decode_ready_condition(dynamic raw);
+
+@protected (BrowserEngine,ServiceStatus) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_browser_engine_service_status(dynamic raw);
+
+@protected RecyclePolicy dco_decode_recycle_policy(dynamic raw);
+
+@protected RefreshOutcome dco_decode_refresh_outcome(dynamic raw);
+
+@protected ResourceType dco_decode_resource_type(dynamic raw);
+
+@protected RetryPolicy dco_decode_retry_policy(dynamic raw);
+
+@protected SealedCookieJar dco_decode_sealed_cookie_jar(dynamic raw);
+
+@protected ServiceStatus dco_decode_service_status(dynamic raw);
+
+@protected SiteConfig dco_decode_site_config(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected WatchdogConfig dco_decode_watchdog_config(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected BrowserEngine sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(SseDeserializer deserializer);
+
+@protected BrowserServiceManager sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(SseDeserializer deserializer);
+
+@protected Database sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(SseDeserializer deserializer);
+
+@protected PriceEngine sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(SseDeserializer deserializer);
+
+@protected ProductDetails sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(SseDeserializer deserializer);
+
+@protected Value sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(SseDeserializer deserializer);
+
+@protected BrowserEngine sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(SseDeserializer deserializer);
+
+@protected BrowserServiceManager sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(SseDeserializer deserializer);
+
+@protected PriceEngine sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(SseDeserializer deserializer);
+
+@protected ProductDetails sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(SseDeserializer deserializer);
+
+@protected BrowserEngine sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(SseDeserializer deserializer);
+
+@protected BrowserServiceManager sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(SseDeserializer deserializer);
+
+@protected Database sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(SseDeserializer deserializer);
+
+@protected PriceEngine sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(SseDeserializer deserializer);
+
+@protected ProductDetails sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(SseDeserializer deserializer);
+
+@protected BrowserEngine sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(SseDeserializer deserializer);
+
+@protected BrowserServiceManager sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(SseDeserializer deserializer);
+
+@protected Database sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(SseDeserializer deserializer);
+
+@protected PriceEngine sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(SseDeserializer deserializer);
+
+@protected ProductDetails sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(SseDeserializer deserializer);
+
+@protected Value sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(SseDeserializer deserializer);
+
+@protected Set<String> sse_decode_Set_String_None(SseDeserializer deserializer);
+
+@protected RustStreamSink<EngineEvent> sse_decode_StreamSink_engine_event_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected BinaryInfo sse_decode_binary_info(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected BinaryInfo sse_decode_box_autoadd_binary_info(SseDeserializer deserializer);
+
+@protected BrowserOptions sse_decode_box_autoadd_browser_options(SseDeserializer deserializer);
+
+@protected CachedImage sse_decode_box_autoadd_cached_image(SseDeserializer deserializer);
+
+@protected FetchTimeouts sse_decode_box_autoadd_fetch_timeouts(SseDeserializer deserializer);
+
+@protected Money sse_decode_box_autoadd_money(SseDeserializer deserializer);
+
+@protected ProductRecord sse_decode_box_autoadd_product_record(SseDeserializer deserializer);
+
+@protected RateLimit sse_decode_box_autoadd_rate_limit(SseDeserializer deserializer);
+
+@protected ReadyCondition sse_decode_box_autoadd_ready_condition(SseDeserializer deserializer);
+
+@protected RecyclePolicy sse_decode_box_autoadd_recycle_policy(SseDeserializer deserializer);
+
+@protected RetryPolicy sse_decode_box_autoadd_retry_policy(SseDeserializer deserializer);
+
+@protected SiteConfig sse_decode_box_autoadd_site_config(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected WatchdogConfig sse_decode_box_autoadd_watchdog_config(SseDeserializer deserializer);
+
+@protected BrowserEngineStatus sse_decode_browser_engine_status(SseDeserializer deserializer);
+
+@protected BrowserKind sse_decode_browser_kind(SseDeserializer deserializer);
+
+@protected BrowserOptions sse_decode_browser_options(SseDeserializer deserializer);
+
+@protected CachedImage sse_decode_cached_image(SseDeserializer deserializer);
+
+@protected CookieFormat sse_decode_cookie_format(SseDeserializer deserializer);
 
-  @protected
-  PriceEngine
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          dynamic raw);
-
-  @protected
-  ProductDetails
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          dynamic raw);
-
-  @protected
-  Value
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          dynamic raw);
-
-  @protected
-  String dco_decode_String(dynamic raw);
-
-  @protected
-  bool dco_decode_bool(dynamic raw);
-
-  @protected
-  int dco_decode_box_autoadd_i_32(dynamic raw);
-
-  @protected
-  ProductRecord dco_decode_box_autoadd_product_record(dynamic raw);
-
-  @protected
-  BrowserEngineStatus dco_decode_browser_engine_status(dynamic raw);
-
-  @protected
-  int dco_decode_i_32(dynamic raw);
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
-
-  @protected
-  List<PriceEntry> dco_decode_list_price_entry(dynamic raw);
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
-
-  @protected
-  List<ProductRecord> dco_decode_list_product_record(dynamic raw);
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
-
-  @protected
-  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
-
-  @protected
-  ProductRecord? dco_decode_opt_box_autoadd_product_record(dynamic raw);
-
-  @protected
-  PriceEngineStatus dco_decode_price_engine_status(dynamic raw);
-
-  @protected
-  PriceEntry dco_decode_price_entry(dynamic raw);
-
-  @protected
-  ProductRecord dco_decode_product_record(dynamic raw);
-
-  @protected
-  (
-    BrowserEngine,
-    ServiceStatus
-  ) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_browser_engine_service_status(
-      dynamic raw);
-
-  @protected
-  ServiceStatus dco_decode_service_status(dynamic raw);
-
-  @protected
-  int dco_decode_u_16(dynamic raw);
-
-  @protected
-  int dco_decode_u_8(dynamic raw);
-
-  @protected
-  void dco_decode_unit(dynamic raw);
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
-
-  @protected
-  BrowserEngine
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          SseDeserializer deserializer);
-
-  @protected
-  BrowserServiceManager
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          SseDeserializer deserializer);
-
-  @protected
-  Database
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          SseDeserializer deserializer);
+@protected DiagnosticCapture sse_decode_diagnostic_capture(SseDeserializer deserializer);
 
-  @protected
-  PriceEngine
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          SseDeserializer deserializer);
+@protected DiagnosticCaptureContent sse_decode_diagnostic_capture_content(SseDeserializer deserializer);
 
-  @protected
-  ProductDetails
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          SseDeserializer deserializer);
+@protected DiscoveryReport sse_decode_discovery_report(SseDeserializer deserializer);
 
-  @protected
-  Value
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          SseDeserializer deserializer);
+@protected DomainRateStatus sse_decode_domain_rate_status(SseDeserializer deserializer);
 
-  @protected
-  BrowserEngine
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          SseDeserializer deserializer);
+@protected EngineEvent sse_decode_engine_event(SseDeserializer deserializer);
 
-  @protected
-  BrowserServiceManager
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          SseDeserializer deserializer);
+@protected double sse_decode_f_64(SseDeserializer deserializer);
 
-  @protected
-  PriceEngine
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          SseDeserializer deserializer);
+@protected FetchErrorKind sse_decode_fetch_error_kind(SseDeserializer deserializer);
 
-  @protected
-  ProductDetails
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          SseDeserializer deserializer);
+@protected FetchPathStats sse_decode_fetch_path_stats(SseDeserializer deserializer);
 
-  @protected
-  BrowserEngine
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          SseDeserializer deserializer);
+@protected FetchTimeouts sse_decode_fetch_timeouts(SseDeserializer deserializer);
 
-  @protected
-  BrowserServiceManager
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          SseDeserializer deserializer);
+@protected FetchTimingStats sse_decode_fetch_timing_stats(SseDeserializer deserializer);
 
-  @protected
-  Database
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          SseDeserializer deserializer);
-
-  @protected
-  PriceEngine
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          SseDeserializer deserializer);
-
-  @protected
-  ProductDetails
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          SseDeserializer deserializer);
-
-  @protected
-  BrowserEngine
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          SseDeserializer deserializer);
+@protected FetcherKind sse_decode_fetcher_kind(SseDeserializer deserializer);
 
-  @protected
-  BrowserServiceManager
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          SseDeserializer deserializer);
+@protected int sse_decode_i_32(SseDeserializer deserializer);
 
-  @protected
-  Database
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          SseDeserializer deserializer);
-
-  @protected
-  PriceEngine
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          SseDeserializer deserializer);
-
-  @protected
-  ProductDetails
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          SseDeserializer deserializer);
-
-  @protected
-  Value
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          SseDeserializer deserializer);
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
-
-  @protected
-  ProductRecord sse_decode_box_autoadd_product_record(
-      SseDeserializer deserializer);
-
-  @protected
-  BrowserEngineStatus sse_decode_browser_engine_status(
-      SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
-
-  @protected
-  List<PriceEntry> sse_decode_list_price_entry(SseDeserializer deserializer);
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
-
-  @protected
-  List<ProductRecord> sse_decode_list_product_record(
-      SseDeserializer deserializer);
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
-
-  @protected
-  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
-
-  @protected
-  ProductRecord? sse_decode_opt_box_autoadd_product_record(
-      SseDeserializer deserializer);
-
-  @protected
-  PriceEngineStatus sse_decode_price_engine_status(
-      SseDeserializer deserializer);
-
-  @protected
-  PriceEntry sse_decode_price_entry(SseDeserializer deserializer);
-
-  @protected
-  ProductRecord sse_decode_product_record(SseDeserializer deserializer);
-
-  @protected
-  (
-    BrowserEngine,
-    ServiceStatus
-  ) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_browser_engine_service_status(
-      SseDeserializer deserializer);
-
-  @protected
-  ServiceStatus sse_decode_service_status(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_16(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
-
-  @protected
-  void sse_encode_AnyhowException(
-      AnyhowException self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          BrowserEngine self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          BrowserServiceManager self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          Database self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          PriceEngine self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          ProductDetails self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          Value self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          BrowserEngine self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          BrowserServiceManager self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          PriceEngine self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          ProductDetails self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          BrowserEngine self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          BrowserServiceManager self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          Database self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          PriceEngine self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          ProductDetails self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          BrowserEngine self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          BrowserServiceManager self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          Database self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          PriceEngine self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          ProductDetails self, SseSerializer serializer);
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          Value self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_product_record(
-      ProductRecord self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_browser_engine_status(
-      BrowserEngineStatus self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+@protected ImageCacheStats sse_decode_image_cache_stats(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_price_entry(
-      List<PriceEntry> self, SseSerializer serializer);
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-      Uint8List self, SseSerializer serializer);
+@protected List<CachedImage> sse_decode_list_cached_image(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_list_product_record(
-      List<ProductRecord> self, SseSerializer serializer);
+@protected List<DiagnosticCapture> sse_decode_list_diagnostic_capture(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+@protected List<DomainRateStatus> sse_decode_list_domain_rate_status(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+@protected List<FetchPathStats> sse_decode_list_fetch_path_stats(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_opt_box_autoadd_product_record(
-      ProductRecord? self, SseSerializer serializer);
+@protected List<FetchTimingStats> sse_decode_list_fetch_timing_stats(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_price_engine_status(
-      PriceEngineStatus self, SseSerializer serializer);
+@protected List<PriceEntry> sse_decode_list_price_entry(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_price_entry(PriceEntry self, SseSerializer serializer);
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_product_record(ProductRecord self, SseSerializer serializer);
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
 
-  @protected
-  void
-      sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_browser_engine_service_status(
-          (BrowserEngine, ServiceStatus) self, SseSerializer serializer);
+@protected List<ProductRecord> sse_decode_list_product_record(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_service_status(ServiceStatus self, SseSerializer serializer);
+@protected List<ProxyStatus> sse_decode_list_proxy_status(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_u_16(int self, SseSerializer serializer);
+@protected List<RefreshOutcome> sse_decode_list_refresh_outcome(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected List<ResourceType> sse_decode_list_resource_type(SseDeserializer deserializer);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected List<SealedCookieJar> sse_decode_list_sealed_cookie_jar(SseDeserializer deserializer);
+
+@protected List<SiteConfig> sse_decode_list_site_config(SseDeserializer deserializer);
+
+@protected Money sse_decode_money(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected BinaryInfo? sse_decode_opt_box_autoadd_binary_info(SseDeserializer deserializer);
+
+@protected CachedImage? sse_decode_opt_box_autoadd_cached_image(SseDeserializer deserializer);
+
+@protected Money? sse_decode_opt_box_autoadd_money(SseDeserializer deserializer);
+
+@protected ProductRecord? sse_decode_opt_box_autoadd_product_record(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected PriceEngineStatus sse_decode_price_engine_status(SseDeserializer deserializer);
+
+@protected PriceEntry sse_decode_price_entry(SseDeserializer deserializer);
+
+@protected ProductRecord sse_decode_product_record(SseDeserializer deserializer);
+
+@protected ProxyStatus sse_decode_proxy_status(SseDeserializer deserializer);
+
+@protected RateLimit sse_decode_rate_limit(SseDeserializer deserializer);
+
+@protected ReadyCondition sse_decode_ready_condition(SseDeserializer deserializer);
+
+@protected (BrowserEngine,ServiceStatus) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_browser_engine_service_status(SseDeserializer deserializer);
+
+@protected RecyclePolicy sse_decode_recycle_policy(SseDeserializer deserializer);
+
+@protected RefreshOutcome sse_decode_refresh_outcome(SseDeserializer deserializer);
+
+@protected ResourceType sse_decode_resource_type(SseDeserializer deserializer);
+
+@protected RetryPolicy sse_decode_retry_policy(SseDeserializer deserializer);
+
+@protected SealedCookieJar sse_decode_sealed_cookie_jar(SseDeserializer deserializer);
+
+@protected ServiceStatus sse_decode_service_status(SseDeserializer deserializer);
+
+@protected SiteConfig sse_decode_site_config(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected WatchdogConfig sse_decode_watchdog_config(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(BrowserEngine self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(BrowserServiceManager self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(Database self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(PriceEngine self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(ProductDetails self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(Value self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(BrowserEngine self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(BrowserServiceManager self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(PriceEngine self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(ProductDetails self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(BrowserEngine self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(BrowserServiceManager self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(Database self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(PriceEngine self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(ProductDetails self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(BrowserEngine self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(BrowserServiceManager self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(Database self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(PriceEngine self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(ProductDetails self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(Value self, SseSerializer serializer);
+
+@protected void sse_encode_Set_String_None(Set<String> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_engine_event_Sse(RustStreamSink<EngineEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_binary_info(BinaryInfo self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_binary_info(BinaryInfo self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_browser_options(BrowserOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_cached_image(CachedImage self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_fetch_timeouts(FetchTimeouts self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_money(Money self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_product_record(ProductRecord self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_rate_limit(RateLimit self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_ready_condition(ReadyCondition self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_recycle_policy(RecyclePolicy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_retry_policy(RetryPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_site_config(SiteConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_watchdog_config(WatchdogConfig self, SseSerializer serializer);
+
+@protected void sse_encode_browser_engine_status(BrowserEngineStatus self, SseSerializer serializer);
+
+@protected void sse_encode_browser_kind(BrowserKind self, SseSerializer serializer);
+
+@protected void sse_encode_browser_options(BrowserOptions self, SseSerializer serializer);
+
+@protected void sse_encode_cached_image(CachedImage self, SseSerializer serializer);
+
+@protected void sse_encode_cookie_format(CookieFormat self, SseSerializer serializer);
+
+@protected void sse_encode_diagnostic_capture(DiagnosticCapture self, SseSerializer serializer);
+
+@protected void sse_encode_diagnostic_capture_content(DiagnosticCaptureContent self, SseSerializer serializer);
+
+@protected void sse_encode_discovery_report(DiscoveryReport self, SseSerializer serializer);
+
+@protected void sse_encode_domain_rate_status(DomainRateStatus self, SseSerializer serializer);
+
+@protected void sse_encode_engine_event(EngineEvent self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_fetch_error_kind(FetchErrorKind self, SseSerializer serializer);
+
+@protected void sse_encode_fetch_path_stats(FetchPathStats self, SseSerializer serializer);
+
+@protected void sse_encode_fetch_timeouts(FetchTimeouts self, SseSerializer serializer);
+
+@protected void sse_encode_fetch_timing_stats(FetchTimingStats self, SseSerializer serializer);
+
+@protected void sse_encode_fetcher_kind(FetcherKind self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_image_cache_stats(ImageCacheStats self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_cached_image(List<CachedImage> self, SseSerializer serializer);
+
+@protected void sse_encode_list_diagnostic_capture(List<DiagnosticCapture> self, SseSerializer serializer);
+
+@protected void sse_encode_list_domain_rate_status(List<DomainRateStatus> self, SseSerializer serializer);
+
+@protected void sse_encode_list_fetch_path_stats(List<FetchPathStats> self, SseSerializer serializer);
+
+@protected void sse_encode_list_fetch_timing_stats(List<FetchTimingStats> self, SseSerializer serializer);
+
+@protected void sse_encode_list_price_entry(List<PriceEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_product_record(List<ProductRecord> self, SseSerializer serializer);
+
+@protected void sse_encode_list_proxy_status(List<ProxyStatus> self, SseSerializer serializer);
+
+@protected void sse_encode_list_refresh_outcome(List<RefreshOutcome> self, SseSerializer serializer);
+
+@protected void sse_encode_list_resource_type(List<ResourceType> self, SseSerializer serializer);
+
+@protected void sse_encode_list_sealed_cookie_jar(List<SealedCookieJar> self, SseSerializer serializer);
+
+@protected void sse_encode_list_site_config(List<SiteConfig> self, SseSerializer serializer);
+
+@protected void sse_encode_money(Money self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_binary_info(BinaryInfo? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_cached_image(CachedImage? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_money(Money? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_product_record(ProductRecord? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
+
+@protected void sse_encode_price_engine_status(PriceEngineStatus self, SseSerializer serializer);
+
+@protected void sse_encode_price_entry(PriceEntry self, SseSerializer serializer);
+
+@protected void sse_encode_product_record(ProductRecord self, SseSerializer serializer);
+
+@protected void sse_encode_proxy_status(ProxyStatus self, SseSerializer serializer);
+
+@protected void sse_encode_rate_limit(RateLimit self, SseSerializer serializer);
+
+@protected void sse_encode_ready_condition(ReadyCondition self, SseSerializer serializer);
+
+@protected void sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_browser_engine_service_status((BrowserEngine,ServiceStatus) self, SseSerializer serializer);
+
+@protected void sse_encode_recycle_policy(RecyclePolicy self, SseSerializer serializer);
+
+@protected void sse_encode_refresh_outcome(RefreshOutcome self, SseSerializer serializer);
+
+@protected void sse_encode_resource_type(ResourceType self, SseSerializer serializer);
+
+@protected void sse_encode_retry_policy(RetryPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_sealed_cookie_jar(SealedCookieJar self, SseSerializer serializer);
+
+@protected void sse_encode_service_status(ServiceStatus self, SseSerializer serializer);
+
+@protected void sse_encode_site_config(SiteConfig self, SseSerializer serializer);
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_watchdog_config(WatchdogConfig self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-              ptr);
-
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-              ptr);
-
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-              ptr);
-
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-              ptr);
-
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-              ptr);
-
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-              ptr);
-
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-              ptr);
-
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-              ptr);
-
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-              ptr);
-
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-              ptr);
-
-  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          int ptr) =>
-      wasmModule
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-              ptr);
-
-  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          int ptr) =>
-      wasmModule
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-              ptr);
-}
-
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
-
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          int ptr);
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(
-          int ptr);
-
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          int ptr);
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(
-          int ptr);
-
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          int ptr);
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(
-          int ptr);
-
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          int ptr);
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(
-          int ptr);
-
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          int ptr);
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(
-          int ptr);
-
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          int ptr);
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(
-          int ptr);
-}
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(ptr);
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
+
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserEngine(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerBrowserServiceManager(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDatabase(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPriceEngine(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerProductDetails(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerValue(int ptr);
+        }
+        
\ No newline at end of file
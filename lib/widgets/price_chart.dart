@@ -46,9 +46,13 @@ class _PriceChartState extends State<PriceChart>
     if (widget.priceHistory.isNotEmpty) {
       for (var i = 0; i < widget.priceHistory.length; i++) {
         spots
-            .add(FlSpot(i.toDouble(), widget.priceHistory[i].price.toDouble()));
+            .add(FlSpot(i.toDouble(), widget.priceHistory[i].price.asMajor()));
       }
     }
+    // Axis labels use the currency of the latest price
+    final currency = widget.priceHistory.isNotEmpty
+        ? widget.priceHistory.last.price.currency
+        : null;
 
     // Handle empty data case
     if (spots.isEmpty) {
@@ -161,7 +165,9 @@ class _PriceChartState extends State<PriceChart>
                           padding: const EdgeInsets.only(right: 8.0),
                           child: Text(
                             NumberFormat.compactSimpleCurrency(
-                                    locale: 'en_IN', decimalDigits: 0)
+                                    locale: 'en_IN',
+                                    name: currency,
+                                    decimalDigits: 0)
                                 .format(maxPrice),
                             style: TextStyle(
                               color: theme.colorScheme.onSurfaceVariant,
@@ -190,7 +196,9 @@ class _PriceChartState extends State<PriceChart>
                       padding: const EdgeInsets.only(right: 8.0),
                       child: Text(
                         NumberFormat.compactSimpleCurrency(
-                                locale: 'en_IN', decimalDigits: 0)
+                                locale: 'en_IN',
+                                name: currency,
+                                decimalDigits: 0)
                             .format(value),
                         style: TextStyle(
                           color: theme.colorScheme.onSurfaceVariant,
@@ -2,7 +2,6 @@ import 'dart:convert';
 import 'package:palert/widgets/price_chart.dart';
 import 'package:flutter/material.dart';
 import 'package:flutter/services.dart';
-import 'package:intl/intl.dart';
 import 'package:provider/provider.dart';
 import 'package:palert/providers/product_provider.dart';
 import 'package:palert/providers/status_provider.dart';
@@ -185,11 +184,7 @@ class _ProductDetailViewState extends State<ProductDetailView> {
                                 color: theme.colorScheme.onPrimaryContainer)),
                         const SizedBox(height: 4),
                         Text(
-                          NumberFormat.currency(
-                                  locale: 'en_IN',
-                                  symbol: '₹ ',
-                                  decimalDigits: 0)
-                              .format(latestPrice.price),
+                          latestPrice.price.display(),
                           style: theme.textTheme.displaySmall?.copyWith(
                               fontWeight: FontWeight.bold,
                               color: theme.colorScheme.primary),
@@ -215,9 +210,7 @@ class _ProductDetailViewState extends State<ProductDetailView> {
                               color: theme.colorScheme.onPrimaryContainer)),
                       const SizedBox(height: 4),
                       Text(
-                        NumberFormat.currency(
-                                locale: 'en_IN', symbol: '₹ ', decimalDigits: 0)
-                            .format(latestPrice.price),
+                        latestPrice.price.display(),
                         style: theme.textTheme.displaySmall?.copyWith(
                             fontWeight: FontWeight.bold,
                             color: theme.colorScheme.primary),
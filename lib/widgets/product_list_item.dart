@@ -1,6 +1,5 @@
 import 'package:flutter/material.dart';
 import 'package:flutter/services.dart';
-import 'package:intl/intl.dart';
 import 'package:provider/provider.dart';
 import 'package:palert/providers/product_provider.dart';
 import 'package:palert/src/rust/api/models.dart';
@@ -24,8 +23,9 @@ class ProductListItem extends StatelessWidget {
     final lastPrice = product.priceHistory.last.price;
     final prevPrice =
         product.priceHistory[product.priceHistory.length - 2].price;
-    if (lastPrice > prevPrice) return PriceChange.increased;
-    if (lastPrice < prevPrice) return PriceChange.decreased;
+    if (lastPrice.currency != prevPrice.currency) return PriceChange.unknown;
+    if (lastPrice.asMajor() > prevPrice.asMajor()) return PriceChange.increased;
+    if (lastPrice.asMajor() < prevPrice.asMajor()) return PriceChange.decreased;
     return PriceChange.same;
   }
 
@@ -175,13 +175,7 @@ class ProductListItem extends StatelessWidget {
                         crossAxisAlignment: CrossAxisAlignment.center,
                         children: [
                           Text(
-                            latestPrice != null
-                                ? NumberFormat.currency(
-                                        locale: 'en_IN',
-                                        symbol: '₹',
-                                        decimalDigits: 0)
-                                    .format(latestPrice)
-                                : 'N/A',
+                            latestPrice?.display() ?? 'N/A',
                             style: theme.textTheme.titleMedium?.copyWith(
                                 color: theme.colorScheme.primary,
                                 fontWeight: FontWeight.w800),
@@ -94,14 +94,22 @@ class _ProductListViewState extends State<ProductListView> {
         _isSearching ? List.of(_filteredProducts) : List.of(provider.products);
     if (_sortMode == SortMode.lowToHigh) {
       products.sort((a, b) {
-        final pa = a.priceHistory.isNotEmpty ? a.priceHistory.last.price : 0;
-        final pb = b.priceHistory.isNotEmpty ? b.priceHistory.last.price : 0;
+        final pa = a.priceHistory.isNotEmpty
+            ? a.priceHistory.last.price.asMajor()
+            : 0.0;
+        final pb = b.priceHistory.isNotEmpty
+            ? b.priceHistory.last.price.asMajor()
+            : 0.0;
         return pa.compareTo(pb);
       });
     } else if (_sortMode == SortMode.highToLow) {
       products.sort((a, b) {
-        final pa = a.priceHistory.isNotEmpty ? a.priceHistory.last.price : 0;
-        final pb = b.priceHistory.isNotEmpty ? b.priceHistory.last.price : 0;
+        final pa = a.priceHistory.isNotEmpty
+            ? a.priceHistory.last.price.asMajor()
+            : 0.0;
+        final pb = b.priceHistory.isNotEmpty
+            ? b.priceHistory.last.price.asMajor()
+            : 0.0;
         return pb.compareTo(pa);
       });
     }
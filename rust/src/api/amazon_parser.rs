@@ -1,5 +1,6 @@
 // src/amazon_parser.rs
 use crate::api::models::ProductDetails;
+use crate::api::money::{Money, DEFAULT_CURRENCY};
 use scraper::{Html, Selector};
 use serde_json::json;
 use std::collections::HashMap;
@@ -15,6 +16,32 @@ fn get_text(element: &scraper::ElementRef, selector_str: &str) -> String {
         .unwrap_or_else(|| "Not Found".to_string())
 }
 
+// Price shown in an `.a-price` block. The off-screen label carries the full "₹1,299.00";
+// when it is empty the visible symbol, whole and fraction parts of the same block are used.
+fn core_price(block: &scraper::ElementRef) -> Option<Money> {
+    let part = |selector: &str| {
+        let selector = Selector::parse(selector).unwrap();
+        block
+            .select(&selector)
+            .next()
+            .map(|el| el.text().collect::<String>().trim().to_string())
+            .unwrap_or_default()
+    };
+    let offscreen = part("span.a-offscreen");
+    if let Some(price) = Money::parse(&offscreen, DEFAULT_CURRENCY) {
+        return Some(price);
+    }
+    // The whole part ends with the decimal point, e.g. "1,299."
+    let whole = part("span.a-price-whole");
+    let fraction = part("span.a-price-fraction");
+    let visible = if fraction.is_empty() {
+        whole
+    } else {
+        format!("{}.{}", whole.trim_end_matches('.'), fraction)
+    };
+    Money::parse(&format!("{}{}", part("span.a-price-symbol"), visible), DEFAULT_CURRENCY)
+}
+
 // The function now accepts the URL as an argument
 pub fn extract_details(html_source: &str, url: &str) -> anyhow::Result<ProductDetails> {
     let document = Html::parse_document(html_source);
@@ -28,7 +55,9 @@ pub fn extract_details(html_source: &str, url: &str) -> anyhow::Result<ProductDe
     // --- Selectors ---
     let title_selector = Selector::parse("span#productTitle").unwrap();
     // ... other selectors remain the same ...
-    let price_selector = Selector::parse("span.a-price-whole").unwrap();
+    // Only the buy-box price; the page also lists the M.R.P. and other products' prices
+    let price_selector =
+        Selector::parse("#corePrice_feature_div .a-price, #corePriceDisplay_desktop_feature_div .a-price").unwrap();
     let _rating_text_selector = Selector::parse("i.a-icon-star span.a-icon-alt").unwrap();
     let _rating_count_selector = Selector::parse("span#acrCustomerReviewText").unwrap();
     let features_selector = Selector::parse("#feature-bullets .a-list-item").unwrap();
@@ -44,16 +73,7 @@ pub fn extract_details(html_source: &str, url: &str) -> anyhow::Result<ProductDe
         .map(|t| t.text().collect::<String>().trim().to_string())
         .unwrap_or_else(|| "Not Found".to_string());
     
-    let price: Option<i32> = document.select(&price_selector).next().and_then(|p| {
-        p.text()
-            .collect::<String>()
-            .replace(',', "")
-            .split('.')
-            .next()
-            .unwrap_or("")
-            .parse::<i32>()
-            .ok()
-    });
+    let price = document.select(&price_selector).next().and_then(|p| core_price(&p));
 
     let rating_text = get_text(&document.root_element(), "i.a-icon-star span.a-icon-alt");
     let rating_count = get_text(&document.root_element(), "span#acrCustomerReviewText");
@@ -108,4 +128,49 @@ pub fn extract_details(html_source: &str, url: &str) -> anyhow::Result<ProductDe
         seller,
         images,
     })
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://www.amazon.in/dp/B0TESTTEST";
+
+    fn page(core_price: &str) -> String {
+        format!(
+            r#"<html><body>
+            <span id="productTitle">Wireless Mouse</span>
+            <div id="corePriceDisplay_desktop_feature_div">{}
+              <span class="a-price a-text-price"><span class="a-offscreen">₹1,999.00</span></span>
+            </div>
+            <div class="carousel"><span class="a-price"><span class="a-offscreen">₹499.00</span></span></div>
+            </body></html>"#,
+            core_price
+        )
+    }
+
+    #[test]
+    fn takes_the_buy_box_price() {
+        let html = page(r#"<span class="a-price"><span class="a-offscreen">₹1,299.00</span></span>"#);
+        let details = extract_details(&html, URL).unwrap();
+        assert_eq!(details.price, Some(Money::new(129_900, "INR")));
+    }
+
+    #[test]
+    fn falls_back_to_the_visible_parts_of_the_same_price() {
+        let html = page(
+            r#"<span class="a-price"><span class="a-offscreen"></span>
+            <span class="a-price-symbol">₹</span><span class="a-price-whole">1,299<span class="a-price-decimal">.</span></span><span class="a-price-fraction">50</span></span>"#,
+        );
+        let details = extract_details(&html, URL).unwrap();
+        assert_eq!(details.price, Some(Money::new(129_950, "INR")));
+    }
+
+    #[test]
+    fn never_uses_mrp_or_other_products_prices() {
+        let html = page(r#"<span class="a-price"><span class="a-offscreen"></span></span>"#);
+        assert_eq!(extract_details(&html, URL).unwrap().price, None);
+        let without_core = "<html><body><span class=\"a-price\"><span class=\"a-offscreen\">₹499.00</span></span></body></html>";
+        assert_eq!(extract_details(without_core, URL).unwrap().price, None);
+    }
+}
@@ -1,10 +1,66 @@
 
-use crate::api::{models::ProductRecord, price_engine::{PriceEngine, PriceEngineStatus}};
+use crate::api::{binary_discovery::{self, DiscoveryReport}, browser_service_manager::{BrowserKind, BrowserOptions}, cookie_jar::CookieFormat, diagnostics::{DiagnosticCapture, DiagnosticCaptureContent}, events::EngineEvent, fetcher::FetcherKind, image_cache::ImageCacheStats, models::{FetchPathStats, FetchTimingStats, ProductRecord, RefreshOutcome}, price_engine::{PriceEngine, PriceEngineStatus}, proxy::ProxyStatus, rate_limiter::RateLimit, recycle_policy::RecyclePolicy, resource_blocking::ResourceType, retry_policy::RetryPolicy, site_config::{ReadyCondition, SiteConfig}, timeouts::FetchTimeouts, watchdog::WatchdogConfig};
+use crate::frb_generated::StreamSink;
+use tokio::sync::broadcast::error::RecvError;
 
 pub async fn get_price_engine(port: u16, browser_path: &str, db_path: &str, driver_path:&str) -> anyhow::Result<PriceEngine> {
     PriceEngine::new(port, browser_path, db_path, driver_path).await
 }
 
+pub async fn get_price_engine_for_browser(
+    port: u16,
+    browser_kind: BrowserKind,
+    browser_path: &str,
+    db_path: &str,
+    driver_path: &str,
+) -> anyhow::Result<PriceEngine> {
+    PriceEngine::new_with_browser_kind(port, browser_kind, browser_path, db_path, driver_path).await
+}
+
+pub async fn get_price_engine_with_options(
+    port: u16,
+    browser_kind: BrowserKind,
+    options: BrowserOptions,
+    browser_path: &str,
+    db_path: &str,
+    driver_path: &str,
+) -> anyhow::Result<PriceEngine> {
+    PriceEngine::new_with_options(port, browser_kind, options, browser_path, db_path, driver_path).await
+}
+
+/// Finds the browser and driver of `browser_kind` on this machine and checks that their versions match.
+pub async fn discover_browser_binaries(browser_kind: BrowserKind) -> anyhow::Result<DiscoveryReport> {
+    Ok(binary_discovery::discover(browser_kind).await)
+}
+
+/// Checks user-chosen browser and driver paths; an empty path is discovered instead.
+pub async fn check_browser_binaries(browser_kind: BrowserKind, browser_path: &str, driver_path: &str) -> anyhow::Result<DiscoveryReport> {
+    Ok(binary_discovery::inspect(browser_kind, browser_path, driver_path).await)
+}
+
+pub async fn get_browser_binaries(engine: &PriceEngine) -> anyhow::Result<DiscoveryReport> {
+    engine.get_browser_binaries().await
+}
+
+/// Returns the last `lines` lines chromedriver (or the configured driver) printed, for troubleshooting.
+pub async fn get_driver_log_tail(engine: &PriceEngine, lines: u32) -> anyhow::Result<Vec<String>> {
+    engine.get_driver_log_tail(lines).await
+}
+
+/// Creates an engine that uses an existing WebDriver server or Selenium Grid instead of spawning a driver.
+pub async fn get_remote_price_engine(
+    webdriver_url: &str,
+    browser_kind: BrowserKind,
+    options: BrowserOptions,
+    db_path: &str,
+) -> anyhow::Result<PriceEngine> {
+    PriceEngine::new_remote(webdriver_url, browser_kind, options, db_path).await
+}
+
+pub async fn get_http_only_price_engine(db_path: &str) -> anyhow::Result<PriceEngine> {
+    PriceEngine::new_http_only(db_path).await
+}
+
 pub async fn shutdown_price_engine(engine: PriceEngine) -> anyhow::Result<()> {
     engine.shutdown().await
 }
@@ -16,6 +72,28 @@ pub async fn fetch_and_update_product(
     engine.fetch_and_update_product(url).await
 }
 
+pub async fn refresh_all_products(
+    engine: &mut PriceEngine,
+) -> anyhow::Result<Vec<RefreshOutcome>> {
+    engine.refresh_all_products().await
+}
+
+pub async fn set_browser_pool_size(engine: &mut PriceEngine, pool_size: u32) -> anyhow::Result<()> {
+    engine.set_browser_pool_size(pool_size).await
+}
+
+pub async fn set_browser_recycle_policy(engine: &mut PriceEngine, policy: RecyclePolicy) -> anyhow::Result<()> {
+    engine.set_browser_recycle_policy(policy).await
+}
+
+pub async fn set_fetch_timeouts(engine: &mut PriceEngine, timeouts: FetchTimeouts) -> anyhow::Result<()> {
+    engine.set_fetch_timeouts(timeouts).await
+}
+
+pub async fn get_fetch_timeouts(engine: &PriceEngine) -> anyhow::Result<FetchTimeouts> {
+    Ok(engine.get_fetch_timeouts())
+}
+
 pub async fn get_all_products_in_db(
     engine: &PriceEngine,
 ) -> anyhow::Result<Vec<ProductRecord>> {
@@ -47,16 +125,176 @@ pub async fn restore_from_backup(
         .map_err(|e| anyhow::anyhow!("Failed to restore from backup: {}", e))
 }
 
+pub async fn get_image_cache_stats(engine: &PriceEngine) -> anyhow::Result<ImageCacheStats> {
+    engine.get_image_cache_stats()
+        .map_err(|e| anyhow::anyhow!("Failed to read image cache: {}", e))
+}
+
+pub async fn cleanup_image_cache(engine: &PriceEngine) -> anyhow::Result<ImageCacheStats> {
+    engine.cleanup_image_cache()
+        .map_err(|e| anyhow::anyhow!("Failed to clean up image cache: {}", e))
+}
+
+pub async fn get_site_configs(engine: &PriceEngine) -> anyhow::Result<Vec<SiteConfig>> {
+    Ok(engine.get_site_configs())
+}
+
+pub async fn set_site_fetcher(
+    engine: &mut PriceEngine,
+    domain: &str,
+    fetcher: FetcherKind,
+) -> anyhow::Result<()> {
+    engine.set_site_fetcher(domain, fetcher)
+}
+
+pub async fn set_site_fallback(
+    engine: &mut PriceEngine,
+    domain: &str,
+    enabled: bool,
+) -> anyhow::Result<()> {
+    engine.set_site_fallback(domain, enabled);
+    Ok(())
+}
+
+pub async fn set_site_ready_condition(
+    engine: &mut PriceEngine,
+    domain: &str,
+    condition: ReadyCondition,
+    timeout_ms: u32,
+) -> anyhow::Result<()> {
+    engine.set_site_ready_condition(domain, condition, timeout_ms);
+    Ok(())
+}
+
+pub async fn set_site_rate_limit(
+    engine: &mut PriceEngine,
+    domain: &str,
+    rate_limit: RateLimit,
+) -> anyhow::Result<()> {
+    engine.set_site_rate_limit(domain, rate_limit);
+    Ok(())
+}
+
+pub async fn set_site_retry_policy(
+    engine: &mut PriceEngine,
+    domain: &str,
+    retry_policy: RetryPolicy,
+) -> anyhow::Result<()> {
+    engine.set_site_retry_policy(domain, retry_policy);
+    Ok(())
+}
+
+/// Imports cookies (Netscape cookies.txt or JSON) for `domain`; returns how many were imported.
+pub async fn import_cookies(
+    engine: &mut PriceEngine,
+    domain: &str,
+    content: String,
+    format: CookieFormat,
+) -> anyhow::Result<u32> {
+    engine.import_cookies(domain, &content, format).await
+}
+
+pub async fn export_cookies(engine: &mut PriceEngine, domain: &str, format: CookieFormat) -> anyhow::Result<String> {
+    engine.export_cookies(domain, format).await
+}
+
+/// Lists the latest screenshots and page sources saved when fetching the product failed.
+pub async fn get_diagnostic_captures(
+    engine: &PriceEngine,
+    product_id: &str,
+    limit: u32,
+) -> anyhow::Result<Vec<DiagnosticCapture>> {
+    engine.get_diagnostic_captures(product_id, limit)
+}
+
+/// Lists the latest captures saved when fetching `url` failed, or of every page when `url`
+/// is `None`; works for links that never became a product because their first fetch failed.
+pub async fn get_diagnostic_captures_for_url(
+    engine: &PriceEngine,
+    url: Option<String>,
+    limit: u32,
+) -> anyhow::Result<Vec<DiagnosticCapture>> {
+    engine.get_diagnostic_captures_for_url(url, limit)
+}
+
+pub async fn open_diagnostic_capture(engine: &PriceEngine, capture_id: &str) -> anyhow::Result<DiagnosticCaptureContent> {
+    engine.open_diagnostic_capture(capture_id)
+}
+
+pub async fn set_diagnostics_retention(engine: &mut PriceEngine, max_captures: u32) -> anyhow::Result<()> {
+    engine.set_diagnostics_retention(max_captures)
+}
+
+pub async fn set_default_proxies(engine: &mut PriceEngine, proxies: Vec<String>) -> anyhow::Result<()> {
+    engine.set_default_proxies(proxies)
+}
+
+pub async fn set_site_proxies(engine: &mut PriceEngine, domain: &str, proxies: Vec<String>) -> anyhow::Result<()> {
+    engine.set_site_proxies(domain, proxies)
+}
+
+pub async fn get_proxy_status(engine: &PriceEngine) -> anyhow::Result<Vec<ProxyStatus>> {
+    Ok(engine.get_proxy_status())
+}
+
+pub async fn set_site_block_cooldown(engine: &mut PriceEngine, domain: &str, cooldown_ms: u32) -> anyhow::Result<()> {
+    engine.set_site_block_cooldown(domain, cooldown_ms);
+    Ok(())
+}
+
+pub async fn set_site_allowed_resources(engine: &mut PriceEngine, domain: &str, allowed: Vec<ResourceType>) -> anyhow::Result<()> {
+    engine.set_site_allowed_resources(domain, allowed);
+    Ok(())
+}
+
+/// Forwards engine events, such as a site serving a captcha, to the UI until the stream is closed.
+pub async fn subscribe_engine_events(engine: &PriceEngine, sink: StreamSink<EngineEvent>) -> anyhow::Result<()> {
+    let mut receiver = engine.subscribe_events();
+    tokio::spawn(async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    if sink.add(event).is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => println!("Event stream fell behind, {} events dropped", skipped),
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+    Ok(())
+}
+
+pub async fn get_fetch_path_stats(engine: &PriceEngine) -> anyhow::Result<Vec<FetchPathStats>> {
+    engine.get_fetch_path_stats()
+}
+
+pub async fn get_fetch_timing_stats(engine: &PriceEngine) -> anyhow::Result<Vec<FetchTimingStats>> {
+    engine.get_fetch_timing_stats()
+}
+
 // New functions for service management
 pub async fn check_service_status(engine: &PriceEngine) -> anyhow::Result<PriceEngineStatus> {
     Ok(engine.check_service_status().await)
 }
 
 pub async fn get_current_port(engine: &PriceEngine) -> anyhow::Result<u16> {
-    Ok(engine.get_current_port())
+    Ok(engine.get_current_port().await)
 }
 
 pub async fn restart_browser_service(engine: &mut PriceEngine) -> anyhow::Result<String> {
     let status = engine.restart_browser_service().await?;
     Ok(format!("Service restarted: {} on port {}", status.error_message.unwrap_or_else(|| "Successfully restarted".to_string()), status.port))
 }
+
+/// Probes the browser service in the background and restarts it when it fails; status
+/// changes arrive through `subscribe_engine_events`.
+pub async fn start_service_watchdog(engine: &mut PriceEngine, config: WatchdogConfig) -> anyhow::Result<()> {
+    engine.start_service_watchdog(config)
+}
+
+pub async fn stop_service_watchdog(engine: &mut PriceEngine) -> anyhow::Result<()> {
+    engine.stop_service_watchdog();
+    Ok(())
+}
@@ -0,0 +1,328 @@
+// src/binary_discovery.rs
+use regex::Regex;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, SystemTime};
+
+use crate::api::browser_service_manager::BrowserKind;
+
+const VERSION_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn version_pattern() -> &'static Regex {
+    static VERSION: OnceLock<Regex> = OnceLock::new();
+    VERSION.get_or_init(|| Regex::new(r"\d+(?:\.\d+)+").unwrap())
+}
+
+// Versions already read, with the modification time of the executable they were read
+// from, so an updated browser or driver is asked again
+type VersionCache = Mutex<HashMap<PathBuf, (SystemTime, Option<String>)>>;
+
+fn known_versions() -> &'static VersionCache {
+    static KNOWN: OnceLock<VersionCache> = OnceLock::new();
+    KNOWN.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// A browser or driver executable and the version it reports
+#[derive(Debug, Clone)]
+pub struct BinaryInfo {
+    pub path: String,
+    /// Full version string, e.g. "126.0.6478.126"; `None` if it could not be read.
+    pub version: Option<String>,
+    pub major_version: Option<u32>,
+}
+
+/// The browser and driver found for a browser kind, and whether they work together
+#[derive(Debug, Clone)]
+pub struct DiscoveryReport {
+    pub browser_kind: BrowserKind,
+    pub browser: Option<BinaryInfo>,
+    pub driver: Option<BinaryInfo>,
+    /// False when a binary is missing or the driver was built for another browser version.
+    pub compatible: bool,
+    /// Explanation for the settings screen, including what to install on a mismatch.
+    pub message: String,
+}
+
+/// Searches PATH and the usual install locations for `kind`'s browser and driver.
+pub async fn discover(kind: BrowserKind) -> DiscoveryReport {
+    inspect(kind, "", "").await
+}
+
+/// Checks the given browser and driver, discovering whichever path is empty.
+pub async fn inspect(kind: BrowserKind, browser_path: &str, driver_path: &str) -> DiscoveryReport {
+    let browser_path = non_empty(browser_path).or_else(|| find_browser(kind));
+    let driver_path = non_empty(driver_path).or_else(|| find_driver(kind));
+
+    let browser = match browser_path {
+        Some(path) => Some(browser_info(kind, &path).await),
+        None => None,
+    };
+    let driver = match driver_path {
+        Some(path) => Some(binary_info(&path).await),
+        None => None,
+    };
+    let (compatible, message) = compatibility(kind, browser.as_ref(), driver.as_ref());
+    DiscoveryReport {
+        browser_kind: kind,
+        browser,
+        driver,
+        compatible,
+        message,
+    }
+}
+
+fn non_empty(path: &str) -> Option<PathBuf> {
+    (!path.trim().is_empty()).then(|| PathBuf::from(path.trim()))
+}
+
+fn compatibility(kind: BrowserKind, browser: Option<&BinaryInfo>, driver: Option<&BinaryInfo>) -> (bool, String) {
+    let driver_name = kind.driver_name();
+    let (Some(browser), Some(driver)) = (browser, driver) else {
+        let missing = match (browser, driver) {
+            (None, None) => format!("Neither {:?} nor {} was found", kind, driver_name),
+            (None, _) => format!("{:?} was not found", kind),
+            _ => format!("{} was not found; install it or set its path", driver_name),
+        };
+        return (false, missing);
+    };
+
+    let describe = |info: &BinaryInfo| info.version.clone().unwrap_or_else(|| "unknown version".to_string());
+    let versions = format!("{:?} {}, {} {}", kind, describe(browser), driver_name, describe(driver));
+    // geckodriver is versioned separately and supports a range of Firefox releases
+    if kind == BrowserKind::Firefox {
+        return (true, versions);
+    }
+    match (browser.major_version, driver.major_version) {
+        (Some(browser_major), Some(driver_major)) if browser_major != driver_major => (
+            false,
+            format!("{}: {} {} does not match the browser; install {} {}", versions, driver_name, driver_major, driver_name, browser_major),
+        ),
+        (Some(_), Some(_)) => (true, versions),
+        _ => (true, format!("{} (could not confirm that the versions match)", versions)),
+    }
+}
+
+/// Runs `--version` on the executable.
+async fn binary_info(path: &Path) -> BinaryInfo {
+    let version = cached_version(path, run_version(path)).await;
+    info(path, version)
+}
+
+async fn browser_info(kind: BrowserKind, path: &Path) -> BinaryInfo {
+    // chrome.exe and msedge.exe open a window instead of printing their version on
+    // Windows; their version is the name of a folder next to the executable instead
+    let version = if cfg!(windows) && kind != BrowserKind::Firefox {
+        version_from_install_dir(path)
+    } else {
+        cached_version(path, run_version(path)).await
+    };
+    info(path, version)
+}
+
+// Starting a browser just to read its version is slow, so each executable is only run
+// once until the file changes
+async fn cached_version(path: &Path, read: impl std::future::Future<Output = Option<String>>) -> Option<String> {
+    let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    if let Some(modified) = modified {
+        if let Some((seen, version)) = known_versions().lock().unwrap().get(path) {
+            if *seen == modified {
+                return version.clone();
+            }
+        }
+    }
+    let version = read.await;
+    if let Some(modified) = modified {
+        known_versions().lock().unwrap().insert(path.to_path_buf(), (modified, version.clone()));
+    }
+    version
+}
+
+fn info(path: &Path, version: Option<String>) -> BinaryInfo {
+    let major_version = version
+        .as_deref()
+        .and_then(|v| v.split('.').next())
+        .and_then(|major| major.parse().ok());
+    BinaryInfo {
+        path: path.to_string_lossy().into_owned(),
+        version,
+        major_version,
+    }
+}
+
+async fn run_version(path: &Path) -> Option<String> {
+    let mut command = tokio::process::Command::new(path);
+    command.arg("--version").kill_on_drop(true);
+    let output = match tokio::time::timeout(VERSION_TIMEOUT, command.output()).await {
+        Ok(Ok(output)) => output,
+        Ok(Err(e)) => {
+            println!("Could not run {} --version: {}", path.display(), e);
+            return None;
+        }
+        Err(_) => {
+            println!("{} --version did not answer within {} s", path.display(), VERSION_TIMEOUT.as_secs());
+            return None;
+        }
+    };
+    let text = String::from_utf8_lossy(&output.stdout);
+    version_pattern().find(&text).map(|m| m.as_str().to_string())
+}
+
+fn version_from_install_dir(path: &Path) -> Option<String> {
+    let dir = path.parent()?;
+    std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| version_pattern().find(name).is_some_and(|m| m.as_str() == name))
+        .max_by_key(|name| name.split('.').map(|part| part.parse::<u32>().unwrap_or(0)).collect::<Vec<_>>())
+}
+
+fn find_browser(kind: BrowserKind) -> Option<PathBuf> {
+    let names: &[&str] = match kind {
+        BrowserKind::Chrome => &["google-chrome", "google-chrome-stable", "chrome"],
+        BrowserKind::Chromium => &["chromium", "chromium-browser"],
+        BrowserKind::Edge => &["microsoft-edge", "microsoft-edge-stable", "msedge"],
+        BrowserKind::Firefox => &["firefox"],
+    };
+    find_in_path(names).or_else(|| browser_locations(kind, &windows_roots()).into_iter().find(|p| p.is_file()))
+}
+
+fn find_driver(kind: BrowserKind) -> Option<PathBuf> {
+    let name = kind.driver_name();
+    find_in_path(&[name]).or_else(|| driver_locations(kind, &windows_roots()).into_iter().find(|p| p.is_file()))
+}
+
+// The usual install locations of `kind`'s browser, under `windows_roots` for Windows
+fn browser_locations(kind: BrowserKind, windows_roots: &[PathBuf]) -> Vec<PathBuf> {
+    let unix: &[&str] = match kind {
+        BrowserKind::Chrome => &[
+            "/opt/google/chrome/chrome",
+            "/Applications/Google Chrome.app/Contents/MacOS/Google Chrome",
+        ],
+        BrowserKind::Chromium => &[
+            "/snap/bin/chromium",
+            "/usr/lib/chromium/chromium",
+            "/Applications/Chromium.app/Contents/MacOS/Chromium",
+        ],
+        BrowserKind::Edge => &[
+            "/opt/microsoft/msedge/msedge",
+            "/Applications/Microsoft Edge.app/Contents/MacOS/Microsoft Edge",
+        ],
+        BrowserKind::Firefox => &[
+            "/usr/lib/firefox/firefox",
+            "/snap/bin/firefox",
+            "/Applications/Firefox.app/Contents/MacOS/firefox",
+        ],
+    };
+    let (dir, exe) = windows_install(kind);
+    unix.iter()
+        .map(PathBuf::from)
+        .chain(windows_locations(windows_roots, &format!(r"{}\{}", dir, exe)))
+        .collect()
+}
+
+// The usual install locations of `kind`'s driver: on Windows its own folder or the
+// browser's folder under each of `windows_roots`
+fn driver_locations(kind: BrowserKind, windows_roots: &[PathBuf]) -> Vec<PathBuf> {
+    let name = kind.driver_name();
+    let unix = [
+        format!("/usr/lib/chromium/{}", name),
+        format!("/usr/lib/chromium-browser/{}", name),
+        format!("/snap/bin/{}", name),
+        format!("/usr/local/bin/{}", name),
+        format!("/opt/homebrew/bin/{}", name),
+    ];
+    let (browser_dir, _) = windows_install(kind);
+    let windows = [format!(r"{}\{}.exe", name, name), format!(r"{}\{}.exe", browser_dir, name)];
+    unix.into_iter()
+        .map(PathBuf::from)
+        .chain(windows.iter().flat_map(|relative| windows_locations(windows_roots, relative)))
+        .collect()
+}
+
+// The folder under a Windows program folder that `kind`'s browser installs into, and
+// the browser's executable
+fn windows_install(kind: BrowserKind) -> (&'static str, &'static str) {
+    match kind {
+        BrowserKind::Chrome => (r"Google\Chrome\Application", "chrome.exe"),
+        BrowserKind::Chromium => (r"Chromium\Application", "chrome.exe"),
+        BrowserKind::Edge => (r"Microsoft\Edge\Application", "msedge.exe"),
+        BrowserKind::Firefox => ("Mozilla Firefox", "firefox.exe"),
+    }
+}
+
+fn find_in_path(names: &[&str]) -> Option<PathBuf> {
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path).find_map(|dir| {
+        names
+            .iter()
+            .map(|name| dir.join(format!("{}{}", name, std::env::consts::EXE_SUFFIX)))
+            .find(|candidate| candidate.is_file())
+    })
+}
+
+// The Windows program folders; none on other systems
+fn windows_roots() -> Vec<PathBuf> {
+    if !cfg!(windows) {
+        return Vec::new();
+    }
+    ["ProgramFiles", "ProgramFiles(x86)", "LOCALAPPDATA"]
+        .iter()
+        .filter_map(std::env::var_os)
+        .map(PathBuf::from)
+        .collect()
+}
+
+// `relative`, a backslash separated path, under each of `roots`
+fn windows_locations(roots: &[PathBuf], relative: &str) -> Vec<PathBuf> {
+    roots
+        .iter()
+        .map(|root| relative.split('\\').fold(root.clone(), |path, part| path.join(part)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn cached_version_reads_each_unchanged_file_once() {
+        let path = std::env::current_exe().unwrap();
+        let first = cached_version(&path, async { Some("1.2.3".to_string()) }).await;
+        let second = cached_version(&path, async { panic!("version read again") }).await;
+        assert_eq!(first.as_deref(), Some("1.2.3"));
+        assert_eq!(second, first);
+    }
+
+    #[test]
+    fn looks_for_drivers_in_the_unix_and_windows_install_folders() {
+        let roots = [PathBuf::from("C:/Program Files"), PathBuf::from("C:/Users/me/AppData/Local")];
+        let under = |root: usize, parts: &[&str]| parts.iter().fold(roots[root].clone(), |path, part| path.join(part));
+
+        let chrome = driver_locations(BrowserKind::Chrome, &roots);
+        assert!(chrome.contains(&PathBuf::from("/usr/local/bin/chromedriver")));
+        assert!(chrome.contains(&under(0, &["chromedriver", "chromedriver.exe"])));
+        assert!(chrome.contains(&under(1, &["Google", "Chrome", "Application", "chromedriver.exe"])));
+
+        let edge = driver_locations(BrowserKind::Edge, &roots);
+        assert!(edge.contains(&under(0, &["Microsoft", "Edge", "Application", "msedgedriver.exe"])));
+        let firefox = driver_locations(BrowserKind::Firefox, &roots);
+        assert!(firefox.contains(&under(1, &["Mozilla Firefox", "geckodriver.exe"])));
+
+        assert!(driver_locations(BrowserKind::Chrome, &[])
+            .iter()
+            .all(|p| p.to_string_lossy().starts_with('/')));
+    }
+
+    #[test]
+    fn looks_for_browsers_in_the_unix_and_windows_install_folders() {
+        let roots = [PathBuf::from("C:/Program Files (x86)")];
+        let edge = browser_locations(BrowserKind::Edge, &roots);
+        assert!(edge.contains(&PathBuf::from("/opt/microsoft/msedge/msedge")));
+        assert!(edge.contains(&roots[0].join("Microsoft").join("Edge").join("Application").join("msedge.exe")));
+        let firefox = browser_locations(BrowserKind::Firefox, &roots);
+        assert_eq!(firefox.last(), Some(&roots[0].join("Mozilla Firefox").join("firefox.exe")));
+    }
+}
@@ -0,0 +1,117 @@
+// src/bot_wall.rs
+use regex::Regex;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::api::events::{self, EngineEvent, EventBus};
+use crate::api::fetch_error::FetchError;
+use crate::api::rate_limiter::RateLimiter;
+
+// Phrases that only appear on interstitial pages, matched against the lower-cased HTML
+const MARKERS: &[(&str, &str)] = &[
+    ("enter the characters you see below", "captcha challenge"),
+    ("/errors/validatecaptcha", "captcha challenge"),
+    ("sorry, we just need to make sure you're not a robot", "robot check"),
+    ("type the characters you see in this image", "captcha challenge"),
+    ("captcha-delivery.com", "captcha challenge"),
+    ("are you a human?", "human verification"),
+];
+
+fn blocked_title() -> &'static Regex {
+    static TITLE: OnceLock<Regex> = OnceLock::new();
+    TITLE.get_or_init(|| Regex::new(r"<title[^>]*>\s*(access denied|403 forbidden|robot check)").unwrap())
+}
+
+/// Returns why `html` looks like a captcha or access-denied page instead of real content.
+pub(crate) fn detect(html: &str) -> Option<&'static str> {
+    let page = html.to_lowercase();
+    if blocked_title().is_match(&page) {
+        return Some("access denied page");
+    }
+    MARKERS
+        .iter()
+        .find(|(marker, _)| page.contains(marker))
+        .map(|(_, reason)| *reason)
+}
+
+/// Fails with [`FetchError::CoolingDown`] while `site` is paused after a bot wall.
+pub(crate) fn ensure_not_cooling_down(url: &str, site_domain: &str, limiter: &RateLimiter) -> anyhow::Result<()> {
+    match limiter.cooldown_remaining(site_domain) {
+        Some(remaining) => Err(FetchError::CoolingDown {
+            url: url.to_string(),
+            domain: site_domain.to_string(),
+            remaining_ms: remaining.as_millis() as u64,
+        }
+        .into()),
+        None => Ok(()),
+    }
+}
+
+/// Checks a fetched page for a bot wall. When one is found the site's cooldown is
+/// started, a [`EngineEvent::SiteBlocked`] is emitted and [`FetchError::Blocked`] returned.
+pub(crate) fn check_page(
+    html: &str,
+    url: &str,
+    site_domain: &str,
+    cooldown_ms: u32,
+    limiter: &RateLimiter,
+    events: &EventBus,
+) -> anyhow::Result<()> {
+    let Some(reason) = detect(html) else {
+        return Ok(());
+    };
+
+    println!("{} served a {} for {}; pausing fetches for {} s", site_domain, reason, url, cooldown_ms / 1000);
+    limiter.start_cooldown(site_domain, Duration::from_millis(cooldown_ms as u64));
+    events.emit(EngineEvent::SiteBlocked {
+        domain: site_domain.to_string(),
+        url: url.to_string(),
+        reason: reason.to_string(),
+        cooldown_ms: cooldown_ms as u64,
+        timestamp: events::now_timestamp(),
+    });
+    Err(FetchError::Blocked {
+        url: url.to_string(),
+        domain: site_domain.to_string(),
+        reason: reason.to_string(),
+        cooldown_ms: cooldown_ms as u64,
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::fetch_error::{self, FetchErrorKind};
+
+    #[test]
+    fn detects_captcha_and_access_denied_pages() {
+        let captcha = "<html><body><form action=\"/errors/validateCaptcha\">Enter the characters you see below</form></body></html>";
+        assert_eq!(detect(captcha), Some("captcha challenge"));
+        assert_eq!(detect("<html><head><title>Access Denied</title></head></html>"), Some("access denied page"));
+        assert_eq!(detect("<TITLE lang=\"en\"> Robot Check</TITLE>"), Some("access denied page"));
+    }
+
+    #[test]
+    fn ignores_product_pages() {
+        let product = "<html><head><title>Wireless Mouse : Amazon.in</title></head><body>Add to Cart</body></html>";
+        assert_eq!(detect(product), None);
+        // Words from the markers in ordinary text do not count
+        assert_eq!(detect("<title>Access control keypad</title><p>Robot vacuum</p>"), None);
+    }
+
+    #[test]
+    fn blocked_page_starts_the_cooldown_and_emits_an_event() {
+        let limiter = RateLimiter::new();
+        let events = EventBus::new();
+        let mut received = events.subscribe();
+
+        let error = check_page("<title>403 Forbidden</title>", "https://a.com/p", "a.com", 60_000, &limiter, &events).unwrap_err();
+        assert_eq!(fetch_error::classify(&error), FetchErrorKind::BotWall);
+        assert!(limiter.cooldown_remaining("a.com").is_some());
+        assert!(matches!(received.try_recv(), Ok(EngineEvent::SiteBlocked { .. })));
+
+        let skipped = ensure_not_cooling_down("https://a.com/q", "a.com", &limiter).unwrap_err();
+        assert!(matches!(skipped.downcast_ref::<FetchError>(), Some(FetchError::CoolingDown { .. })));
+    }
+}
@@ -1,9 +1,34 @@
 // src/browser_service_manager.rs
 use std::net::TcpListener;
+use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use thirtyfour::common::capabilities::firefox::FirefoxPreferences;
 use thirtyfour::prelude::*;
 use flutter_rust_bridge::frb;
+use crate::api::driver_log::DriverLog;
+use crate::api::process_tree;
+use crate::api::proxy;
+use crate::api::resource_blocking::ResourceType;
+use crate::api::timeouts::FetchTimeouts;
+
+const REMOTE_RECONNECT_ATTEMPTS: u32 = 5;
+const REMOTE_RECONNECT_DELAY: Duration = Duration::from_secs(2);
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(20);
+const FIRST_POLL_DELAY: Duration = Duration::from_millis(100);
+const MAX_POLL_DELAY: Duration = Duration::from_secs(1);
+/// Ports tried before giving up when each one turns out to be taken.
+const MAX_PORT_ATTEMPTS: u32 = 5;
+/// What chromedriver, msedgedriver and geckodriver print when their port is taken.
+const PORT_IN_USE_MARKERS: &[&str] = &["address already in use", "address in use", "port not available", "bind() failed"];
+
+/// How an attempt to start the driver ended
+#[derive(Debug, PartialEq)]
+enum Startup {
+    Ready,
+    PortInUse,
+    Failed(String),
+}
 
 /// Result of a browser service check
 #[frb]
@@ -12,6 +37,100 @@ pub struct ServiceStatus {
     pub is_healthy: bool,
     pub port: u16,
     pub error_message: Option<String>,
+    /// How long starting the driver took until it answered, in milliseconds; 0 for plain checks.
+    pub startup_ms: u64,
+}
+
+/// The browser family to drive, which decides capabilities, driver arguments and health checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrowserKind {
+    Chrome,
+    Chromium,
+    Firefox,
+    Edge,
+}
+
+impl BrowserKind {
+    /// Guesses the browser from the driver executable name, defaulting to Chrome.
+    pub fn detect(driver_path: &str) -> Self {
+        let name = Path::new(driver_path)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_lowercase())
+            .unwrap_or_default();
+        if name.contains("geckodriver") {
+            BrowserKind::Firefox
+        } else if name.contains("msedgedriver") {
+            BrowserKind::Edge
+        } else {
+            BrowserKind::Chrome
+        }
+    }
+
+    /// Name of the matching WebDriver executable, for log and error messages.
+    pub(crate) fn driver_name(&self) -> &'static str {
+        match self {
+            BrowserKind::Chrome | BrowserKind::Chromium => "chromedriver",
+            BrowserKind::Firefox => "geckodriver",
+            BrowserKind::Edge => "msedgedriver",
+        }
+    }
+
+    /// Command-line arguments that make the driver listen on `port`.
+    fn driver_args(&self, port: u16) -> Vec<String> {
+        match self {
+            BrowserKind::Chrome | BrowserKind::Chromium | BrowserKind::Edge => {
+                vec![format!("--port={}", port), "--whitelisted-ips=".to_string()]
+            }
+            BrowserKind::Firefox => vec![
+                "--port".to_string(),
+                port.to_string(),
+                "--host".to_string(),
+                "127.0.0.1".to_string(),
+            ],
+        }
+    }
+
+    /// Whether `/status` reporting `ready: false` means the driver is unusable.
+    /// geckodriver only hosts one session and reports not-ready while it is in use.
+    fn ready_flag_is_reliable(&self) -> bool {
+        *self != BrowserKind::Firefox
+    }
+}
+
+/// How browser sessions are launched
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrowserOptions {
+    /// Profile directory kept between runs, preserving cookies, location and consent state.
+    /// Only one session can use a profile at a time, so this disables parallel sessions.
+    pub user_data_dir: Option<String>,
+    /// Extra command-line arguments passed to the browser as-is.
+    pub extra_args: Vec<String>,
+    pub window_width: u32,
+    pub window_height: u32,
+    /// Preferred page language, e.g. "en-IN".
+    pub language: Option<String>,
+    /// Replaces the browser's own user-agent string.
+    pub user_agent: Option<String>,
+    /// Run without a visible window; turn off to watch the browser while debugging.
+    pub headless: bool,
+    /// Resource types sessions skip downloading; sites can allow some back through
+    /// their `allowed_resources`.
+    pub blocked_resources: Vec<ResourceType>,
+}
+
+impl Default for BrowserOptions {
+    fn default() -> Self {
+        Self {
+            user_data_dir: None,
+            extra_args: Vec::new(),
+            window_width: 1366,
+            window_height: 768,
+            language: None,
+            user_agent: None,
+            headless: true,
+            blocked_resources: vec![ResourceType::Image, ResourceType::Font, ResourceType::Media],
+        }
+    }
 }
 
 /// A robust browser service manager with fallback mechanisms
@@ -23,11 +142,25 @@ pub struct BrowserServiceManager {
     max_port: u16,
     driver_path: String,
     browser_path: String,
+    browser_kind: BrowserKind,
+    // Proxy URL applied to sessions created from now on
+    proxy: Option<String>,
+    options: BrowserOptions,
+    // Page-load, script and implicit-wait limits of every session created
+    timeouts: FetchTimeouts,
+    // Client for the driver's /status endpoint
+    status_client: reqwest::Client,
     chrome_process: Option<Child>,
+    // Output of the driver process
+    driver_log: DriverLog,
+    // Base URL of an externally managed WebDriver server; no driver is spawned when set
+    remote_url: Option<String>,
+    // Records the driver and browser pids so a later run can clean up after a crash
+    pid_file: Option<PathBuf>,
 }
 
 impl BrowserServiceManager {
-    /// Creates a new service manager
+    /// Creates a new service manager; the browser kind is guessed from the driver name
     pub fn new(
         initial_port: u16,
         driver_path: &str,
@@ -39,7 +172,42 @@ impl BrowserServiceManager {
             max_port: initial_port + 100, // Allow 100 port range
             driver_path: driver_path.to_string(),
             browser_path: browser_path.to_string(),
+            browser_kind: BrowserKind::detect(driver_path),
+            proxy: None,
+            options: BrowserOptions::default(),
+            timeouts: FetchTimeouts::default(),
+            status_client: reqwest::Client::new(),
             chrome_process: None,
+            driver_log: DriverLog::default(),
+            remote_url: None,
+            pid_file: None,
+        }
+    }
+
+    /// Creates a manager for a WebDriver server that is already running elsewhere, such
+    /// as a Selenium Grid or a standalone Selenium container at `http://host:4444`.
+    /// No driver process is spawned or killed; restarts only reconnect.
+    pub fn new_remote(url: &str, browser_kind: BrowserKind) -> anyhow::Result<Self> {
+        let url = url.trim().trim_end_matches('/');
+        let parsed = reqwest::Url::parse(url).map_err(|e| anyhow::anyhow!("Invalid WebDriver URL '{}': {}", url, e))?;
+        let port = parsed.port_or_known_default().unwrap_or(0);
+        let mut manager = Self::new(port, browser_kind.driver_name(), "");
+        manager.max_port = port;
+        manager.browser_kind = browser_kind;
+        manager.remote_url = Some(url.to_string());
+        Ok(manager)
+    }
+
+    /// Whether the manager attaches to an external WebDriver server instead of running its own driver
+    pub fn is_remote(&self) -> bool {
+        self.remote_url.is_some()
+    }
+
+    // Where the driver is, for messages
+    fn location(&self) -> String {
+        match self.remote_url.as_deref() {
+            Some(url) => url.to_string(),
+            None => format!("port {}", self.current_port),
         }
     }
 
@@ -59,104 +227,260 @@ impl BrowserServiceManager {
         anyhow::bail!("No available ports found in range {}-{}", self.min_port, self.max_port)
     }
 
-    /// Checks if the browser service is responsive at the current port
+    /// Checks if the browser service is responsive at the current port. A driver that
+    /// does not answer within the status check timeout is unhealthy.
     pub async fn check_service_health(&self) -> ServiceStatus {
-        let url = format!("http://localhost:{}/status", self.current_port);
-        
-        match reqwest::get(&url).await {
+        let url = format!("{}/status", self.driver_url());
+        let request = self.status_client.get(&url).timeout(Duration::from_millis(self.timeouts.status_check_ms));
+
+        match request.send().await {
             Ok(response) => {
                 if response.status().is_success() {
+                    let ready = response
+                        .json::<serde_json::Value>()
+                        .await
+                        .ok()
+                        .and_then(|body| body["value"]["ready"].as_bool());
+                    // A Grid reports not-ready while all of its slots are busy, which is not a failure
+                    if ready == Some(false) && self.browser_kind.ready_flag_is_reliable() && !self.is_remote() {
+                        return ServiceStatus {
+                            is_healthy: false,
+                            port: self.current_port,
+                            error_message: Some(format!("{} on {} is not ready", self.browser_kind.driver_name(), self.location())),
+                            startup_ms: 0,
+                        };
+                    }
                     ServiceStatus {
                         is_healthy: true,
                         port: self.current_port,
                         error_message: None,
+                        startup_ms: 0,
                     }
                 } else {
                     ServiceStatus {
                         is_healthy: false,
                         port: self.current_port,
-                        error_message: Some(format!("Service unhealthy on port {}: HTTP {}", self.current_port, response.status())),
+                        error_message: Some(format!("Service unhealthy on {}: HTTP {}", self.location(), response.status())),
+                        startup_ms: 0,
                     }
                 }
             }
-            Err(e) => ServiceStatus {
-                is_healthy: false,
-                port: self.current_port,
-                error_message: Some(format!("Service not responding on port {}: {}", self.current_port, e)),
-            },
+            Err(e) => {
+                let message = if e.is_timeout() {
+                    format!("Service on {} did not answer within {} ms", self.location(), self.timeouts.status_check_ms)
+                } else {
+                    format!("Service not responding on {}: {}", self.location(), e)
+                };
+                ServiceStatus {
+                    is_healthy: false,
+                    port: self.current_port,
+                    error_message: Some(self.driver_log.annotate(message)),
+                    startup_ms: 0,
+                }
+            }
         }
     }
 
-    /// Starts the chromedriver service on the current port
+    /// Starts the driver service on the current port, moving on to the next free port
+    /// when the driver cannot bind it. Polls `/status` until the driver is ready or
+    /// the startup timeout passes.
     pub async fn start_service(&mut self) -> anyhow::Result<ServiceStatus> {
-        // First, check if port is available
+        let started = Instant::now();
+        if self.is_remote() {
+            println!("Connecting to WebDriver server at {}...", self.location());
+            let mut status = self.check_service_health().await;
+            status.startup_ms = started.elapsed().as_millis() as u64;
+            return Ok(status);
+        }
+
+        // Only a hint: another process can still take the port before the driver binds
+        // it, which shows up below as a driver that exits with a bind error
         if !Self::is_port_available(self.current_port) {
-            // Find available port
-            let new_port = self.find_available_port()?;
-            return Ok(ServiceStatus {
-                is_healthy: false,
-                port: new_port,
-                error_message: Some(format!("Port {} was busy, found available port {}", self.current_port, new_port)),
-            });
+            self.move_to_next_port()?;
         }
 
-        // Kill existing process if any
-        self.stop_service();
+        let mut outcome = Startup::PortInUse;
+        for _ in 0..MAX_PORT_ATTEMPTS {
+            // Kill existing process if any
+            self.stop_service();
+            self.spawn_driver()?;
+            outcome = self.wait_until_ready().await;
+            if outcome != Startup::PortInUse {
+                break;
+            }
+            println!("Port {} is already in use, trying another", self.current_port);
+            self.stop_service();
+            self.move_to_next_port()?;
+        }
+
+        let startup_ms = started.elapsed().as_millis() as u64;
+        let error_message = match outcome {
+            Startup::Ready => {
+                println!("{} ready on port {} after {} ms", self.browser_kind.driver_name(), self.current_port, startup_ms);
+                None
+            }
+            Startup::PortInUse => Some(format!("No free port found after {} attempts", MAX_PORT_ATTEMPTS)),
+            Startup::Failed(message) => Some(self.driver_log.annotate(message)),
+        };
+        Ok(ServiceStatus {
+            is_healthy: error_message.is_none(),
+            port: self.current_port,
+            error_message,
+            startup_ms,
+        })
+    }
 
-        println!("Starting chromedriver on port {}...", self.current_port);
+    fn spawn_driver(&mut self) -> anyhow::Result<()> {
+        let driver_name = self.browser_kind.driver_name();
+        println!("Starting {} on port {}...", driver_name, self.current_port);
 
         let mut cmd = Command::new(&self.driver_path);
-        cmd.arg(format!("--port={}", self.current_port));
-        cmd.arg("--whitelisted-ips=");
+        cmd.args(self.browser_kind.driver_args(self.current_port));
 
         #[cfg(windows)] {
             use std::os::windows::process::CommandExt;
             const CREATE_NO_WINDOW: u32 = 0x08000000;
             cmd.creation_flags(CREATE_NO_WINDOW);
         }
+        // Lead a new process group so the driver and its browser can be killed together
+        #[cfg(unix)] {
+            use std::os::unix::process::CommandExt;
+            cmd.process_group(0);
+        }
 
-        let process = cmd
-            .stdout(Stdio::null())
-            .stderr(Stdio::null())
+        let mut process = cmd
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
             .spawn()
             .map_err(|e| {
-                anyhow::anyhow!("Failed to start chromedriver. Is it in your PATH? Error: {}", e)
+                anyhow::anyhow!("Failed to start {}. Is it in your PATH? Error: {}", driver_name, e)
             })?;
+        self.driver_log.capture(&mut process);
 
         self.chrome_process = Some(process);
+        self.record_pids();
+        Ok(())
+    }
 
-        // Wait for service to start
-        tokio::time::sleep(Duration::from_secs(3)).await;
-
-        // Verify service is running
-        let status = self.check_service_health().await;
-        if status.is_healthy {
-            Ok(ServiceStatus {
-                is_healthy: true,
-                port: self.current_port,
-                error_message: None,
-            })
-        } else {
-            Ok(ServiceStatus {
-                is_healthy: false,
-                port: self.current_port,
-                error_message: Some(format!("Service started but not responding on port {}", self.current_port)),
-            })
+    // Polls /status with growing delays until the driver answers, exits or runs out of time.
+    // The driver is checked for having exited first: after losing the port it may be
+    // another process, even another driver, that answers on it.
+    async fn wait_until_ready(&mut self) -> Startup {
+        let deadline = Instant::now() + STARTUP_TIMEOUT;
+        let mut delay = FIRST_POLL_DELAY;
+        loop {
+            tokio::time::sleep(delay).await;
+            if let Some(exit) = self.driver_exit() {
+                return self.startup_after_exit(exit).await;
+            }
+            if self.check_service_health().await.is_healthy {
+                // The answer only counts if our driver did not exit while it came in
+                return match self.driver_exit() {
+                    Some(exit) => self.startup_after_exit(exit).await,
+                    None => Startup::Ready,
+                };
+            }
+            if Instant::now() >= deadline {
+                return Startup::Failed(format!(
+                    "Service started but not responding on port {} after {} s",
+                    self.current_port,
+                    STARTUP_TIMEOUT.as_secs()
+                ));
+            }
+            delay = (delay * 2).min(MAX_POLL_DELAY);
+        }
+    }
+
+    // How the driver exited, or `None` while it still runs
+    fn driver_exit(&mut self) -> Option<std::process::ExitStatus> {
+        self.chrome_process.as_mut().and_then(|process| process.try_wait().ok().flatten())
+    }
+
+    // Tells a driver that lost its port, to be retried on another one, from one that failed
+    async fn startup_after_exit(&self, exit: std::process::ExitStatus) -> Startup {
+        // Give the log readers a moment to take in the driver's last words
+        tokio::time::sleep(FIRST_POLL_DELAY).await;
+        let output = self.driver_log.recent_lines().join("\n").to_lowercase();
+        if PORT_IN_USE_MARKERS.iter().any(|marker| output.contains(marker)) || !Self::is_port_available(self.current_port) {
+            return Startup::PortInUse;
+        }
+        Startup::Failed(format!("{} exited right after starting ({})", self.browser_kind.driver_name(), exit))
+    }
+
+    // Switches to the next free port after the current one, wrapping around the range
+    fn move_to_next_port(&mut self) -> anyhow::Result<u16> {
+        let after = (self.current_port.saturating_add(1)..=self.max_port).chain(self.min_port..self.current_port);
+        for port in after {
+            if Self::is_port_available(port) {
+                self.current_port = port;
+                return Ok(port);
+            }
         }
+        anyhow::bail!("No available ports found in range {}-{}", self.min_port, self.max_port)
     }
 
-    /// Stops the chromedriver service
+    /// Stops the driver service
     pub fn stop_service(&mut self) {
         if let Some(mut process) = self.chrome_process.take() {
-            println!("Stopping chromedriver process...");
-            if let Err(e) = process.kill() {
-                eprintln!("Failed to kill chromedriver process: {}", e);
+            let driver_name = self.browser_kind.driver_name();
+            println!("Stopping {} and its browser processes...", driver_name);
+            process_tree::kill_tree(process.id());
+            let _ = process.kill();
+            // Reap the driver so it does not linger as a zombie, off the async runtime's
+            // threads when there is one
+            let mut reap = move || {
+                if let Err(e) = process.wait() {
+                    eprintln!("Failed to reap {} process: {}", driver_name, e);
+                }
+            };
+            match tokio::runtime::Handle::try_current() {
+                Ok(runtime) => drop(runtime.spawn_blocking(reap)),
+                Err(_) => reap(),
+            }
+            if let Some(path) = self.pid_file.as_deref() {
+                let _ = std::fs::remove_file(path);
+            }
+        }
+    }
+
+    /// Records the driver's processes in `path` from now on, first killing any driver
+    /// and browser processes a previous run left behind in it.
+    pub(crate) fn set_pid_file(&mut self, path: PathBuf) {
+        for recorded in process_tree::read_pid_file(&path) {
+            // A pid may have been reused by an unrelated process since the crash or a
+            // reboot, so it must still have the recorded start time and a browser's name
+            if !recorded.is_running() {
+                continue;
             }
+            match process_tree::process_name(recorded.pid) {
+                Some(name) if is_browser_process(&name) => {
+                    println!("Killing {} (pid {}) left over from a previous run", name, recorded.pid);
+                    process_tree::kill_tree(recorded.pid);
+                }
+                _ => {}
+            }
+        }
+        let _ = std::fs::remove_file(&path);
+        self.pid_file = Some(path);
+    }
+
+    // Writes the driver's pid and those of the browser processes it started
+    fn record_pids(&self) {
+        let (Some(path), Some(process)) = (self.pid_file.as_deref(), self.chrome_process.as_ref()) else {
+            return;
+        };
+        let mut pids = vec![process.id()];
+        pids.extend(process_tree::descendants(process.id()));
+        if let Err(e) = process_tree::write_pid_file(path, &pids) {
+            println!("Failed to write pid file {}: {}", path.display(), e);
         }
     }
 
     /// Restarts the service with fallback to new port if needed
     pub async fn restart_service(&mut self) -> anyhow::Result<ServiceStatus> {
+        if self.is_remote() {
+            return Ok(self.reconnect().await);
+        }
         self.stop_service();
         
         // Try current port first
@@ -170,6 +494,20 @@ impl BrowserServiceManager {
         self.start_service().await
     }
 
+    // A remote server cannot be restarted from here, so wait for it to come back instead
+    async fn reconnect(&self) -> ServiceStatus {
+        let mut status = self.check_service_health().await;
+        for attempt in 1..=REMOTE_RECONNECT_ATTEMPTS {
+            if status.is_healthy {
+                break;
+            }
+            println!("WebDriver server at {} unavailable (attempt {} of {}), retrying...", self.location(), attempt, REMOTE_RECONNECT_ATTEMPTS);
+            tokio::time::sleep(REMOTE_RECONNECT_DELAY).await;
+            status = self.check_service_health().await;
+        }
+        status
+    }
+
     /// Gets the current port
     pub fn get_current_port(&self) -> u16 {
         self.current_port
@@ -184,16 +522,193 @@ impl BrowserServiceManager {
         }
     }
 
+    /// Gets the browser kind being driven
+    pub fn get_browser_kind(&self) -> BrowserKind {
+        self.browser_kind
+    }
+
+    /// Overrides the browser kind guessed from the driver name
+    pub fn set_browser_kind(&mut self, browser_kind: BrowserKind) {
+        self.browser_kind = browser_kind;
+    }
+
+    /// Gets the proxy new browser sessions are routed through
+    pub fn get_proxy(&self) -> Option<String> {
+        self.proxy.clone()
+    }
+
+    /// Routes browser sessions created from now on through `proxy` (e.g. `http://host:3128`
+    /// or `socks5://host:1080`), or directly when `None`.
+    pub fn set_proxy(&mut self, proxy: Option<String>) -> anyhow::Result<()> {
+        if let Some(url) = proxy.as_deref() {
+            proxy::webdriver_proxy(url, self.browser_kind)?;
+        }
+        self.proxy = proxy;
+        Ok(())
+    }
+
+    /// Gets the options browser sessions are launched with
+    pub fn get_browser_options(&self) -> BrowserOptions {
+        self.options.clone()
+    }
+
+    /// Sets the options used for browser sessions created from now on
+    pub fn set_browser_options(&mut self, options: BrowserOptions) {
+        self.options = options;
+    }
+
+    /// Gets the timeouts browser sessions are created with
+    pub fn get_timeouts(&self) -> FetchTimeouts {
+        self.timeouts.clone()
+    }
+
+    /// Sets the timeouts used for browser sessions created from now on
+    pub fn set_timeouts(&mut self, timeouts: FetchTimeouts) {
+        self.timeouts = timeouts;
+    }
+
     /// Creates a WebDriver instance internally (not exposed to FFI)
     pub(crate) async fn create_webdriver_internal(&self) -> anyhow::Result<thirtyfour::WebDriver> {
-        let mut caps = DesiredCapabilities::chrome();
-        let _ = caps.set_headless();
-        caps.set_binary(&self.browser_path)?;
-
-        let driver_url = format!("http://localhost:{}", self.current_port);
-        let driver = thirtyfour::WebDriver::new(&driver_url, caps).await?;
+        let driver = thirtyfour::WebDriver::new(self.driver_url(), self.webdriver_capabilities()?).await?;
+        // The session started browser processes that a crash would otherwise leave running
+        self.record_pids();
         Ok(driver)
     }
+
+    /// Writes the driver's output to `path`, rotating it once it grows too large
+    pub(crate) fn set_driver_log_path(&mut self, path: PathBuf) {
+        self.driver_log = DriverLog::new(Some(path));
+    }
+
+    /// Returns the newest `lines` lines of driver output
+    pub(crate) fn driver_log_tail(&self, lines: usize) -> anyhow::Result<Vec<String>> {
+        self.driver_log.tail(lines)
+    }
+
+    /// Process id of the driver started by this manager, if it is running
+    pub(crate) fn driver_pid(&self) -> Option<u32> {
+        self.chrome_process.as_ref().map(Child::id)
+    }
+
+    /// URL of the running driver service
+    pub(crate) fn driver_url(&self) -> String {
+        match self.remote_url.as_deref() {
+            Some(url) => url.to_string(),
+            None => format!("http://localhost:{}", self.current_port),
+        }
+    }
+
+    /// How many sessions the driver can host at once (geckodriver allows only one,
+    /// and a persistent profile can only be opened by one browser)
+    pub(crate) fn max_sessions(&self) -> usize {
+        if self.options.user_data_dir.is_some() {
+            return 1;
+        }
+        match self.browser_kind {
+            BrowserKind::Firefox => 1,
+            _ => usize::MAX,
+        }
+    }
+
+    /// Capabilities for new sessions of the configured browser
+    pub(crate) fn webdriver_capabilities(&self) -> anyhow::Result<Capabilities> {
+        let mut caps: Capabilities = match self.browser_kind {
+            // chromedriver only accepts browserName "chrome", so Chromium reuses Chrome capabilities
+            BrowserKind::Chrome | BrowserKind::Chromium => {
+                self.chromium_like_caps(DesiredCapabilities::chrome())?
+            }
+            BrowserKind::Edge => self.chromium_like_caps(DesiredCapabilities::edge())?,
+            BrowserKind::Firefox => self.firefox_caps()?,
+        };
+        if let Some(url) = self.proxy.as_deref() {
+            caps.set_proxy(proxy::webdriver_proxy(url, self.browser_kind)?)?;
+        }
+        // A session without a page-load timeout can hang on a page forever
+        caps.insert("timeouts".to_string(), serde_json::to_value(self.timeouts.webdriver())?);
+        Ok(caps)
+    }
+
+    fn chromium_like_caps<C>(&self, mut caps: C) -> anyhow::Result<Capabilities>
+    where
+        C: ChromiumLikeCapabilities + Into<Capabilities>,
+    {
+        let options = &self.options;
+        if options.headless {
+            caps.set_headless()?;
+        }
+        if !self.browser_path.is_empty() {
+            caps.set_binary(&self.browser_path)?;
+        }
+        if let Some(dir) = options.user_data_dir.as_deref() {
+            caps.add_arg(&format!("--user-data-dir={}", dir))?;
+        }
+        caps.add_arg(&format!("--window-size={},{}", options.window_width, options.window_height))?;
+        if let Some(language) = options.language.as_deref() {
+            caps.add_arg(&format!("--lang={}", language))?;
+            caps.add_experimental_option("prefs", serde_json::json!({ "intl.accept_languages": language }))?;
+        }
+        if let Some(user_agent) = options.user_agent.as_deref() {
+            caps.add_arg(&format!("--user-agent={}", user_agent))?;
+        }
+        for arg in &options.extra_args {
+            caps.add_arg(arg)?;
+        }
+        Ok(caps.into())
+    }
+
+    fn firefox_caps(&self) -> anyhow::Result<Capabilities> {
+        let options = &self.options;
+        let mut caps = DesiredCapabilities::firefox();
+        if options.headless {
+            caps.set_headless()?;
+        }
+        if !self.browser_path.is_empty() {
+            caps.set_firefox_binary(&self.browser_path)?;
+        }
+        if let Some(dir) = options.user_data_dir.as_deref() {
+            caps.add_arg("-profile")?;
+            caps.add_arg(dir)?;
+        }
+        caps.add_arg(&format!("--width={}", options.window_width))?;
+        caps.add_arg(&format!("--height={}", options.window_height))?;
+        for arg in &options.extra_args {
+            caps.add_arg(arg)?;
+        }
+
+        let mut preferences = FirefoxPreferences::new();
+        if let Some(language) = options.language.as_deref() {
+            preferences.set("intl.accept_languages", language)?;
+        }
+        if let Some(user_agent) = options.user_agent.as_deref() {
+            preferences.set_user_agent(user_agent.to_string())?;
+        }
+        // Firefox has no per-navigation blocking, so these apply to every site
+        for blocked in &options.blocked_resources {
+            match blocked {
+                ResourceType::Image => preferences.set("permissions.default.image", 2)?,
+                ResourceType::Font => preferences.set("browser.display.use_document_fonts", 0)?,
+                ResourceType::Media => {
+                    // Blocking playback alone still lets players buffer, so preloading,
+                    // streaming (MediaSource) and directly opened media files are off too
+                    preferences.set("media.autoplay.default", 5)?;
+                    preferences.set("media.preload.default", 0)?;
+                    preferences.set("media.preload.auto", 0)?;
+                    preferences.set("media.mediasource.enabled", false)?;
+                    preferences.set("media.play-stand-alone", false)?;
+                }
+            }
+        }
+        caps.set_preferences(preferences)?;
+        Ok(caps.into())
+    }
+}
+
+// Whether a process name belongs to a WebDriver or a browser this crate can drive
+fn is_browser_process(name: &str) -> bool {
+    let name = name.to_lowercase();
+    ["chromedriver", "geckodriver", "msedgedriver", "chrome", "chromium", "msedge", "firefox"]
+        .iter()
+        .any(|known| name.contains(known))
 }
 
 impl Drop for BrowserServiceManager {
@@ -201,3 +716,24 @@ impl Drop for BrowserServiceManager {
         self.stop_service();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn driver_that_never_answers_is_unhealthy() {
+        // Connections are queued by the OS but never answered
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let mut manager = BrowserServiceManager::new_remote(&url, BrowserKind::Chrome).unwrap();
+        manager.set_timeouts(FetchTimeouts {
+            status_check_ms: 200,
+            ..FetchTimeouts::default()
+        });
+
+        let status = manager.check_service_health().await;
+        assert!(!status.is_healthy);
+        assert!(status.error_message.unwrap().contains("did not answer within 200 ms"));
+    }
+}
@@ -0,0 +1,414 @@
+// src/cookie_jar.rs
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use reqwest::Method;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::fs;
+use crate::api::database::data_dir;
+use crate::api::site_config;
+use std::path::{Path, PathBuf};
+use thirtyfour::common::command::FormatRequestData;
+use thirtyfour::{RequestData, SessionId};
+
+const KEY_FILE_NAME: &str = "cookie_jar.key";
+
+/// Cookies to restore into every new session, keyed by site domain
+pub(crate) type CookiesByDomain = HashMap<String, Vec<SessionCookie>>;
+
+/// File formats cookies can be imported from and exported to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CookieFormat {
+    /// The tab-separated `cookies.txt` format written by curl and browser extensions.
+    Netscape,
+    /// A JSON array of cookie objects, as exported by browser extensions.
+    Json,
+}
+
+/// One browser cookie, independent of where it came from
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionCookie {
+    pub name: String,
+    pub value: String,
+    #[serde(default)]
+    pub domain: String,
+    #[serde(default = "root_path")]
+    pub path: String,
+    #[serde(default)]
+    pub secure: bool,
+    #[serde(default)]
+    pub http_only: bool,
+    /// Unix time the cookie expires at; `None` for cookies that end with the session.
+    #[serde(default, alias = "expirationDate", alias = "expiry", deserialize_with = "unix_time")]
+    pub expires: Option<i64>,
+    #[serde(default)]
+    pub same_site: Option<String>,
+}
+
+// Whether `host` is `domain` or one of its subdomains
+fn is_within(host: &str, domain: &str) -> bool {
+    host == domain || host.ends_with(&format!(".{}", domain))
+}
+
+fn root_path() -> String {
+    "/".to_string()
+}
+
+// Extensions write expiry as fractional seconds
+fn unix_time<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<i64>, D::Error> {
+    Ok(Option::<f64>::deserialize(deserializer)?.map(|t| t as i64))
+}
+
+impl SessionCookie {
+    /// Whether the cookie belongs to the site at `domain`: set for `domain` or one of its
+    /// parent domains, and so sent to it, or set for one of its subdomains.
+    pub(crate) fn belongs_to(&self, domain: &str) -> bool {
+        let cookie_domain = self.domain.trim_start_matches('.').to_lowercase();
+        let domain = domain.trim_start_matches('.').to_lowercase();
+        // A parent must be a registrable name, never a bare suffix such as "in"
+        is_within(&cookie_domain, &domain) || (cookie_domain.contains('.') && is_within(&domain, &cookie_domain))
+    }
+
+    fn is_expired(&self, now: i64) -> bool {
+        self.expires.is_some_and(|expires| expires > 0 && expires <= now)
+    }
+
+    /// The cookie as a WebDriver cookie object. Built as JSON since `thirtyfour::Cookie`
+    /// has no `httpOnly` field and would drop the flag.
+    pub(crate) fn to_webdriver(&self) -> serde_json::Value {
+        let mut cookie = json!({
+            "name": self.name,
+            "value": self.value,
+            "path": self.path,
+            "secure": self.secure,
+            "httpOnly": self.http_only,
+        });
+        if !self.domain.is_empty() {
+            cookie["domain"] = json!(self.domain);
+        }
+        if let Some(expires) = self.expires {
+            cookie["expiry"] = json!(expires);
+        }
+        let same_site = match self.same_site.as_deref().map(str::to_lowercase).as_deref() {
+            Some("strict") => Some("Strict"),
+            Some("lax") => Some("Lax"),
+            Some("none") | Some("no_restriction") => Some("None"),
+            _ => None,
+        };
+        if let Some(same_site) = same_site {
+            cookie["sameSite"] = json!(same_site);
+        }
+        cookie
+    }
+
+    /// The cookie as a `Set-Cookie` header value, for the HTTP client's jar.
+    pub(crate) fn to_set_cookie(&self) -> String {
+        let mut header = format!("{}={}; Path={}", self.name, self.value, self.path);
+        if !self.domain.is_empty() {
+            header.push_str(&format!("; Domain={}", self.domain));
+        }
+        if self.secure {
+            header.push_str("; Secure");
+        }
+        if self.http_only {
+            header.push_str("; HttpOnly");
+        }
+        if let Some(expires) = self.expires.and_then(|t| chrono::DateTime::from_timestamp(t, 0)) {
+            header.push_str(&format!("; Expires={}", expires.format("%a, %d %b %Y %H:%M:%S GMT")));
+        }
+        header
+    }
+}
+
+/// Parses cookies in `format` and keeps the unexpired ones that belong to `domain`.
+pub(crate) fn parse(content: &str, format: CookieFormat, domain: &str) -> anyhow::Result<Vec<SessionCookie>> {
+    let cookies = match format {
+        CookieFormat::Json => serde_json::from_str::<Vec<SessionCookie>>(content)
+            .map_err(|e| anyhow::anyhow!("Invalid JSON cookie file: {}", e))?,
+        CookieFormat::Netscape => parse_netscape(content)?,
+    };
+    let now = chrono::Utc::now().timestamp();
+    Ok(cookies
+        .into_iter()
+        .filter(|c| c.belongs_to(domain) && !c.is_expired(now))
+        .collect())
+}
+
+fn parse_netscape(content: &str) -> anyhow::Result<Vec<SessionCookie>> {
+    let mut cookies = Vec::new();
+    for (number, line) in content.lines().enumerate() {
+        let (line, http_only) = match line.strip_prefix("#HttpOnly_") {
+            Some(rest) => (rest, true),
+            None => (line, false),
+        };
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split('\t').collect();
+        if fields.len() < 7 {
+            anyhow::bail!("Line {} of the cookie file has {} fields, expected 7", number + 1, fields.len());
+        }
+        let expires: i64 = fields[4].trim().parse().unwrap_or(0);
+        cookies.push(SessionCookie {
+            domain: fields[0].to_string(),
+            path: fields[2].to_string(),
+            secure: fields[3].eq_ignore_ascii_case("TRUE"),
+            expires: (expires > 0).then_some(expires),
+            name: fields[5].to_string(),
+            value: fields[6..].join("\t"),
+            http_only,
+            same_site: None,
+        });
+    }
+    Ok(cookies)
+}
+
+/// Writes cookies in `format`.
+pub(crate) fn format(cookies: &[SessionCookie], format: CookieFormat) -> anyhow::Result<String> {
+    match format {
+        CookieFormat::Json => Ok(serde_json::to_string_pretty(cookies)?),
+        CookieFormat::Netscape => {
+            let mut out = String::from("# Netscape HTTP Cookie File\n");
+            for cookie in cookies {
+                let prefix = if cookie.http_only { "#HttpOnly_" } else { "" };
+                let flag = if cookie.domain.starts_with('.') { "TRUE" } else { "FALSE" };
+                let secure = if cookie.secure { "TRUE" } else { "FALSE" };
+                out.push_str(&format!(
+                    "{}{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+                    prefix,
+                    cookie.domain,
+                    flag,
+                    cookie.path,
+                    secure,
+                    cookie.expires.unwrap_or(0),
+                    cookie.name,
+                    cookie.value
+                ));
+            }
+            Ok(out)
+        }
+    }
+}
+
+/// Loads `cookies` into a browser session, leaving it on the page it showed.
+/// Cookies the browser rejects are skipped.
+pub(crate) async fn apply_to_session(driver: &thirtyfour::WebDriver, domain: &str, cookies: &[SessionCookie]) -> anyhow::Result<()> {
+    if cookies.is_empty() {
+        return Ok(());
+    }
+    let navigated = visit_domain(driver, domain).await?;
+    for cookie in cookies {
+        if let Err(e) = driver.cmd(AddCookie(cookie.to_webdriver())).await {
+            println!("Browser rejected cookie {} for {}: {}", cookie.name, domain, e);
+        }
+    }
+    return_from_visit(driver, navigated).await;
+    Ok(())
+}
+
+/// Gets the session onto a page of `domain`, since WebDriver only reads and sets cookies
+/// for the page that is open. A session already there stays put; any other visits the
+/// site's robots.txt, the lightest page it is sure to serve. Returns whether it navigated.
+async fn visit_domain(driver: &thirtyfour::WebDriver, domain: &str) -> anyhow::Result<bool> {
+    if shows_domain(driver.current_url().await?.as_str(), domain) {
+        return Ok(false);
+    }
+    driver.goto(format!("https://{}/robots.txt", domain)).await?;
+    Ok(true)
+}
+
+// Takes the session back to the page it showed before `visit_domain` navigated away
+async fn return_from_visit(driver: &thirtyfour::WebDriver, navigated: bool) {
+    if navigated {
+        if let Err(e) = driver.back().await {
+            println!("Failed to return the browser to its previous page: {}", e);
+        }
+    }
+}
+
+// Whether the page at `url` can read and set the cookies of `domain`
+fn shows_domain(url: &str, domain: &str) -> bool {
+    site_config::host_of(url).is_some_and(|host| is_within(&host, &domain.to_lowercase()))
+}
+
+/// WebDriver's Add Cookie command with the cookie given as raw JSON
+#[derive(Debug)]
+struct AddCookie(serde_json::Value);
+
+impl FormatRequestData for AddCookie {
+    fn format_request(&self, session_id: &SessionId) -> RequestData {
+        RequestData::new(Method::POST, format!("session/{}/cookie", session_id)).add_body(json!({ "cookie": self.0 }))
+    }
+}
+
+/// Loads the cookies of every domain into a new browser session, logging failures.
+pub(crate) async fn apply_all_to_session(driver: &thirtyfour::WebDriver, cookies: &CookiesByDomain) {
+    for (domain, domain_cookies) in cookies {
+        if let Err(e) = apply_to_session(driver, domain, domain_cookies).await {
+            println!("Failed to restore cookies for {}: {}", domain, e);
+        }
+    }
+}
+
+/// Reads the cookies a browser session holds for `domain`, leaving it on the page it
+/// showed. The raw WebDriver response is decoded directly, since `thirtyfour::Cookie`
+/// drops the `httpOnly` flag.
+pub(crate) async fn read_from_session(driver: &thirtyfour::WebDriver, domain: &str) -> anyhow::Result<Vec<SessionCookie>> {
+    let navigated = visit_domain(driver, domain).await?;
+    let cookies = driver.cmd(thirtyfour::common::command::Command::GetAllCookies).await.and_then(|r| r.value());
+    return_from_visit(driver, navigated).await;
+    Ok(cookies?)
+}
+
+/// Encrypts cookie jars for storage in the database. The key is kept in a separate file
+/// next to the database, so a copied or backed-up database alone does not reveal sessions.
+pub(crate) struct CookieVault {
+    cipher: ChaCha20Poly1305,
+}
+
+impl CookieVault {
+    /// Opens the vault for the database at `db_path`, creating its key on first use.
+    pub(crate) fn open(db_path: &str) -> anyhow::Result<Self> {
+        let key_path = key_path(db_path);
+        let key = match fs::read(&key_path) {
+            Ok(bytes) if bytes.len() == 32 => Key::clone_from_slice(&bytes),
+            Ok(_) => anyhow::bail!("Cookie key file {} is corrupt", key_path.display()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let key = ChaCha20Poly1305::generate_key(&mut OsRng);
+                write_private(&key_path, key.as_slice())?;
+                key
+            }
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self {
+            cipher: ChaCha20Poly1305::new(&key),
+        })
+    }
+
+    /// Returns (nonce, ciphertext) for `cookies`.
+    pub(crate) fn seal(&self, cookies: &[SessionCookie]) -> anyhow::Result<(Vec<u8>, Vec<u8>)> {
+        let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let plaintext = serde_json::to_vec(cookies)?;
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, plaintext.as_slice())
+            .map_err(|_| anyhow::anyhow!("Failed to encrypt cookies"))?;
+        Ok((nonce.to_vec(), ciphertext))
+    }
+
+    pub(crate) fn open_sealed(&self, nonce: &[u8], ciphertext: &[u8]) -> anyhow::Result<Vec<SessionCookie>> {
+        if nonce.len() != 12 {
+            anyhow::bail!("Stored cookie nonce has the wrong length");
+        }
+        let plaintext = self
+            .cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow::anyhow!("Stored cookies could not be decrypted; was the key file replaced?"))?;
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+}
+
+fn key_path(db_path: &str) -> PathBuf {
+    data_dir(db_path).join(KEY_FILE_NAME)
+}
+
+fn write_private(path: &Path, bytes: &[u8]) -> anyhow::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    std::io::Write::write_all(&mut file, bytes)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn webdriver_cookies_keep_http_only_and_expiry() {
+        let response = serde_json::json!([
+            {
+                "name": "session-id",
+                "value": "abc",
+                "domain": ".amazon.in",
+                "path": "/",
+                "secure": true,
+                "httpOnly": true,
+                "expiry": 1893456000,
+                "sameSite": "Lax"
+            },
+            { "name": "theme", "value": "dark", "domain": "www.amazon.in" }
+        ]);
+        let cookies: Vec<SessionCookie> = serde_json::from_value(response).unwrap();
+
+        assert!(cookies[0].http_only);
+        assert!(cookies[0].secure);
+        assert_eq!(cookies[0].expires, Some(1893456000));
+        assert_eq!(cookies[0].same_site.as_deref(), Some("Lax"));
+        assert!(cookies[0].to_set_cookie().contains("; HttpOnly"));
+        assert!(!cookies[1].http_only);
+        assert_eq!(cookies[1].path, "/");
+    }
+
+    #[test]
+    fn cookies_sent_to_webdriver_keep_http_only_and_expiry() {
+        let cookie = SessionCookie {
+            name: "session-id".to_string(),
+            value: "abc".to_string(),
+            domain: ".amazon.in".to_string(),
+            path: "/".to_string(),
+            secure: true,
+            http_only: true,
+            expires: Some(1893456000),
+            same_site: Some("lax".to_string()),
+        };
+        let sent = cookie.to_webdriver();
+        assert_eq!(sent["httpOnly"], json!(true));
+        assert_eq!(sent["expiry"], json!(1893456000));
+        assert_eq!(sent["sameSite"], json!("Lax"));
+
+        // What the browser reports back decodes to the same cookie
+        let read: SessionCookie = serde_json::from_value(sent).unwrap();
+        assert_eq!(read, SessionCookie { same_site: Some("Lax".to_string()), ..cookie });
+
+        let request = AddCookie(json!({ "name": "a" })).format_request(&SessionId::from("s1"));
+        assert_eq!(request.uri.as_ref(), "session/s1/cookie");
+        assert_eq!(request.body, Some(json!({ "cookie": { "name": "a" } })));
+    }
+
+    #[test]
+    fn parse_keeps_cookies_of_the_domain_its_parents_and_subdomains() {
+        let content = r#"[
+            { "name": "parent", "value": "1", "domain": ".Amazon.in" },
+            { "name": "same", "value": "2", "domain": "www.amazon.in" },
+            { "name": "sub", "value": "3", "domain": "m.www.amazon.in" },
+            { "name": "sibling", "value": "4", "domain": "pay.amazon.in" },
+            { "name": "other", "value": "5", "domain": "flipkart.com" },
+            { "name": "suffix", "value": "6", "domain": ".in" }
+        ]"#;
+        let names: Vec<String> = parse(content, CookieFormat::Json, "WWW.amazon.in")
+            .unwrap()
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+        assert_eq!(names, ["parent", "same", "sub"]);
+    }
+
+    #[test]
+    fn sessions_on_the_domain_are_not_moved() {
+        assert!(shows_domain("https://www.amazon.in/dp/B0TESTTEST", "www.amazon.in"));
+        assert!(shows_domain("https://WWW.Amazon.in/", "amazon.in"));
+        assert!(!shows_domain("https://www.amazon.in/", "pay.amazon.in"));
+        assert!(!shows_domain("https://www.flipkart.com/", "amazon.in"));
+        assert!(!shows_domain("about:blank", "amazon.in"));
+        assert!(!shows_domain("data:,", "amazon.in"));
+    }
+}
@@ -1,10 +1,15 @@
 // src/database.rs
-use crate::api::models::{PriceEntry, ProductDetails, ProductRecord};
+use crate::api::fetcher::FetcherKind;
+use crate::api::migrations;
+use crate::api::models::{CachedImage, FetchPathStats, FetchTimingStats, PriceEntry, ProductDetails, ProductRecord};
+use crate::api::money::Money;
 use chrono::{DateTime, Utc};
 use rusqlite::{Connection, Result};
 use std::sync::Mutex;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::fs::File;
+use std::path::Path;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DatabaseBackup {
@@ -13,47 +18,38 @@ pub struct DatabaseBackup {
     pub version: String,
 }
 
+/// One domain's cookie jar as stored: encrypted, with the nonce needed to decrypt it
+#[derive(Debug)]
+pub struct SealedCookieJar {
+    pub domain: String,
+    pub nonce: Vec<u8>,
+    pub ciphertext: Vec<u8>,
+}
+
+/// The directory holding the database at `db_path`, where the app keeps its other
+/// files (image cache, logs, keys); the working directory for a bare file name.
+pub(crate) fn data_dir(db_path: &str) -> &Path {
+    Path::new(db_path)
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."))
+}
+
 pub struct Database {
     conn: Mutex<Connection>,
 }
 
 impl Database {
-    /// Opens a connection to the SQLite database and sets up the tables.
-    pub fn new(db_path: &str) -> Result<Self> {
-        let conn = Connection::open(db_path)?;
-        let db = Database {
+    /// Opens a connection to the SQLite database and migrates it to the current schema.
+    /// Fails with [`migrations::SchemaTooNew`] if a newer version of the app wrote it.
+    pub fn new(db_path: &str) -> anyhow::Result<Self> {
+        let mut conn = Connection::open(db_path)?;
+        migrations::run(&mut conn, db_path)?;
+        // SQLite leaves foreign keys unenforced unless every connection asks for them
+        conn.pragma_update(None, "foreign_keys", true)?;
+        Ok(Database {
             conn: Mutex::new(conn),
-        };
-        db.setup_database()?;
-        Ok(db)
-    }
-
-    /// Creates the `products` and `prices` tables if they don't exist.
-    fn setup_database(&self) -> Result<()> {
-        let conn = self.conn.lock().unwrap();
-        conn.execute_batch(
-            "BEGIN;
-            CREATE TABLE IF NOT EXISTS products (
-                id TEXT PRIMARY KEY,
-                site TEXT NOT NULL,
-                url TEXT NOT NULL,
-                title TEXT NOT NULL,
-                seller TEXT,
-                images TEXT,
-                features TEXT,
-                specifications TEXT
-            );
-            CREATE TABLE IF NOT EXISTS prices (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                product_id TEXT NOT NULL,
-                price INTEGER NOT NULL,
-                in_stock BOOLEAN NOT NULL,
-                timestamp TEXT NOT NULL,
-                FOREIGN KEY (product_id) REFERENCES products (id)
-            );
-            COMMIT;",
-        )?;
-        Ok(())
+        })
     }
 
     /// Inserts or updates a product's static details.
@@ -90,13 +86,14 @@ impl Database {
 
     pub fn insert_price_entry(&self, details: &ProductDetails) -> Result<()> {
         let conn = self.conn.lock().unwrap();
-        if let Some(price) = details.price {
+        if let Some(price) = &details.price {
             let now: DateTime<Utc> = Utc::now();
             conn.execute(
-                "INSERT INTO prices (product_id, price, in_stock, timestamp) VALUES (?1, ?2, ?3, ?4)",
+                "INSERT INTO prices (product_id, price_minor, currency, in_stock, timestamp) VALUES (?1, ?2, ?3, ?4, ?5)",
                 rusqlite::params![
                     details.id,
-                    price,
+                    price.minor_units,
+                    price.currency,
                     details.in_stock,
                     now.to_rfc3339(),
                 ],
@@ -105,6 +102,177 @@ impl Database {
         Ok(())
     }
 
+    /// Records a locally cached copy of one of a product's images.
+    pub fn upsert_cached_image(&self, product_id: &str, position: usize, image: &CachedImage) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO product_images (product_id, url, position, content_hash, local_path, thumbnail_path)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT(product_id, url) DO UPDATE SET
+                position=excluded.position,
+                content_hash=excluded.content_hash,
+                local_path=excluded.local_path,
+                thumbnail_path=excluded.thumbnail_path;",
+            rusqlite::params![
+                product_id,
+                image.url,
+                position as i64,
+                image.content_hash,
+                image.local_path,
+                image.thumbnail_path,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Forgets the cached images of `product_id` whose URL is not in `current_urls`, so
+    /// images the product no longer shows can be cleaned up. Returns how many were dropped.
+    pub fn remove_stale_cached_images(&self, product_id: &str, current_urls: &[String]) -> Result<usize> {
+        let conn = self.conn.lock().unwrap();
+        let current = serde_json::to_string(current_urls).unwrap_or_default();
+        conn.execute(
+            "DELETE FROM product_images WHERE product_id = ?1 AND url NOT IN (SELECT value FROM json_each(?2))",
+            rusqlite::params![product_id, current],
+        )
+    }
+
+    /// Looks up a cached image by its remote URL, regardless of which product owns it.
+    pub fn get_cached_image_by_url(&self, url: &str) -> Result<Option<CachedImage>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT url, content_hash, local_path, thumbnail_path FROM product_images WHERE url = ?1 LIMIT 1",
+        )?;
+        let mut rows = stmt.query([url])?;
+        match rows.next()? {
+            Some(row) => Ok(Some(Self::cached_image_from_row(row)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the content hashes of every image still referenced by a product.
+    pub fn get_referenced_image_hashes(&self) -> Result<HashSet<String>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT DISTINCT content_hash FROM product_images")?;
+        let hashes = stmt.query_map([], |row| row.get(0))?.collect::<Result<HashSet<String>>>()?;
+        Ok(hashes)
+    }
+
+    fn get_cached_images(conn: &Connection, product_id: &str) -> Result<Vec<CachedImage>> {
+        let mut stmt = conn.prepare(
+            "SELECT url, content_hash, local_path, thumbnail_path FROM product_images WHERE product_id = ?1 ORDER BY position ASC",
+        )?;
+        let images = stmt.query_map([product_id], Self::cached_image_from_row)?.collect::<Result<Vec<_>>>()?;
+        Ok(images)
+    }
+
+    fn cached_image_from_row(row: &rusqlite::Row) -> Result<CachedImage> {
+        Ok(CachedImage {
+            url: row.get(0)?,
+            content_hash: row.get(1)?,
+            local_path: row.get(2)?,
+            thumbnail_path: row.get(3)?,
+        })
+    }
+
+    /// Records which fetcher served a product check and whether it was a fallback.
+    pub fn record_fetch_check(&self, site: &str, url: &str, fetcher: FetcherKind, fell_back: bool) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO fetch_checks (site, url, fetcher, fell_back, timestamp) VALUES (?1, ?2, ?3, ?4, ?5)",
+            rusqlite::params![site, url, fetcher.as_str(), fell_back, Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    /// Aggregates recorded checks per site and fetcher.
+    pub fn get_fetch_path_stats(&self) -> Result<Vec<FetchPathStats>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT site, fetcher, COUNT(*), SUM(fell_back), MAX(timestamp)
+             FROM fetch_checks GROUP BY site, fetcher ORDER BY site, fetcher",
+        )?;
+        let stats = stmt
+            .query_map([], |row| {
+                Ok(FetchPathStats {
+                    site: row.get(0)?,
+                    fetcher: row.get(1)?,
+                    checks: row.get(2)?,
+                    fallbacks: row.get(3)?,
+                    last_used: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>>>()?;
+        Ok(stats)
+    }
+
+    /// Records how long a successful page fetch took and which resources were blocked during it.
+    pub fn record_fetch_timing(
+        &self,
+        site: &str,
+        url: &str,
+        fetcher: FetcherKind,
+        blocked_resources: &str,
+        duration_ms: u64,
+    ) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO fetch_timings (site, url, fetcher, blocked_resources, duration_ms, timestamp)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            rusqlite::params![site, url, fetcher.as_str(), blocked_resources, duration_ms as i64, Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    /// Aggregates recorded fetch timings per site, fetcher and set of blocked resources,
+    /// so runs with and without blocking can be compared.
+    pub fn get_fetch_timing_stats(&self) -> Result<Vec<FetchTimingStats>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT site, fetcher, blocked_resources, COUNT(*), CAST(AVG(duration_ms) AS INTEGER), MAX(duration_ms)
+             FROM fetch_timings GROUP BY site, fetcher, blocked_resources
+             ORDER BY site, fetcher, blocked_resources",
+        )?;
+        let stats = stmt
+            .query_map([], |row| {
+                Ok(FetchTimingStats {
+                    site: row.get(0)?,
+                    fetcher: row.get(1)?,
+                    blocked_resources: row.get(2)?,
+                    fetches: row.get(3)?,
+                    avg_duration_ms: row.get::<_, i64>(4)? as u64,
+                    max_duration_ms: row.get::<_, i64>(5)? as u64,
+                })
+            })?
+            .collect::<Result<Vec<_>>>()?;
+        Ok(stats)
+    }
+
+    /// Stores the encrypted cookie jar of one domain, replacing any earlier one.
+    pub fn save_session_cookies(&self, domain: &str, nonce: &[u8], ciphertext: &[u8]) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT OR REPLACE INTO session_cookies (domain, nonce, ciphertext, updated_at) VALUES (?1, ?2, ?3, ?4)",
+            rusqlite::params![domain, nonce, ciphertext, Utc::now().to_rfc3339()],
+        )?;
+        Ok(())
+    }
+
+    /// Returns every stored encrypted cookie jar.
+    pub fn get_all_session_cookies(&self) -> Result<Vec<SealedCookieJar>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare("SELECT domain, nonce, ciphertext FROM session_cookies ORDER BY domain")?;
+        let jars = stmt
+            .query_map([], |row| {
+                Ok(SealedCookieJar {
+                    domain: row.get(0)?,
+                    nonce: row.get(1)?,
+                    ciphertext: row.get(2)?,
+                })
+            })?
+            .collect::<Result<Vec<_>>>()?;
+        Ok(jars)
+    }
+
     pub fn get_all_products_with_history(&self) -> Result<Vec<ProductRecord>> {
         let conn = self.conn.lock().unwrap();
         let mut stmt_products = conn.prepare("SELECT id, site, url, title, seller, images, features, specifications FROM products")?;
@@ -122,18 +290,21 @@ impl Database {
             let specifications: String = specs_json;
 
             let mut stmt_prices =
-                conn.prepare("SELECT price, in_stock, timestamp FROM prices WHERE product_id = ?1 ORDER BY timestamp ASC")?;
+                conn.prepare("SELECT price_minor, currency, in_stock, timestamp FROM prices WHERE product_id = ?1 ORDER BY timestamp ASC")?;
             let mut rows_prices = stmt_prices.query([&product_id])?;
             let mut price_history = Vec::new();
 
             while let Some(price_row) = rows_prices.next()? {
+                let currency: String = price_row.get(1)?;
                 price_history.push(PriceEntry {
-                    price: price_row.get(0)?,
-                    in_stock: price_row.get(1)?,
-                    timestamp: price_row.get(2)?,
+                    price: Money::new(price_row.get(0)?, &currency),
+                    in_stock: price_row.get(2)?,
+                    timestamp: price_row.get(3)?,
                 });
             }
 
+            let cached_images = Self::get_cached_images(&conn, &product_id)?;
+
             products.push(ProductRecord {
                 id: product_id,
                 site: row.get(1)?,
@@ -144,6 +315,7 @@ impl Database {
                 features,
                 specifications,
                 price_history,
+                cached_images,
             });
         }
 
@@ -167,18 +339,21 @@ impl Database {
             let specifications: String = specs_json;
 
             let mut stmt_prices =
-                conn.prepare("SELECT price, in_stock, timestamp FROM prices WHERE product_id = ?1 ORDER BY timestamp ASC")?;
+                conn.prepare("SELECT price_minor, currency, in_stock, timestamp FROM prices WHERE product_id = ?1 ORDER BY timestamp ASC")?;
             let mut rows_prices = stmt_prices.query([product_id])?;
             let mut price_history = Vec::new();
 
             while let Some(price_row) = rows_prices.next()? {
+                let currency: String = price_row.get(1)?;
                 price_history.push(PriceEntry {
-                    price: price_row.get(0)?,
-                    in_stock: price_row.get(1)?,
-                    timestamp: price_row.get(2)?,
+                    price: Money::new(price_row.get(0)?, &currency),
+                    in_stock: price_row.get(2)?,
+                    timestamp: price_row.get(3)?,
                 });
             }
 
+            let cached_images = Self::get_cached_images(&conn, product_id)?;
+
             Ok(Some(ProductRecord {
                 id: row.get(0)?,
                 site: row.get(1)?,
@@ -189,6 +364,7 @@ impl Database {
                 features,
                 specifications,
                 price_history,
+                cached_images,
             }))
         } else {
             Ok(None)
@@ -209,11 +385,7 @@ impl Database {
             return Ok(());
         }
         
-        // Delete from prices table first (due to foreign key constraint)
-        let prices_deleted = conn.execute("DELETE FROM prices WHERE product_id = ?1", [product_id])?;
-        println!("Deleted {} price entries for product ID: {}", prices_deleted, product_id);
-        
-        // Delete from products table
+        // Price history and cached image entries are deleted with it by ON DELETE CASCADE
         let products_deleted = conn.execute("DELETE FROM products WHERE id = ?1", [product_id])?;
         println!("Deleted {} product entries for product ID: {}", products_deleted, product_id);
         
@@ -235,7 +407,7 @@ impl Database {
         let backup = DatabaseBackup {
             products,
             backup_timestamp: Utc::now().to_rfc3339(),
-            version: "1.0.0".to_string(),
+            version: "2.0.0".to_string(),
         };
         
         let json_data = serde_json::to_string_pretty(&backup)
@@ -271,7 +443,7 @@ impl Database {
         
         // If replace_existing is true, clear existing data
         if replace_existing {
-            conn.execute("DELETE FROM prices", [])?;
+            // Cascades to their price history and cached image entries
             conn.execute("DELETE FROM products", [])?;
             println!("Cleared existing data");
         }
@@ -281,9 +453,18 @@ impl Database {
         
         let product_count = backup.products.len();
         for product in backup.products {
-            // Insert product
+            // Insert product. OR REPLACE would delete the existing row and cascade to its history
             tx.execute(
-                "INSERT OR REPLACE INTO products (id, site, url, title, seller, images, features, specifications) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                "INSERT INTO products (id, site, url, title, seller, images, features, specifications)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
+                 ON CONFLICT(id) DO UPDATE SET
+                    site=excluded.site,
+                    url=excluded.url,
+                    title=excluded.title,
+                    seller=excluded.seller,
+                    images=excluded.images,
+                    features=excluded.features,
+                    specifications=excluded.specifications",
                 (
                     &product.id,
                     &product.site,
@@ -299,10 +480,11 @@ impl Database {
             // Insert price history
             for price_entry in product.price_history {
                 tx.execute(
-                    "INSERT OR REPLACE INTO prices (product_id, price, in_stock, timestamp) VALUES (?1, ?2, ?3, ?4)",
+                    "INSERT OR REPLACE INTO prices (product_id, price_minor, currency, in_stock, timestamp) VALUES (?1, ?2, ?3, ?4, ?5)",
                     (
                         &product.id,
-                        price_entry.price,
+                        price_entry.price.minor_units,
+                        &price_entry.price.currency,
                         price_entry.in_stock,
                         &price_entry.timestamp,
                     ),
@@ -314,4 +496,50 @@ impl Database {
         println!("Backup restored successfully. Imported {} products", product_count);
         Ok(())
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn product(id: &str) -> ProductDetails {
+        ProductDetails {
+            id: id.to_string(),
+            site: "amazon".to_string(),
+            url: format!("https://www.amazon.in/dp/{}", id),
+            title: "Test product".to_string(),
+            price: None,
+            rating: String::new(),
+            features: Vec::new(),
+            specifications: serde_json::Value::Null,
+            in_stock: false,
+            seller: None,
+            images: Vec::new(),
+        }
+    }
+
+    fn image(url: &str) -> CachedImage {
+        CachedImage {
+            url: url.to_string(),
+            content_hash: format!("hash-{}", url),
+            local_path: format!("/cache/{}.jpg", url),
+            thumbnail_path: format!("/cache/{}.thumb.jpg", url),
+        }
+    }
+
+    #[test]
+    fn remove_stale_cached_images_keeps_current_urls_and_other_products() {
+        let db = Database::new(":memory:").unwrap();
+        db.upsert_product(&product("a")).unwrap();
+        db.upsert_product(&product("b")).unwrap();
+        db.upsert_cached_image("a", 0, &image("one")).unwrap();
+        db.upsert_cached_image("a", 1, &image("two")).unwrap();
+        db.upsert_cached_image("b", 0, &image("three")).unwrap();
+
+        let removed = db.remove_stale_cached_images("a", &["two".to_string()]).unwrap();
+
+        assert_eq!(removed, 1);
+        assert!(db.get_cached_image_by_url("one").unwrap().is_none());
+        assert!(db.get_cached_image_by_url("two").unwrap().is_some());
+        assert!(db.get_cached_image_by_url("three").unwrap().is_some());
+    }
+}
@@ -0,0 +1,186 @@
+// src/diagnostics.rs
+use serde::{Deserialize, Serialize};
+use std::fs;
+use crate::api::database::data_dir;
+use std::path::PathBuf;
+
+const DIAGNOSTICS_DIR_NAME: &str = "diagnostics";
+const METADATA_FILE: &str = "capture.json";
+const HTML_FILE: &str = "page.html";
+const SCREENSHOT_FILE: &str = "screenshot.png";
+const DEFAULT_MAX_CAPTURES: usize = 100;
+
+/// What the engine saw when a fetch failed or returned a suspicious page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiagnosticCapture {
+    /// Folder name of the capture; sorts by creation time.
+    pub id: String,
+    pub url: String,
+    pub fetcher: String,
+    pub reason: String,
+    pub timestamp: String,
+    pub html_path: Option<String>,
+    pub screenshot_path: Option<String>,
+}
+
+/// The saved files of one capture
+#[derive(Debug, Clone)]
+pub struct DiagnosticCaptureContent {
+    pub capture: DiagnosticCapture,
+    pub html: Option<String>,
+    pub screenshot_png: Option<Vec<u8>>,
+}
+
+/// Keeps page captures in `diagnostics/<id>/`, next to the database file, deleting
+/// the oldest once more than `max_captures` exist.
+pub struct DiagnosticsStore {
+    root: PathBuf,
+    max_captures: usize,
+}
+
+impl DiagnosticsStore {
+    /// Creates a store rooted in the directory containing `db_path`.
+    pub(crate) fn new(db_path: &str) -> anyhow::Result<Self> {
+        let root = data_dir(db_path).join(DIAGNOSTICS_DIR_NAME);
+        fs::create_dir_all(&root)?;
+
+        Ok(Self {
+            root,
+            max_captures: DEFAULT_MAX_CAPTURES,
+        })
+    }
+
+    /// Sets how many captures are kept and deletes any beyond that.
+    pub(crate) fn set_max_captures(&mut self, max_captures: u32) -> anyhow::Result<()> {
+        self.max_captures = max_captures as usize;
+        self.prune()
+    }
+
+    /// Saves whatever was captured of a failed page. Returns `None` when there is nothing to save.
+    pub(crate) fn save(
+        &self,
+        url: &str,
+        fetcher: &str,
+        reason: &str,
+        html: Option<&str>,
+        screenshot_png: Option<&[u8]>,
+    ) -> anyhow::Result<Option<DiagnosticCapture>> {
+        if html.is_none() && screenshot_png.is_none() {
+            return Ok(None);
+        }
+
+        let now = chrono::Utc::now();
+        let id = format!("{}-{:04x}", now.format("%Y%m%dT%H%M%S%3f"), rand::random::<u16>());
+        let dir = self.root.join(&id);
+        fs::create_dir_all(&dir)?;
+
+        let html_path = match html {
+            Some(html) => {
+                let path = dir.join(HTML_FILE);
+                fs::write(&path, html)?;
+                Some(path.to_string_lossy().into_owned())
+            }
+            None => None,
+        };
+        let screenshot_path = match screenshot_png {
+            Some(png) => {
+                let path = dir.join(SCREENSHOT_FILE);
+                fs::write(&path, png)?;
+                Some(path.to_string_lossy().into_owned())
+            }
+            None => None,
+        };
+
+        let capture = DiagnosticCapture {
+            id,
+            url: url.to_string(),
+            fetcher: fetcher.to_string(),
+            reason: reason.to_string(),
+            timestamp: now.to_rfc3339(),
+            html_path,
+            screenshot_path,
+        };
+        fs::write(dir.join(METADATA_FILE), serde_json::to_vec_pretty(&capture)?)?;
+        self.prune()?;
+        Ok(Some(capture))
+    }
+
+    /// Returns the newest captures first, limited to `url` when given.
+    pub(crate) fn list(&self, url: Option<&str>, limit: usize) -> anyhow::Result<Vec<DiagnosticCapture>> {
+        let mut captures = Vec::new();
+        for id in self.capture_ids()?.into_iter().rev() {
+            if captures.len() >= limit {
+                break;
+            }
+            match self.read_metadata(&id) {
+                Ok(capture) if url.is_none_or(|u| capture.url == u) => captures.push(capture),
+                Ok(_) => {}
+                Err(e) => println!("Skipping unreadable diagnostic capture {}: {}", id, e),
+            }
+        }
+        Ok(captures)
+    }
+
+    /// Loads the files of the capture with the given id.
+    pub(crate) fn open(&self, id: &str) -> anyhow::Result<DiagnosticCaptureContent> {
+        // Ids are folder names; refuse anything that could point outside the store
+        if id.is_empty() || id.contains(['/', '\\']) || id.contains("..") {
+            anyhow::bail!("Invalid capture id '{}'", id);
+        }
+        let capture = self.read_metadata(id)?;
+        let dir = self.root.join(id);
+        Ok(DiagnosticCaptureContent {
+            html: fs::read_to_string(dir.join(HTML_FILE)).ok(),
+            screenshot_png: fs::read(dir.join(SCREENSHOT_FILE)).ok(),
+            capture,
+        })
+    }
+
+    fn read_metadata(&self, id: &str) -> anyhow::Result<DiagnosticCapture> {
+        let bytes = fs::read(self.root.join(id).join(METADATA_FILE))?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+
+    // Capture folder names, oldest first
+    fn capture_ids(&self) -> anyhow::Result<Vec<String>> {
+        let mut ids: Vec<String> = fs::read_dir(&self.root)?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .collect();
+        ids.sort();
+        Ok(ids)
+    }
+
+    fn prune(&self) -> anyhow::Result<()> {
+        let ids = self.capture_ids()?;
+        let excess = ids.len().saturating_sub(self.max_captures);
+        for id in &ids[..excess] {
+            fs::remove_dir_all(self.root.join(id))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_captures_of_a_url_or_of_every_page() {
+        let dir = std::env::temp_dir().join(format!("palert-diagnostics-test-{}", std::process::id()));
+        let store = DiagnosticsStore::new(dir.join("palert.sqlite").to_str().unwrap()).unwrap();
+        store.save("https://a.com/new", "http", "timed out", Some("<html></html>"), None).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        store.save("https://a.com/other", "browser", "blocked", None, Some(b"png")).unwrap();
+
+        let for_url = store.list(Some("https://a.com/new"), 10).unwrap();
+        let all = store.list(None, 10).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(for_url.len(), 1);
+        assert_eq!(for_url[0].reason, "timed out");
+        let urls: Vec<&str> = all.iter().map(|c| c.url.as_str()).collect();
+        assert_eq!(urls, ["https://a.com/other", "https://a.com/new"]);
+    }
+}
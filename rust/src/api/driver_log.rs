@@ -0,0 +1,201 @@
+// src/driver_log.rs
+use flutter_rust_bridge::frb;
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Write};
+use crate::api::database::data_dir;
+use std::path::{Path, PathBuf};
+use std::process::Child;
+use std::sync::{Arc, Mutex};
+
+const LOG_DIR_NAME: &str = "logs";
+const LOG_FILE_NAME: &str = "driver.log";
+const MAX_LOG_BYTES: u64 = 1024 * 1024;
+/// Rotated files kept besides the active one (`driver.log.1`, `driver.log.2`, ...).
+const ROTATED_FILES: usize = 3;
+/// Lines of the running driver's output kept in memory for error messages.
+const RECENT_LINES: usize = 20;
+
+#[frb(ignore)]
+#[derive(Debug, Default)]
+struct LogState {
+    file: Option<File>,
+    size: u64,
+    // Output of the current driver process only
+    recent: VecDeque<String>,
+    // Counts the driver processes captured so far, so readers of an old process
+    // stop adding to `recent` once a new one starts
+    generation: u64,
+}
+
+/// Collects a driver's stdout and stderr into a size-bounded set of rotating log files
+/// and keeps its last lines at hand for error messages. Without a path, only the
+/// in-memory lines are kept.
+#[frb(ignore)]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct DriverLog {
+    path: Option<PathBuf>,
+    state: Arc<Mutex<LogState>>,
+}
+
+/// Where the driver log of the database at `db_path` is written: `logs/driver.log` next to it.
+pub(crate) fn path_for(db_path: &str) -> PathBuf {
+    data_dir(db_path).join(LOG_DIR_NAME).join(LOG_FILE_NAME)
+}
+
+impl DriverLog {
+    pub(crate) fn new(path: Option<PathBuf>) -> Self {
+        Self {
+            path,
+            state: Arc::default(),
+        }
+    }
+
+    /// Starts copying the output of a freshly spawned driver into the log.
+    /// The process must have been spawned with piped stdout and stderr.
+    pub(crate) fn capture(&self, process: &mut Child) {
+        let generation = self.next_generation();
+        if let Some(stdout) = process.stdout.take() {
+            self.spawn_reader(stdout, "stdout", generation);
+        }
+        if let Some(stderr) = process.stderr.take() {
+            self.spawn_reader(stderr, "stderr", generation);
+        }
+    }
+
+    // Forgets the previous driver's recent lines and returns the new driver's generation
+    fn next_generation(&self) -> u64 {
+        let mut state = self.state.lock().unwrap();
+        state.recent.clear();
+        state.generation += 1;
+        state.generation
+    }
+
+    // The reader thread ends when the driver exits and closes the pipe
+    fn spawn_reader(&self, stream: impl Read + Send + 'static, name: &'static str, generation: u64) {
+        let log = self.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stream).lines() {
+                match line {
+                    Ok(line) => log.append(generation, &format!("{} [{}] {}", chrono::Utc::now().to_rfc3339(), name, line)),
+                    Err(_) => break,
+                }
+            }
+        });
+    }
+
+    // Every line goes to the file, but only the current driver's lines are kept in memory
+    fn append(&self, generation: u64, line: &str) {
+        let mut state = self.state.lock().unwrap();
+        if state.generation == generation {
+            if state.recent.len() == RECENT_LINES {
+                state.recent.pop_front();
+            }
+            state.recent.push_back(line.to_string());
+        }
+
+        if let Some(path) = self.path.as_deref() {
+            if let Err(e) = write_line(path, &mut state, line) {
+                eprintln!("Failed to write driver log {}: {}", path.display(), e);
+            }
+        }
+    }
+
+    /// The last lines the running driver printed, oldest first.
+    pub(crate) fn recent_lines(&self) -> Vec<String> {
+        self.state.lock().unwrap().recent.iter().cloned().collect()
+    }
+
+    /// Appends the driver's last lines to `message`, so startup and health errors
+    /// show why the driver failed.
+    pub(crate) fn annotate(&self, message: String) -> String {
+        let recent = self.recent_lines();
+        if recent.is_empty() {
+            return message;
+        }
+        format!("{}\nLast driver output:\n{}", message, recent.join("\n"))
+    }
+
+    /// Returns up to `lines` of the newest logged lines, oldest first, reading into
+    /// the most recent rotated file when the active one is shorter.
+    pub(crate) fn tail(&self, lines: usize) -> anyhow::Result<Vec<String>> {
+        let Some(path) = self.path.as_deref() else {
+            let recent = self.recent_lines();
+            return Ok(recent[recent.len().saturating_sub(lines)..].to_vec());
+        };
+        let mut tail: Vec<String> = Vec::new();
+        for file in [path.to_path_buf(), rotated_path(path, 1)] {
+            let content = match fs::read_to_string(&file) {
+                Ok(content) => content,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            let mut older: Vec<String> = content.lines().map(str::to_string).collect();
+            older.append(&mut tail);
+            tail = older;
+            if tail.len() >= lines {
+                break;
+            }
+        }
+        Ok(tail[tail.len().saturating_sub(lines)..].to_vec())
+    }
+}
+
+fn write_line(path: &Path, state: &mut LogState, line: &str) -> std::io::Result<()> {
+    if state.file.is_none() {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        state.size = file.metadata()?.len();
+        state.file = Some(file);
+    }
+    if state.size >= MAX_LOG_BYTES {
+        state.file = None;
+        rotate(path)?;
+        state.file = Some(OpenOptions::new().create(true).append(true).open(path)?);
+        state.size = 0;
+    }
+    if let Some(file) = state.file.as_mut() {
+        writeln!(file, "{}", line)?;
+        state.size += line.len() as u64 + 1;
+    }
+    Ok(())
+}
+
+// driver.log becomes driver.log.1, driver.log.1 becomes driver.log.2, and the oldest is dropped
+fn rotate(path: &Path) -> std::io::Result<()> {
+    let oldest = rotated_path(path, ROTATED_FILES);
+    if oldest.exists() {
+        fs::remove_file(&oldest)?;
+    }
+    for index in (1..ROTATED_FILES).rev() {
+        let from = rotated_path(path, index);
+        if from.exists() {
+            fs::rename(&from, rotated_path(path, index + 1))?;
+        }
+    }
+    fs::rename(path, rotated_path(path, 1))
+}
+
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", index));
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_of_a_replaced_driver_is_not_kept_as_recent() {
+        let log = DriverLog::new(None);
+        let old = log.next_generation();
+        log.append(old, "old driver starting");
+        let current = log.next_generation();
+        log.append(old, "old driver exiting");
+        log.append(current, "new driver starting");
+        assert_eq!(log.recent_lines(), ["new driver starting"]);
+    }
+}
@@ -0,0 +1,56 @@
+// src/events.rs
+use tokio::sync::broadcast;
+
+const EVENT_BUFFER: usize = 64;
+
+/// Something the engine noticed that the UI may want to show as it happens
+#[derive(Debug, Clone)]
+pub enum EngineEvent {
+    /// A site served a captcha or access-denied page and its fetches are paused.
+    SiteBlocked {
+        domain: String,
+        url: String,
+        reason: String,
+        cooldown_ms: u64,
+        timestamp: String,
+    },
+    /// The background watchdog saw the browser service go down or come back.
+    ServiceStatusChanged {
+        is_healthy: bool,
+        port: u16,
+        message: String,
+        timestamp: String,
+    },
+    /// The watchdog used up its restart budget and leaves the browser service down.
+    ServiceRestartsExhausted {
+        restarts: u32,
+        window_ms: u64,
+        timestamp: String,
+    },
+}
+
+/// Fan-out channel for [`EngineEvent`]s. Events sent while nobody listens are dropped.
+#[derive(Debug, Clone)]
+pub(crate) struct EventBus {
+    sender: broadcast::Sender<EngineEvent>,
+}
+
+impl EventBus {
+    pub(crate) fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_BUFFER);
+        Self { sender }
+    }
+
+    pub(crate) fn emit(&self, event: EngineEvent) {
+        let _ = self.sender.send(event);
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<EngineEvent> {
+        self.sender.subscribe()
+    }
+}
+
+/// Current time in the format used for event timestamps.
+pub(crate) fn now_timestamp() -> String {
+    chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string()
+}
@@ -0,0 +1,184 @@
+// src/fetch_error.rs
+use std::fmt;
+use thirtyfour::error::{WebDriverError, WebDriverErrorInner};
+
+/// Failures of a page fetch that callers may want to tell apart from generic errors.
+/// Returned wrapped in `anyhow::Error`; use `downcast_ref::<FetchError>()` to inspect.
+#[derive(Debug, Clone)]
+pub enum FetchError {
+    /// The page loaded but its readiness condition never became true.
+    PageNotReady {
+        url: String,
+        condition: String,
+        timeout_ms: u64,
+    },
+    /// Loading the page, a script in it, or the whole fetch exceeded its configured timeout.
+    TimedOut {
+        url: String,
+        operation: String,
+        timeout_ms: u64,
+    },
+    /// There is no working WebDriver session to load the page with.
+    DriverUnavailable { reason: String },
+    /// The server answered with a non-success HTTP status.
+    HttpStatus { url: String, status: u16 },
+    /// The page was fetched but could not be parsed into a product.
+    ParseFailure { url: String, reason: String },
+    /// The site served a captcha or access-denied page; its fetches are paused for `cooldown_ms`.
+    Blocked {
+        url: String,
+        domain: String,
+        reason: String,
+        cooldown_ms: u64,
+    },
+    /// The request was not sent because the site is still paused after an earlier bot wall.
+    CoolingDown {
+        url: String,
+        domain: String,
+        remaining_ms: u64,
+    },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::PageNotReady { url, condition, timeout_ms } => write!(
+                f,
+                "Page {} was not ready after {} ms (waiting for {})",
+                url, timeout_ms, condition
+            ),
+            FetchError::TimedOut { url, operation, timeout_ms } => {
+                write!(f, "{} of {} timed out after {} ms", operation, url, timeout_ms)
+            }
+            FetchError::DriverUnavailable { reason } => write!(f, "Browser session unavailable: {}", reason),
+            FetchError::HttpStatus { url, status } => write!(f, "HTTP {} while fetching {}", status, url),
+            FetchError::ParseFailure { url, reason } => write!(f, "Failed to parse {}: {}", url, reason),
+            FetchError::Blocked { url, domain, reason, cooldown_ms } => write!(
+                f,
+                "Blocked by {} while fetching {} ({}); retry in {} s",
+                domain, url, reason, cooldown_ms / 1000
+            ),
+            FetchError::CoolingDown { url, domain, remaining_ms } => write!(
+                f,
+                "Skipped {}: {} is paused for another {} s after blocking us",
+                url, domain, remaining_ms / 1000
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Broad categories of fetch failures, used to decide how to recover
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchErrorKind {
+    /// The driver process or session is gone; only a restart helps.
+    DriverDead,
+    /// Navigation or page readiness took too long.
+    NavigationTimeout,
+    /// The site answered with a 5xx status.
+    HttpServerError,
+    /// The site answered with a 4xx status.
+    HttpClientError,
+    /// The site served a captcha or access-denied page instead of the product.
+    BotWall,
+    /// The page arrived but did not contain a usable product.
+    ParseFailure,
+    /// Anything else, such as a transient network error.
+    Other,
+}
+
+impl FetchErrorKind {
+    /// Whether trying the same request again may succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            FetchErrorKind::DriverDead
+                | FetchErrorKind::NavigationTimeout
+                | FetchErrorKind::HttpServerError
+                | FetchErrorKind::Other
+        )
+    }
+}
+
+impl fmt::Display for FetchErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            FetchErrorKind::DriverDead => "driver dead",
+            FetchErrorKind::NavigationTimeout => "navigation timeout",
+            FetchErrorKind::HttpServerError => "HTTP server error",
+            FetchErrorKind::HttpClientError => "HTTP client error",
+            FetchErrorKind::BotWall => "bot wall",
+            FetchErrorKind::ParseFailure => "parse failure",
+            FetchErrorKind::Other => "error",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Works out what kind of failure an error from any fetch path represents.
+pub(crate) fn classify(error: &anyhow::Error) -> FetchErrorKind {
+    if let Some(fetch_error) = error.downcast_ref::<FetchError>() {
+        return match fetch_error {
+            FetchError::PageNotReady { .. } | FetchError::TimedOut { .. } => FetchErrorKind::NavigationTimeout,
+            FetchError::DriverUnavailable { .. } => FetchErrorKind::DriverDead,
+            FetchError::HttpStatus { status, .. } if *status >= 500 => FetchErrorKind::HttpServerError,
+            FetchError::HttpStatus { .. } => FetchErrorKind::HttpClientError,
+            FetchError::ParseFailure { .. } => FetchErrorKind::ParseFailure,
+            FetchError::Blocked { .. } | FetchError::CoolingDown { .. } => FetchErrorKind::BotWall,
+        };
+    }
+    if let Some(webdriver_error) = error.downcast_ref::<WebDriverError>() {
+        return classify_webdriver(webdriver_error);
+    }
+    if let Some(http_error) = error.downcast_ref::<reqwest::Error>() {
+        if http_error.is_timeout() {
+            return FetchErrorKind::NavigationTimeout;
+        }
+        if let Some(status) = http_error.status() {
+            return if status.is_server_error() {
+                FetchErrorKind::HttpServerError
+            } else {
+                FetchErrorKind::HttpClientError
+            };
+        }
+    }
+    FetchErrorKind::Other
+}
+
+/// Turns a WebDriver timeout while running `operation` on `url` into [`FetchError::TimedOut`];
+/// other errors are passed through unchanged.
+pub(crate) fn timed_out_or(error: WebDriverError, url: &str, operation: &str, timeout_ms: u64) -> anyhow::Error {
+    match error.as_inner() {
+        WebDriverErrorInner::Timeout(_)
+        | WebDriverErrorInner::WebDriverTimeout(_)
+        | WebDriverErrorInner::ScriptTimeout(_) => FetchError::TimedOut {
+            url: url.to_string(),
+            operation: operation.to_string(),
+            timeout_ms,
+        }
+        .into(),
+        _ => error.into(),
+    }
+}
+
+fn classify_webdriver(error: &WebDriverError) -> FetchErrorKind {
+    match error.as_inner() {
+        WebDriverErrorInner::Timeout(_)
+        | WebDriverErrorInner::WebDriverTimeout(_)
+        | WebDriverErrorInner::ScriptTimeout(_) => FetchErrorKind::NavigationTimeout,
+        // The driver cannot be reached or the session/browser behind it is gone
+        WebDriverErrorInner::RequestFailed(_)
+        | WebDriverErrorInner::HttpError(_)
+        | WebDriverErrorInner::IoError(_)
+        | WebDriverErrorInner::UnknownResponse(..)
+        | WebDriverErrorInner::InvalidSessionId(_)
+        | WebDriverErrorInner::SessionNotCreated(_)
+        | WebDriverErrorInner::NoSuchWindow(_)
+        | WebDriverErrorInner::FatalError(_)
+        | WebDriverErrorInner::CommandRecvError(_)
+        | WebDriverErrorInner::CommandSendError(_)
+        | WebDriverErrorInner::SessionCreateError(_) => FetchErrorKind::DriverDead,
+        _ => FetchErrorKind::Other,
+    }
+}
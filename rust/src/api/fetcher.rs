@@ -0,0 +1,123 @@
+// src/fetcher.rs
+use flutter_rust_bridge::frb;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::api::cookie_jar::SessionCookie;
+use crate::api::fetch_error::FetchError;
+use crate::api::site_config::SiteConfig;
+
+pub(crate) const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36";
+const MAX_REDIRECTS: usize = 10;
+
+/// Which backend is used to fetch a site's pages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetcherKind {
+    /// Drive a real browser through WebDriver; needed for pages rendered by JavaScript.
+    Browser,
+    /// Plain HTTP requests; enough for server-rendered pages and needs no browser.
+    Http,
+}
+
+impl FetcherKind {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            FetcherKind::Browser => "browser",
+            FetcherKind::Http => "http",
+        }
+    }
+}
+
+/// A backend that can turn a product URL into page HTML.
+pub trait Fetcher {
+    /// Fetches `url` using the settings of the site it belongs to and returns the page source.
+    /// Goes through `proxy` when one is given. Makes a single attempt; retrying is left to the caller.
+    fn fetch_page(
+        &mut self,
+        url: &str,
+        site: &SiteConfig,
+        proxy: Option<&str>,
+    ) -> impl Future<Output = anyhow::Result<String>> + Send;
+}
+
+/// Fetches pages with `reqwest`, keeping cookies between requests and
+/// transparently decompressing and following redirects.
+#[frb(ignore)]
+#[derive(Debug)]
+pub struct HttpFetcher {
+    // Shared by every client so imported cookies apply whichever proxy is used
+    cookies: Arc<reqwest::cookie::Jar>,
+    client: reqwest::Client,
+    // One client per proxy, created on first use
+    proxied_clients: HashMap<String, reqwest::Client>,
+}
+
+impl HttpFetcher {
+    pub(crate) fn new() -> anyhow::Result<Self> {
+        let cookies = Arc::new(reqwest::cookie::Jar::default());
+        Ok(Self {
+            client: Self::build_client(None, &cookies)?,
+            cookies,
+            proxied_clients: HashMap::new(),
+        })
+    }
+
+    /// Adds imported cookies for `domain` to the jar shared by all requests.
+    pub(crate) fn add_cookies(&self, domain: &str, cookies: &[SessionCookie]) -> anyhow::Result<()> {
+        let url = reqwest::Url::parse(&format!("https://{}/", domain))?;
+        for cookie in cookies {
+            self.cookies.add_cookie_str(&cookie.to_set_cookie(), &url);
+        }
+        Ok(())
+    }
+
+    fn client_for(&mut self, proxy: Option<&str>) -> anyhow::Result<&reqwest::Client> {
+        let Some(proxy) = proxy else {
+            return Ok(&self.client);
+        };
+        if !self.proxied_clients.contains_key(proxy) {
+            let client = Self::build_client(Some(proxy), &self.cookies)?;
+            self.proxied_clients.insert(proxy.to_string(), client);
+        }
+        Ok(&self.proxied_clients[proxy])
+    }
+
+    fn build_client(proxy: Option<&str>, cookies: &Arc<reqwest::cookie::Jar>) -> anyhow::Result<reqwest::Client> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            reqwest::header::ACCEPT,
+            "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8".parse()?,
+        );
+        headers.insert(reqwest::header::ACCEPT_LANGUAGE, "en-IN,en;q=0.9".parse()?);
+
+        let mut builder = reqwest::Client::builder()
+            .user_agent(DEFAULT_USER_AGENT)
+            .default_headers(headers)
+            .cookie_provider(cookies.clone())
+            .gzip(true)
+            .brotli(true)
+            .redirect(reqwest::redirect::Policy::limited(MAX_REDIRECTS))
+            .timeout(Duration::from_secs(30));
+        if let Some(proxy) = proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+        Ok(builder.build()?)
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    async fn fetch_page(&mut self, url: &str, _site: &SiteConfig, proxy: Option<&str>) -> anyhow::Result<String> {
+        let response = self.client_for(proxy)?.get(url).send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(FetchError::HttpStatus {
+                url: url.to_string(),
+                status: status.as_u16(),
+            }
+            .into());
+        }
+        Ok(response.text().await?)
+    }
+}
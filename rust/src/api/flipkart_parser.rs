@@ -6,6 +6,7 @@ use std::collections::HashMap;
 use regex::Regex;
 
 use crate::api::models::ProductDetails; // Import the regex crate
+use crate::api::money::{Money, DEFAULT_CURRENCY};
 
 // The function now accepts the URL as an argument
 pub fn extract_details(html_source: &str, url: &str) -> anyhow::Result<ProductDetails> {
@@ -47,10 +48,10 @@ pub fn extract_details(html_source: &str, url: &str) -> anyhow::Result<ProductDe
         .map(|t| t.text().collect::<String>().trim().to_string())
         .unwrap_or_else(|| "Not Found".to_string());
 
-    let price: Option<i32> = document
+    let price: Option<Money> = document
         .select(&price_selector)
         .next()
-        .and_then(|p| p.text().collect::<String>().replace(['₹', ','], "").trim().parse::<i32>().ok());
+        .and_then(|p| Money::parse(&p.text().collect::<String>(), DEFAULT_CURRENCY));
 
     let rating_value = document.select(&rating_value_selector).next().map(|r| r.text().collect::<String>());
     let rating_count = document.select(&rating_count_selector).next().map(|r| r.text().collect::<String>());
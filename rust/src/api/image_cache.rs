@@ -0,0 +1,165 @@
+// src/image_cache.rs
+use crate::api::database::data_dir;
+use crate::api::fetcher::DEFAULT_USER_AGENT;
+use crate::api::models::CachedImage;
+use crate::api::timeouts::FetchTimeouts;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+const CACHE_DIR_NAME: &str = "image_cache";
+const THUMBNAIL_SIZE: u32 = 256;
+
+/// Size of the on-disk image cache
+#[derive(Debug, Clone)]
+pub struct ImageCacheStats {
+    pub file_count: u32,
+    pub total_bytes: u64,
+}
+
+/// Content-addressed store for product images and their thumbnails.
+///
+/// Originals live in `image_cache/originals/<sha256>.<ext>` and thumbnails in
+/// `image_cache/thumbnails/<sha256>.jpg`, next to the database file.
+pub struct ImageCache {
+    root: PathBuf,
+    timeouts: FetchTimeouts,
+    // One client per proxy, created on first use and dropped when the timeouts change
+    clients: Mutex<HashMap<Option<String>, reqwest::Client>>,
+}
+
+impl ImageCache {
+    /// Creates a cache rooted in the directory containing `db_path`.
+    pub(crate) fn new(db_path: &str) -> anyhow::Result<Self> {
+        let root = data_dir(db_path).join(CACHE_DIR_NAME);
+        fs::create_dir_all(root.join("originals"))?;
+        fs::create_dir_all(root.join("thumbnails"))?;
+
+        Ok(Self {
+            root,
+            timeouts: FetchTimeouts::default(),
+            clients: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Limits image downloads to the per-fetch timeout in `timeouts`.
+    pub(crate) fn set_timeouts(&mut self, timeouts: FetchTimeouts) {
+        self.timeouts = timeouts;
+        self.clients.lock().unwrap().clear();
+    }
+
+    fn client_for(&self, proxy: Option<&str>) -> anyhow::Result<reqwest::Client> {
+        let mut clients = self.clients.lock().unwrap();
+        if let Some(client) = clients.get(&proxy.map(str::to_string)) {
+            return Ok(client.clone());
+        }
+        let mut builder = reqwest::Client::builder().user_agent(DEFAULT_USER_AGENT);
+        if let Some(limit) = self.timeouts.fetch_limit() {
+            builder = builder.connect_timeout(limit).read_timeout(limit);
+        }
+        if let Some(proxy) = proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+        let client = builder.build()?;
+        clients.insert(proxy.map(str::to_string), client.clone());
+        Ok(client)
+    }
+
+    /// Downloads an image through `proxy`, stores it under its content hash and generates
+    /// a thumbnail. Already-cached content is not written again. The download fails once
+    /// it takes longer than the per-fetch timeout.
+    pub(crate) async fn cache_image(&self, url: &str, proxy: Option<&str>) -> anyhow::Result<CachedImage> {
+        let client = self.client_for(proxy)?;
+        let bytes = self
+            .timeouts
+            .limit_fetch(url, async {
+                let response = client.get(url).send().await?.error_for_status()?;
+                Ok(response.bytes().await?.to_vec())
+            })
+            .await?;
+
+        let content_hash = Sha256::digest(&bytes)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>();
+        let format = image::guess_format(&bytes)?;
+        let extension = format.extensions_str().first().copied().unwrap_or("img");
+
+        let local_path = self.root.join("originals").join(format!("{}.{}", content_hash, extension));
+        let thumbnail_path = self.root.join("thumbnails").join(format!("{}.jpg", content_hash));
+
+        if !local_path.exists() {
+            fs::write(&local_path, &bytes)?;
+        }
+        if !thumbnail_path.exists() {
+            let thumb_path = thumbnail_path.clone();
+            tokio::task::spawn_blocking(move || -> anyhow::Result<()> {
+                let img = image::load_from_memory_with_format(&bytes, format)?;
+                img.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+                    .to_rgb8()
+                    .save_with_format(&thumb_path, image::ImageFormat::Jpeg)?;
+                Ok(())
+            })
+            .await??;
+        }
+
+        Ok(CachedImage {
+            url: url.to_string(),
+            content_hash,
+            local_path: local_path.to_string_lossy().to_string(),
+            thumbnail_path: thumbnail_path.to_string_lossy().to_string(),
+        })
+    }
+
+    /// Returns the number of files and total bytes held by the cache.
+    pub(crate) fn stats(&self) -> anyhow::Result<ImageCacheStats> {
+        let mut stats = ImageCacheStats {
+            file_count: 0,
+            total_bytes: 0,
+        };
+        for path in self.cached_files()? {
+            stats.file_count += 1;
+            stats.total_bytes += fs::metadata(&path)?.len();
+        }
+        Ok(stats)
+    }
+
+    /// Deletes cached files whose content hash is not in `referenced`.
+    /// Returns the size of what was removed.
+    pub(crate) fn remove_unreferenced(&self, referenced: &HashSet<String>) -> anyhow::Result<ImageCacheStats> {
+        let mut removed = ImageCacheStats {
+            file_count: 0,
+            total_bytes: 0,
+        };
+        for path in self.cached_files()? {
+            let hash = path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+            if referenced.contains(&hash) {
+                continue;
+            }
+            let size = fs::metadata(&path)?.len();
+            fs::remove_file(&path)?;
+            removed.file_count += 1;
+            removed.total_bytes += size;
+        }
+        Ok(removed)
+    }
+
+    fn cached_files(&self) -> anyhow::Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        for dir in ["originals", "thumbnails"] {
+            let dir = self.root.join(dir);
+            if !dir.exists() {
+                continue;
+            }
+            for entry in fs::read_dir(dir)? {
+                let path = entry?.path();
+                if path.is_file() {
+                    files.push(path);
+                }
+            }
+        }
+        Ok(files)
+    }
+}
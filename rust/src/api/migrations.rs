@@ -0,0 +1,266 @@
+// src/migrations.rs
+// Schema changes of the SQLite database, applied in order and tracked in
+// `PRAGMA user_version`. Every migration runs in its own transaction together with
+// the version bump, so a failure leaves the database at the last good version.
+// Append new migrations to `MIGRATIONS`; never edit or reorder released ones.
+use rusqlite::{Connection, Transaction};
+use std::fmt;
+use crate::api::database::data_dir;
+use std::path::{Path, PathBuf};
+
+const BACKUP_DIR_NAME: &str = "backups";
+
+struct Migration {
+    version: u32,
+    description: &'static str,
+    apply: fn(&Transaction) -> rusqlite::Result<()>,
+}
+
+const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "create the base schema",
+        apply: create_base_schema,
+    },
+    Migration {
+        version: 2,
+        description: "cascade product deletes and index price history",
+        apply: cascade_deletes_and_index,
+    },
+];
+
+/// Schema version this build of the app creates and understands.
+pub fn latest_version() -> u32 {
+    MIGRATIONS.last().map_or(0, |m| m.version)
+}
+
+/// The database was written by a newer version of the app and cannot be used safely.
+#[derive(Debug, Clone)]
+pub struct SchemaTooNew {
+    pub found: u32,
+    pub supported: u32,
+}
+
+impl fmt::Display for SchemaTooNew {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "The database uses schema version {}, but this app only supports up to version {}; update the app to open it",
+            self.found, self.supported
+        )
+    }
+}
+
+impl std::error::Error for SchemaTooNew {}
+
+/// Brings the database at `db_path` up to [`latest_version`]. A database holding data
+/// is first copied into `backups/` next to it, so a failed upgrade can be undone by hand.
+pub(crate) fn run(conn: &mut Connection, db_path: &str) -> anyhow::Result<()> {
+    let current = schema_version(conn)?;
+    let latest = latest_version();
+    if current > latest {
+        return Err(SchemaTooNew { found: current, supported: latest }.into());
+    }
+    if current == latest {
+        return Ok(());
+    }
+
+    if has_tables(conn)? {
+        if let Some(path) = backup_path_for(db_path, current) {
+            backup(conn, &path)?;
+            println!("Backed up database to {} before migrating", path.display());
+        }
+    }
+    // Rebuilding a table drops it, which enforced foreign keys would refuse or cascade;
+    // this pragma cannot change inside a transaction, so it is set for the whole run
+    conn.pragma_update(None, "foreign_keys", false)?;
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current) {
+        println!("Migrating database to version {}: {}...", migration.version, migration.description);
+        let tx = conn.transaction()?;
+        (migration.apply)(&tx)
+            .map_err(|e| anyhow::anyhow!("Database migration to version {} failed: {}", migration.version, e))?;
+        tx.pragma_update(None, "user_version", migration.version)?;
+        tx.commit()?;
+    }
+    Ok(())
+}
+
+fn schema_version(conn: &Connection) -> rusqlite::Result<u32> {
+    conn.pragma_query_value(None, "user_version", |row| row.get(0))
+}
+
+fn has_tables(conn: &Connection) -> rusqlite::Result<bool> {
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name NOT LIKE 'sqlite_%'",
+        [],
+        |row| row.get(0),
+    )?;
+    Ok(count > 0)
+}
+
+/// Where the copy taken before migrating from `version` goes, e.g.
+/// `backups/prices.v1.20260101T120000.db`; `None` for in-memory databases.
+fn backup_path_for(db_path: &str, version: u32) -> Option<PathBuf> {
+    let path = Path::new(db_path);
+    if db_path.is_empty() || db_path == ":memory:" {
+        return None;
+    }
+    let stem = path.file_stem()?.to_string_lossy();
+    let timestamp = chrono::Utc::now().format("%Y%m%dT%H%M%S");
+    Some(
+        data_dir(db_path)
+            .join(BACKUP_DIR_NAME)
+            .join(format!("{}.v{}.{}.db", stem, version, timestamp)),
+    )
+}
+
+// VACUUM INTO writes a consistent copy even while other connections use the database
+fn backup(conn: &Connection, path: &Path) -> anyhow::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    conn.execute("VACUUM INTO ?1", [path.to_string_lossy()])
+        .map_err(|e| anyhow::anyhow!("Could not back up the database to {} before migrating: {}", path.display(), e))?;
+    Ok(())
+}
+
+/// Version 1: the tables as they were before versioning. Databases from those releases
+/// may already have some of them, and may still store `prices` as whole rupees.
+fn create_base_schema(tx: &Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(
+        "CREATE TABLE IF NOT EXISTS products (
+            id TEXT PRIMARY KEY,
+            site TEXT NOT NULL,
+            url TEXT NOT NULL,
+            title TEXT NOT NULL,
+            seller TEXT,
+            images TEXT,
+            features TEXT,
+            specifications TEXT
+        );
+        CREATE TABLE IF NOT EXISTS prices (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            product_id TEXT NOT NULL,
+            price_minor INTEGER NOT NULL,
+            currency TEXT NOT NULL DEFAULT 'INR',
+            in_stock BOOLEAN NOT NULL,
+            timestamp TEXT NOT NULL,
+            FOREIGN KEY (product_id) REFERENCES products (id)
+        );
+        CREATE TABLE IF NOT EXISTS product_images (
+            product_id TEXT NOT NULL,
+            url TEXT NOT NULL,
+            position INTEGER NOT NULL,
+            content_hash TEXT NOT NULL,
+            local_path TEXT NOT NULL,
+            thumbnail_path TEXT NOT NULL,
+            PRIMARY KEY (product_id, url),
+            FOREIGN KEY (product_id) REFERENCES products (id)
+        );
+        CREATE TABLE IF NOT EXISTS fetch_checks (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            site TEXT NOT NULL,
+            url TEXT NOT NULL,
+            fetcher TEXT NOT NULL,
+            fell_back BOOLEAN NOT NULL,
+            timestamp TEXT NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS fetch_timings (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            site TEXT NOT NULL,
+            url TEXT NOT NULL,
+            fetcher TEXT NOT NULL,
+            blocked_resources TEXT NOT NULL,
+            duration_ms INTEGER NOT NULL,
+            timestamp TEXT NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS session_cookies (
+            domain TEXT PRIMARY KEY,
+            nonce BLOB NOT NULL,
+            ciphertext BLOB NOT NULL,
+            updated_at TEXT NOT NULL
+        );",
+    )?;
+    migrate_prices_to_money(tx)
+}
+
+/// Converts a `prices` table holding whole-rupee `price` integers into
+/// `price_minor` + `currency` columns. Does nothing on an up-to-date table.
+fn migrate_prices_to_money(tx: &Transaction) -> rusqlite::Result<()> {
+    let mut stmt = tx.prepare("SELECT name FROM pragma_table_info('prices')")?;
+    let columns: Vec<String> = stmt.query_map([], |row| row.get(0))?.collect::<rusqlite::Result<_>>()?;
+    if columns.iter().any(|c| c == "price_minor") {
+        return Ok(());
+    }
+
+    println!("Migrating price history to minor units with currency...");
+    tx.execute_batch(
+        "CREATE TABLE prices_new (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            product_id TEXT NOT NULL,
+            price_minor INTEGER NOT NULL,
+            currency TEXT NOT NULL DEFAULT 'INR',
+            in_stock BOOLEAN NOT NULL,
+            timestamp TEXT NOT NULL,
+            FOREIGN KEY (product_id) REFERENCES products (id)
+        );
+        INSERT INTO prices_new (id, product_id, price_minor, currency, in_stock, timestamp)
+            SELECT id, product_id, price * 100, 'INR', in_stock, timestamp FROM prices;
+        DROP TABLE prices;
+        ALTER TABLE prices_new RENAME TO prices;",
+    )
+}
+
+/// Version 2: price history and cached images are deleted with their product, and the
+/// per-product lookups are indexed. SQLite cannot change a foreign key in place, so both
+/// tables are rebuilt; rows left behind by products removed earlier are dropped first.
+fn cascade_deletes_and_index(tx: &Transaction) -> rusqlite::Result<()> {
+    let orphaned_prices = tx.execute("DELETE FROM prices WHERE product_id NOT IN (SELECT id FROM products)", [])?;
+    let orphaned_images = tx.execute("DELETE FROM product_images WHERE product_id NOT IN (SELECT id FROM products)", [])?;
+    if orphaned_prices + orphaned_images > 0 {
+        println!("Removed {} orphaned price entries and {} orphaned image entries", orphaned_prices, orphaned_images);
+    }
+
+    tx.execute_batch(
+        "CREATE TABLE prices_new (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            product_id TEXT NOT NULL,
+            price_minor INTEGER NOT NULL,
+            currency TEXT NOT NULL DEFAULT 'INR',
+            in_stock BOOLEAN NOT NULL,
+            timestamp TEXT NOT NULL,
+            FOREIGN KEY (product_id) REFERENCES products (id) ON DELETE CASCADE
+        );
+        INSERT INTO prices_new (id, product_id, price_minor, currency, in_stock, timestamp)
+            SELECT id, product_id, price_minor, currency, in_stock, timestamp FROM prices;
+        DROP TABLE prices;
+        ALTER TABLE prices_new RENAME TO prices;
+
+        CREATE TABLE product_images_new (
+            product_id TEXT NOT NULL,
+            url TEXT NOT NULL,
+            position INTEGER NOT NULL,
+            content_hash TEXT NOT NULL,
+            local_path TEXT NOT NULL,
+            thumbnail_path TEXT NOT NULL,
+            PRIMARY KEY (product_id, url),
+            FOREIGN KEY (product_id) REFERENCES products (id) ON DELETE CASCADE
+        );
+        INSERT INTO product_images_new (product_id, url, position, content_hash, local_path, thumbnail_path)
+            SELECT product_id, url, position, content_hash, local_path, thumbnail_path FROM product_images;
+        DROP TABLE product_images;
+        ALTER TABLE product_images_new RENAME TO product_images;
+
+        CREATE INDEX idx_prices_product_timestamp ON prices (product_id, timestamp);
+        CREATE INDEX idx_product_images_url ON product_images (url);",
+    )?;
+    // Fails the migration, and rolls it back, if any reference is still dangling
+    let violations: i64 = tx.query_row("SELECT COUNT(*) FROM pragma_foreign_key_check", [], |row| row.get(0))?;
+    if violations > 0 {
+        return Err(rusqlite::Error::SqliteFailure(
+            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_CONSTRAINT_FOREIGNKEY),
+            Some(format!("{} rows still reference missing products", violations)),
+        ));
+    }
+    Ok(())
+}
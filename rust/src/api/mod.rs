@@ -2,8 +2,29 @@
 pub mod amazon_parser;
 pub mod flipkart_parser;
 pub mod scraper_engine;
+pub mod session_pool;
+pub mod fetcher;
+pub mod fetch_error;
+pub mod retry_policy;
+pub mod timeouts;
+pub mod recycle_policy;
+pub mod watchdog;
+pub mod process_tree;
+pub mod bot_wall;
+pub mod events;
+pub mod rate_limiter;
+pub mod proxy;
+pub mod cookie_jar;
+pub mod resource_blocking;
+pub mod site_config;
 pub mod browser_service_manager;
+pub mod binary_discovery;
+pub mod driver_log;
 pub mod models;
+pub mod money;
 pub mod apin;
 pub mod price_engine;
-pub mod database;
\ No newline at end of file
+pub mod database;
+pub mod migrations;
+pub mod image_cache;
+pub mod diagnostics;
\ No newline at end of file
@@ -1,6 +1,8 @@
 // src/models.rs
 use serde::{Deserialize, Serialize};
 
+use crate::api::money::Money;
+
 // This struct remains unchanged
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ProductDetails {
@@ -8,7 +10,7 @@ pub struct ProductDetails {
     pub site: String,
     pub url: String,
     pub title: String,
-    pub price: Option<i32>,
+    pub price: Option<Money>,
     pub rating: String,
     pub features: Vec<String>,
     pub specifications: serde_json::Value,
@@ -20,7 +22,7 @@ pub struct ProductDetails {
 // This struct has been updated to derive Clone
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PriceEntry {
-    pub price: i32,
+    pub price: Money,
     pub in_stock: bool,
     pub timestamp: String,
 }
@@ -39,4 +41,45 @@ pub struct ProductRecord {
     // Add the features field here
     pub features: Vec<String>,
     pub price_history: Vec<PriceEntry>,
-}
\ No newline at end of file
+    // Local copies of `images`; absent from backups made before the image cache existed
+    #[serde(default)]
+    pub cached_images: Vec<CachedImage>,
+}
+
+// A product image downloaded into the local image cache.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedImage {
+    pub url: String,
+    pub content_hash: String,
+    pub local_path: String,
+    pub thumbnail_path: String,
+}
+// How often a fetcher served a site, aggregated from recorded checks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FetchPathStats {
+    pub site: String,
+    pub fetcher: String,
+    pub checks: u32,
+    pub fallbacks: u32,
+    pub last_used: String,
+}
+
+// How long successful fetches took per site, fetcher and set of blocked resources.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FetchTimingStats {
+    pub site: String,
+    pub fetcher: String,
+    // Comma-separated resource types the browser skipped; empty when nothing was blocked
+    pub blocked_resources: String,
+    pub fetches: u32,
+    pub avg_duration_ms: u64,
+    pub max_duration_ms: u64,
+}
+
+// Result of re-checking one product during a refresh-all.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RefreshOutcome {
+    pub url: String,
+    pub product: Option<ProductRecord>,
+    pub error: Option<String>,
+}
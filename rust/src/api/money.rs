@@ -0,0 +1,263 @@
+// src/money.rs
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Currency used when a price string carries no symbol or code.
+pub const DEFAULT_CURRENCY: &str = "INR";
+
+// Known currency markers, longest first so "US$" wins over "$" and "Rs." over "Rs".
+const CURRENCY_MARKERS: &[(&str, &str)] = &[
+    ("US$", "USD"),
+    ("USD", "USD"),
+    ("INR", "INR"),
+    ("EUR", "EUR"),
+    ("GBP", "GBP"),
+    ("JPY", "JPY"),
+    ("Rs.", "INR"),
+    ("Rs", "INR"),
+    ("₹", "INR"),
+    ("$", "USD"),
+    ("€", "EUR"),
+    ("£", "GBP"),
+    ("¥", "JPY"),
+];
+
+/// A monetary amount stored as an integer number of minor units (e.g. paise, cents)
+/// together with its ISO 4217 currency code.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "MoneyRepr")]
+pub struct Money {
+    pub minor_units: i64,
+    pub currency: String,
+}
+
+// Backups written before prices carried a currency stored whole rupees as a bare integer.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MoneyRepr {
+    Legacy(i64),
+    Full { minor_units: i64, currency: String },
+}
+
+impl From<MoneyRepr> for Money {
+    fn from(repr: MoneyRepr) -> Self {
+        match repr {
+            MoneyRepr::Legacy(major) => Money::from_major(major, DEFAULT_CURRENCY),
+            MoneyRepr::Full { minor_units, currency } => Money { minor_units, currency },
+        }
+    }
+}
+
+impl Money {
+    /// Creates an amount from minor units.
+    pub fn new(minor_units: i64, currency: &str) -> Self {
+        Self {
+            minor_units,
+            currency: currency.to_uppercase(),
+        }
+    }
+
+    /// Creates an amount from whole major units (e.g. rupees).
+    pub fn from_major(major: i64, currency: &str) -> Self {
+        let currency = currency.to_uppercase();
+        let minor_units = major * 10_i64.pow(minor_digits(&currency));
+        Self { minor_units, currency }
+    }
+
+    /// Parses a displayed price such as `₹1,23,456.00`, `$1,299.99` or `1.299,00 €`.
+    ///
+    /// The currency is taken from the symbol or code in the text, falling back to
+    /// `default_currency`. Returns `None` if the text contains no number.
+    pub fn parse(text: &str, default_currency: &str) -> Option<Money> {
+        let currency = detect_currency(text).unwrap_or(default_currency).to_uppercase();
+        let digits = minor_digits(&currency);
+
+        let start = text.find(|c: char| c.is_ascii_digit())?;
+        let end = text.rfind(|c: char| c.is_ascii_digit())? + 1;
+        // Keep a trailing separator ("1,299.") so it is treated as an empty fraction
+        let end = match text[end..].chars().next() {
+            Some(c @ ('.' | ',')) => end + c.len_utf8(),
+            _ => end,
+        };
+        let number: String = text[start..end]
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '\u{a0}' | '\u{202f}' | '\''))
+            .collect();
+        if number.chars().any(|c| !(c.is_ascii_digit() || c == '.' || c == ',')) {
+            return None;
+        }
+
+        let (whole, fraction) = split_decimal(&number);
+        let whole: String = whole.chars().filter(|c| c.is_ascii_digit()).collect();
+        let major: i64 = if whole.is_empty() { 0 } else { whole.parse().ok()? };
+
+        let mut minor: i64 = 0;
+        let mut fraction_digits = fraction.chars();
+        for _ in 0..digits {
+            let digit = fraction_digits.next().and_then(|c| c.to_digit(10)).unwrap_or(0);
+            minor = minor * 10 + digit as i64;
+        }
+        // Round half up on the first discarded digit
+        if fraction_digits.next().and_then(|c| c.to_digit(10)).unwrap_or(0) >= 5 {
+            minor += 1;
+        }
+
+        let minor_units = major.checked_mul(10_i64.pow(digits))?.checked_add(minor)?;
+        Some(Money { minor_units, currency })
+    }
+
+    /// Returns the amount in major units, for display and charting.
+    #[frb(sync)]
+    pub fn as_major(&self) -> f64 {
+        self.minor_units as f64 / 10_i64.pow(minor_digits(&self.currency)) as f64
+    }
+
+    /// The amount as the app shows it, e.g. `₹1,23,456.00`.
+    #[frb(sync)]
+    pub fn display(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = minor_digits(&self.currency);
+        let scale = 10_i64.pow(digits);
+        let sign = if self.minor_units < 0 { "-" } else { "" };
+        let abs = self.minor_units.unsigned_abs();
+        let whole = group_digits(&(abs / scale as u64).to_string(), self.currency == "INR");
+        let symbol = currency_symbol(&self.currency);
+
+        if digits == 0 {
+            write!(f, "{}{}{}", sign, symbol, whole)
+        } else {
+            let fraction = abs % scale as u64;
+            write!(f, "{}{}{}.{:0width$}", sign, symbol, whole, fraction, width = digits as usize)
+        }
+    }
+}
+
+/// Number of minor-unit digits for a currency.
+fn minor_digits(currency: &str) -> u32 {
+    match currency {
+        "JPY" | "KRW" => 0,
+        _ => 2,
+    }
+}
+
+fn currency_symbol(currency: &str) -> String {
+    CURRENCY_MARKERS
+        .iter()
+        .rev()
+        .find(|(_, code)| *code == currency)
+        .map(|(symbol, _)| symbol.to_string())
+        .unwrap_or_else(|| format!("{} ", currency))
+}
+
+fn detect_currency(text: &str) -> Option<&'static str> {
+    CURRENCY_MARKERS
+        .iter()
+        .find(|(marker, _)| text.contains(marker))
+        .map(|(_, code)| *code)
+}
+
+// Decides which separator, if any, is the decimal point and splits on it.
+// The last separator is decimal when both kinds appear; a lone separator is
+// decimal unless it is repeated or followed by exactly three digits.
+fn split_decimal(number: &str) -> (&str, &str) {
+    let Some(pos) = number.rfind(['.', ',']) else {
+        return (number, "");
+    };
+    let sep = number[pos..].chars().next().unwrap();
+    let other = if sep == '.' { ',' } else { '.' };
+    let after = &number[pos + 1..];
+
+    let is_decimal = if number.contains(other) {
+        true
+    } else {
+        number.matches(sep).count() == 1 && after.len() != 3
+    };
+
+    if is_decimal {
+        (&number[..pos], after)
+    } else {
+        (number, "")
+    }
+}
+
+// Groups digits in threes, or in the Indian lakh/crore style when requested.
+fn group_digits(digits: &str, indian: bool) -> String {
+    if digits.len() <= 3 {
+        return digits.to_string();
+    }
+    let (head, last3) = digits.split_at(digits.len() - 3);
+    let group = if indian { 2 } else { 3 };
+
+    let mut groups = Vec::new();
+    let mut end = head.len();
+    while end > 0 {
+        let start = end.saturating_sub(group);
+        groups.push(&head[start..end]);
+        end = start;
+    }
+    groups.reverse();
+    format!("{},{}", groups.join(","), last3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_indian_grouping() {
+        assert_eq!(Money::parse("₹1,23,456.00", "INR"), Some(Money::new(12_345_600, "INR")));
+        assert_eq!(Money::parse("Rs. 1,23,456", "INR"), Some(Money::new(12_345_600, "INR")));
+    }
+
+    #[test]
+    fn parses_eu_decimal_comma() {
+        assert_eq!(Money::parse("1.299,00 €", "INR"), Some(Money::new(129_900, "EUR")));
+        assert_eq!(Money::parse("12,5 €", "INR"), Some(Money::new(1_250, "EUR")));
+    }
+
+    #[test]
+    fn parses_thousands_without_decimals() {
+        assert_eq!(Money::parse("$1,299", "INR"), Some(Money::new(129_900, "USD")));
+        assert_eq!(Money::parse("$1,299.99", "INR"), Some(Money::new(129_999, "USD")));
+        assert_eq!(Money::parse("¥1,500", "INR"), Some(Money::new(1_500, "JPY")));
+    }
+
+    #[test]
+    fn falls_back_to_default_currency() {
+        assert_eq!(Money::parse("499", "inr"), Some(Money::new(49_900, "INR")));
+    }
+
+    #[test]
+    fn rounds_extra_fraction_digits_half_up() {
+        assert_eq!(Money::parse("$1,299.995", "USD"), Some(Money::new(130_000, "USD")));
+        assert_eq!(Money::parse("$1,299.994", "USD"), Some(Money::new(129_999, "USD")));
+    }
+
+    #[test]
+    fn rejects_ranges_and_text_without_numbers() {
+        assert_eq!(Money::parse("₹1,000 - ₹2,000", "INR"), None);
+        assert_eq!(Money::parse("1,000–2,000", "INR"), None);
+        assert_eq!(Money::parse("Currently unavailable", "INR"), None);
+    }
+
+    #[test]
+    fn deserializes_legacy_whole_rupee_integers() {
+        let legacy: Money = serde_json::from_str("1299").unwrap();
+        assert_eq!(legacy, Money::new(129_900, "INR"));
+        let full: Money = serde_json::from_str(r#"{"minor_units":129999,"currency":"USD"}"#).unwrap();
+        assert_eq!(full, Money::new(129_999, "USD"));
+    }
+
+    #[test]
+    fn displays_with_symbol_and_grouping() {
+        assert_eq!(Money::new(12_345_600, "INR").to_string(), "₹1,23,456.00");
+        assert_eq!(Money::new(123_456_789, "USD").to_string(), "$1,234,567.89");
+        assert_eq!(Money::new(1_500, "JPY").to_string(), "¥1,500");
+    }
+}
@@ -1,10 +1,51 @@
 // src/price_engine.rs
 use crate::api::{
-    amazon_parser, database::Database, flipkart_parser, models::ProductRecord, 
+    amazon_parser, binary_discovery::{self, DiscoveryReport}, bot_wall, database::Database, flipkart_parser, models::{FetchPathStats, FetchTimingStats, ProductDetails, ProductRecord, RefreshOutcome},
     scraper_engine::BrowserEngine, 
-    browser_service_manager::ServiceStatus,
+    session_pool::{LoadedPage, PageJob},
+    browser_service_manager::{BrowserKind, BrowserOptions, BrowserServiceManager, ServiceStatus},
+    cookie_jar::{self, CookieFormat, CookieVault, SessionCookie},
+    diagnostics::{DiagnosticCapture, DiagnosticCaptureContent, DiagnosticsStore},
+    driver_log,
+    process_tree,
+    events::{EngineEvent, EventBus},
+    fetch_error::{self, FetchError, FetchErrorKind},
+    fetcher::{Fetcher, FetcherKind, HttpFetcher},
+    image_cache::{ImageCache, ImageCacheStats},
+    proxy::{self, ProxyRotator, ProxyStatus},
+    rate_limiter::{DomainRateStatus, RateLimit, RateLimiter},
+    resource_blocking::{self, ResourceType},
+    recycle_policy::RecyclePolicy,
+    retry_policy::RetryPolicy,
+    site_config::{self, ReadyCondition, SiteConfig},
+    timeouts::FetchTimeouts,
+    watchdog::{self, WatchdogConfig},
 };
 use chrono;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+type Parser = fn(&str, &str) -> anyhow::Result<ProductDetails>;
+
+/// Picks the page parser for a product URL, or `None` for unsupported sites.
+fn parser_for(url: &str) -> Option<Parser> {
+    if url.contains("amazon.in") {
+        Some(amazon_parser::extract_details)
+    } else if url.contains("flipkart.com") {
+        Some(flipkart_parser::extract_details)
+    } else {
+        None
+    }
+}
+
+/// Whether a parsed page lacks the fields we cannot do without, which usually
+/// means a bot wall or a page the fetcher could not render.
+fn is_incomplete(details: &ProductDetails) -> bool {
+    details.title == "Not Found"
+}
 
 #[derive(Debug, Clone)]
 pub struct PriceEngineStatus {
@@ -12,82 +53,573 @@ pub struct PriceEngineStatus {
     pub current_port: u16,
     pub message: String,
     pub last_check: String,
+    pub rate_limits: Vec<DomainRateStatus>,
 }
 
 pub struct PriceEngine {
-    browser_engine: BrowserEngine,
+    // `None` when the engine runs without a browser (HTTP fetching only).
+    // Shared with the service watchdog, which probes and restarts it in the background
+    browser_engine: Option<Arc<Mutex<BrowserEngine>>>,
+    // The browser it drives, which limits the proxies that can be used
+    browser_kind: Option<BrowserKind>,
+    watchdog: Option<JoinHandle<()>>,
+    http_fetcher: HttpFetcher,
+    site_configs: Vec<SiteConfig>,
+    rate_limiter: Arc<RateLimiter>,
+    // The browser engine keeps its own copy for the sessions it opens
+    timeouts: FetchTimeouts,
+    proxies: ProxyRotator,
+    events: EventBus,
     database: Database,
-    initial_port: u16,
+    image_cache: ImageCache,
+    diagnostics: DiagnosticsStore,
+    cookie_vault: CookieVault,
+    // The binaries the engine was started with, for inspecting their versions later
     browser_path: String,
     driver_path: String,
 }
 
 impl PriceEngine {
     pub async fn new(port: u16, browser_path: &str, db_path: &str, driver_path: &str) -> anyhow::Result<Self> {
-        println!("Initializing browser engine with fallback...");
-        let (browser_engine, service_status) = BrowserEngine::new_with_fallback(port, browser_path, driver_path).await?;
+        Self::new_with_browser_kind(port, BrowserKind::detect(driver_path), browser_path, db_path, driver_path).await
+    }
+
+    /// Creates an engine for an explicitly chosen browser instead of guessing it from the driver name.
+    pub async fn new_with_browser_kind(
+        port: u16,
+        browser_kind: BrowserKind,
+        browser_path: &str,
+        db_path: &str,
+        driver_path: &str,
+    ) -> anyhow::Result<Self> {
+        Self::new_with_options(port, browser_kind, BrowserOptions::default(), browser_path, db_path, driver_path).await
+    }
+
+    /// Creates an engine whose browser is launched with `options` (profile directory,
+    /// extra arguments, window size, language, user agent and headless mode).
+    pub async fn new_with_options(
+        port: u16,
+        browser_kind: BrowserKind,
+        options: BrowserOptions,
+        browser_path: &str,
+        db_path: &str,
+        driver_path: &str,
+    ) -> anyhow::Result<Self> {
+        println!("Initializing {:?} browser engine with fallback...", browser_kind);
+        // An empty path means "find it"; the report also explains version mismatches
+        let binaries = binary_discovery::inspect(browser_kind, browser_path, driver_path).await;
+        let browser_path = match (browser_path.trim().is_empty(), binaries.browser.as_ref()) {
+            (true, Some(browser)) => browser.path.clone(),
+            _ => browser_path.to_string(),
+        };
+        let driver_path = match (driver_path.trim().is_empty(), binaries.driver.as_ref()) {
+            (true, Some(driver)) => driver.path.clone(),
+            _ => driver_path.to_string(),
+        };
+        if !binaries.compatible {
+            println!("Browser check: {}", binaries.message);
+        }
+
+        let mut service_manager = BrowserServiceManager::new(port, &driver_path, &browser_path);
+        service_manager.set_browser_kind(browser_kind);
+        service_manager.set_browser_options(options);
+        service_manager.set_driver_log_path(driver_log::path_for(db_path));
+        service_manager.set_pid_file(process_tree::pid_file_for(db_path));
+        let (browser_engine, service_status) = BrowserEngine::start_with_fallback(service_manager)
+            .await
+            .map_err(|e| match binaries.compatible {
+                true => e,
+                false => anyhow::anyhow!("{} ({})", e, binaries.message),
+            })?;
         
         if service_status.port != port {
             println!("Browser service started on different port: {} (requested: {})", service_status.port, port);
         }
 
+        Self::assemble(Some(browser_engine), site_config::default_site_configs(), db_path, browser_path, driver_path).await
+    }
+
+    /// Creates an engine that drives browsers through an already running WebDriver server,
+    /// such as a Selenium Grid at `http://host:4444`, instead of starting its own driver.
+    pub async fn new_remote(
+        webdriver_url: &str,
+        browser_kind: BrowserKind,
+        options: BrowserOptions,
+        db_path: &str,
+    ) -> anyhow::Result<Self> {
+        println!("Initializing {:?} browser engine on WebDriver server {}...", browser_kind, webdriver_url);
+        let mut service_manager = BrowserServiceManager::new_remote(webdriver_url, browser_kind)?;
+        service_manager.set_browser_options(options);
+        let (browser_engine, _) = BrowserEngine::start_with_fallback(service_manager).await?;
+
+        Self::assemble(Some(browser_engine), site_config::default_site_configs(), db_path, String::new(), String::new()).await
+    }
+
+    /// Creates an engine that never starts a browser; every site is fetched over plain HTTP.
+    pub async fn new_http_only(db_path: &str) -> anyhow::Result<Self> {
+        let mut site_configs = site_config::default_site_configs();
+        for site in site_configs.iter_mut() {
+            site.fetcher = FetcherKind::Http;
+        }
+
+        Self::assemble(None, site_configs, db_path, String::new(), String::new()).await
+    }
+
+    // Opens the database and its side stores and restores saved cookies
+    async fn assemble(
+        browser_engine: Option<BrowserEngine>,
+        site_configs: Vec<SiteConfig>,
+        db_path: &str,
+        browser_path: String,
+        driver_path: String,
+    ) -> anyhow::Result<Self> {
         println!("Connecting to database at '{}'...", db_path);
         let database = Database::new(db_path)?;
-        
-        Ok(Self {
-            browser_engine,
+        let image_cache = ImageCache::new(db_path)?;
+        let diagnostics = DiagnosticsStore::new(db_path)?;
+        let cookie_vault = CookieVault::open(db_path)?;
+
+        let mut engine = Self {
+            browser_kind: browser_engine.as_ref().map(BrowserEngine::get_browser_kind),
+            browser_engine: browser_engine.map(|b| Arc::new(Mutex::new(b))),
+            watchdog: None,
+            http_fetcher: HttpFetcher::new()?,
+            site_configs,
+            rate_limiter: Arc::new(RateLimiter::new()),
+            timeouts: FetchTimeouts::default(),
+            proxies: ProxyRotator::new(),
+            events: EventBus::new(),
             database,
-            initial_port: port,
-            browser_path: browser_path.to_string(),
-            driver_path: driver_path.to_string(),
-        })
+            image_cache,
+            diagnostics,
+            cookie_vault,
+            browser_path,
+            driver_path,
+        };
+        engine.restore_saved_cookies().await;
+        Ok(engine)
     }
 
     /// Checks the health status of the browser service
     pub async fn check_service_status(&self) -> PriceEngineStatus {
-        let browser_status = self.browser_engine.check_service_status().await;
+        let last_check = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string();
+        let Some(browser_engine) = self.browser_engine.as_ref() else {
+            return PriceEngineStatus {
+                is_healthy: true,
+                current_port: 0,
+                message: "Running without a browser (HTTP fetching only)".to_string(),
+                last_check,
+                rate_limits: self.rate_limiter.status(),
+            };
+        };
+
+        let browser_status = browser_engine.lock().await.check_service_status().await;
         
         PriceEngineStatus {
             is_healthy: browser_status.is_running,
             current_port: browser_status.current_port,
             message: browser_status.message,
-            last_check: chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+            last_check,
+            rate_limits: self.rate_limiter.status(),
         }
     }
 
     /// Gets the current port being used by the browser service
-    pub fn get_current_port(&self) -> u16 {
-        self.browser_engine.get_current_port()
+    pub async fn get_current_port(&self) -> u16 {
+        match self.browser_engine.as_ref() {
+            Some(browser_engine) => browser_engine.lock().await.get_current_port(),
+            None => 0,
+        }
+    }
+
+    /// Reports the browser and driver this engine runs, their versions and whether they match
+    pub async fn get_browser_binaries(&self) -> anyhow::Result<DiscoveryReport> {
+        let Some(browser_engine) = self.browser_engine.as_ref() else {
+            anyhow::bail!("No browser is configured for this engine");
+        };
+        let browser_engine = browser_engine.lock().await;
+        if browser_engine.is_remote() {
+            anyhow::bail!("The browser runs on a remote WebDriver server; its binaries cannot be inspected from here");
+        }
+        Ok(binary_discovery::inspect(browser_engine.get_browser_kind(), &self.browser_path, &self.driver_path).await)
+    }
+
+    /// Returns the newest `lines` lines of the browser driver's log, oldest first
+    pub async fn get_driver_log_tail(&self, lines: u32) -> anyhow::Result<Vec<String>> {
+        let Some(browser_engine) = self.browser_engine.as_ref() else {
+            anyhow::bail!("No browser is configured for this engine");
+        };
+        browser_engine.lock().await.driver_log_tail(lines as usize)
+    }
+
+    /// Sets how many browser sessions a refresh-all may run in parallel
+    pub async fn set_browser_pool_size(&mut self, pool_size: u32) -> anyhow::Result<()> {
+        let Some(browser_engine) = self.browser_engine.as_ref() else {
+            anyhow::bail!("No browser is configured for this engine");
+        };
+        browser_engine.lock().await.set_pool_size(pool_size);
+        Ok(())
+    }
+
+    /// Sets after how many page loads, how long, or how much memory the browser session is replaced
+    pub async fn set_browser_recycle_policy(&mut self, policy: RecyclePolicy) -> anyhow::Result<()> {
+        let Some(browser_engine) = self.browser_engine.as_ref() else {
+            anyhow::bail!("No browser is configured for this engine");
+        };
+        browser_engine.lock().await.set_recycle_policy(policy);
+        Ok(())
+    }
+
+    /// Sets how long page loads, scripts and whole fetches may take before they fail
+    /// as timed out. Open browser sessions are updated immediately.
+    pub async fn set_fetch_timeouts(&mut self, timeouts: FetchTimeouts) -> anyhow::Result<()> {
+        if let Some(browser_engine) = self.browser_engine.as_ref() {
+            browser_engine.lock().await.set_timeouts(timeouts.clone()).await?;
+        }
+        self.image_cache.set_timeouts(timeouts.clone());
+        self.timeouts = timeouts;
+        Ok(())
+    }
+
+    pub fn get_fetch_timeouts(&self) -> FetchTimeouts {
+        self.timeouts.clone()
     }
 
     /// Restarts the browser service if needed
     pub async fn restart_browser_service(&mut self) -> anyhow::Result<ServiceStatus> {
+        let Some(browser_engine) = self.browser_engine.as_ref() else {
+            anyhow::bail!("No browser is configured for this engine");
+        };
         println!("Restarting browser service...");
-        self.browser_engine.restart_with_fallback().await
+        browser_engine.lock().await.restart_with_fallback().await
+    }
+
+    /// Starts probing the browser service in the background, restarting it when it is
+    /// unhealthy and emitting status changes as engine events. Replaces a running watchdog.
+    pub fn start_service_watchdog(&mut self, config: WatchdogConfig) -> anyhow::Result<()> {
+        let Some(browser_engine) = self.browser_engine.as_ref().map(Arc::downgrade) else {
+            anyhow::bail!("No browser is configured for this engine");
+        };
+        self.stop_service_watchdog();
+        println!("Starting service watchdog, probing every {} ms", config.interval_ms);
+        self.watchdog = Some(watchdog::spawn(browser_engine, config, self.events.clone()));
+        Ok(())
+    }
+
+    /// Stops the background service watchdog, if one is running.
+    pub fn stop_service_watchdog(&mut self) {
+        if let Some(watchdog) = self.watchdog.take() {
+            watchdog.abort();
+        }
+    }
+
+    /// Returns the per-site settings currently in use.
+    pub fn get_site_configs(&self) -> Vec<SiteConfig> {
+        self.site_configs.clone()
+    }
+
+    /// Chooses which fetcher is used for `domain`, adding the site if it is not configured yet.
+    pub fn set_site_fetcher(&mut self, domain: &str, fetcher: FetcherKind) -> anyhow::Result<()> {
+        if fetcher == FetcherKind::Browser && self.browser_engine.is_none() {
+            anyhow::bail!("Cannot use the browser fetcher for {}: engine was started without a browser", domain);
+        }
+        self.site_config_mut(domain).fetcher = fetcher;
+        Ok(())
+    }
+
+    /// Enables or disables falling back to the other fetcher for `domain`.
+    pub fn set_site_fallback(&mut self, domain: &str, enabled: bool) {
+        self.site_config_mut(domain).fallback = enabled;
+    }
+
+    /// Sets what the browser waits for on `domain` pages and for how long.
+    pub fn set_site_ready_condition(&mut self, domain: &str, condition: ReadyCondition, timeout_ms: u32) {
+        let site = self.site_config_mut(domain);
+        site.ready_condition = condition;
+        site.ready_timeout_ms = timeout_ms;
+    }
+
+    /// Sets the request throttling for `domain`.
+    pub fn set_site_rate_limit(&mut self, domain: &str, rate_limit: RateLimit) {
+        self.site_config_mut(domain).rate_limit = rate_limit;
+    }
+
+    /// Sets how failed fetches of `domain` pages are retried.
+    pub fn set_site_retry_policy(&mut self, domain: &str, retry_policy: RetryPolicy) {
+        self.site_config_mut(domain).retry_policy = retry_policy;
+    }
+
+    /// Sets the proxies used by every site without a proxy list of its own; empty means direct.
+    pub fn set_default_proxies(&mut self, proxies: Vec<String>) -> anyhow::Result<()> {
+        proxy::validate_all(&proxies, self.browser_kind)?;
+        self.proxies.set_default_proxies(proxies);
+        Ok(())
+    }
+
+    /// Assigns `domain` its own rotation of proxies; empty falls back to the engine-wide proxies.
+    pub fn set_site_proxies(&mut self, domain: &str, proxies: Vec<String>) -> anyhow::Result<()> {
+        proxy::validate_all(&proxies, self.browser_kind)?;
+        self.site_config_mut(domain).proxies = proxies;
+        Ok(())
+    }
+
+    /// Returns the health of the configured proxies.
+    pub fn get_proxy_status(&self) -> Vec<ProxyStatus> {
+        self.proxies.status()
+    }
+
+    /// Sets how long fetches from `domain` pause after it served a captcha or access-denied page.
+    pub fn set_site_block_cooldown(&mut self, domain: &str, cooldown_ms: u32) {
+        self.site_config_mut(domain).block_cooldown_ms = cooldown_ms;
+    }
+
+    /// Lets `domain`'s pages load the given resource types even though the browser blocks them.
+    pub fn set_site_allowed_resources(&mut self, domain: &str, allowed: Vec<ResourceType>) {
+        self.site_config_mut(domain).allowed_resources = allowed;
+    }
+
+    /// Returns a receiver for engine events such as a site blocking us.
+    pub(crate) fn subscribe_events(&self) -> tokio::sync::broadcast::Receiver<EngineEvent> {
+        self.events.subscribe()
+    }
+
+    fn site_config_mut(&mut self, domain: &str) -> &mut SiteConfig {
+        let domain = domain.to_lowercase();
+        match self.site_configs.iter().position(|s| s.domain == domain) {
+            Some(index) => &mut self.site_configs[index],
+            None => {
+                self.site_configs.push(SiteConfig::new(&domain));
+                self.site_configs.last_mut().unwrap()
+            }
+        }
+    }
+
+    /// Settings for the site `url` belongs to, or defaults for an unknown site.
+    fn site_config_for(&self, url: &str) -> SiteConfig {
+        self.site_configs
+            .iter()
+            .find(|s| s.matches(url))
+            .cloned()
+            .unwrap_or_else(|| SiteConfig::new(&site_config::host_of(url).unwrap_or_default()))
+    }
+
+    /// Fetches the page through the given backend, retrying as the site's retry policy allows.
+    /// Every attempt waits for its rate-limit slot; the browser is only restarted when its
+    /// driver died. Pages that turn out to be bot walls fail with [`FetchError::Blocked`]
+    /// and pause the site.
+    async fn fetch_page_source(&mut self, url: &str, site: &SiteConfig, fetcher: FetcherKind) -> anyhow::Result<String> {
+        let retry = &site.retry_policy;
+        let mut attempt = 1;
+        loop {
+            bot_wall::ensure_not_cooling_down(url, &site.domain, &self.rate_limiter)?;
+            self.rate_limiter.acquire(&site.domain, &site.rate_limit).await;
+            // The site may have blocked another request while this one waited for its slot
+            bot_wall::ensure_not_cooling_down(url, &site.domain, &self.rate_limiter)?;
+
+            let result = self.fetch_page_once(url, site, fetcher).await.and_then(|html| {
+                bot_wall::check_page(&html, url, &site.domain, site.block_cooldown_ms, &self.rate_limiter, &self.events)?;
+                Ok(html)
+            });
+            let error = match result {
+                Ok(html) => return Ok(html),
+                Err(e) => e,
+            };
+            let kind = fetch_error::classify(&error);
+            if !retry.should_retry(kind, attempt) {
+                return Err(error);
+            }
+
+            let delay = retry.backoff(attempt);
+            println!("{:?} attempt {} for {} failed ({}): {}; retrying in {} ms", fetcher, attempt, url, kind, error, delay.as_millis());
+            tokio::time::sleep(delay).await;
+            if kind == FetchErrorKind::DriverDead {
+                if let Some(browser_engine) = self.browser_engine.as_ref() {
+                    browser_engine.lock().await.restart_after_driver_failure().await;
+                }
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Makes one fetch through the site's next proxy, records how the proxy did and,
+    /// on success, how long the fetch took.
+    async fn fetch_page_once(&mut self, url: &str, site: &SiteConfig, fetcher: FetcherKind) -> anyhow::Result<String> {
+        let started = Instant::now();
+        let (proxy, blocked, result) = match fetcher {
+            FetcherKind::Http => {
+                let proxy = self.proxies.next(site);
+                let result = self.timeouts.limit_fetch(url, self.http_fetcher.fetch_page(url, site, proxy.as_deref())).await;
+                (proxy, Vec::new(), result)
+            }
+            FetcherKind::Browser => match self.browser_engine.as_ref() {
+                // Switching a browser's proxy means a new session, so stay on the current one while it works
+                Some(browser_engine) => {
+                    let mut browser_engine = browser_engine.lock().await;
+                    let proxy = self.proxies.current(site);
+                    let result = browser_engine.fetch_page(url, site, proxy.as_deref()).await;
+                    (proxy, browser_engine.blocked_resources_for(site), result)
+                }
+                None => anyhow::bail!("No browser is configured to fetch {}", url),
+            },
+        };
+
+        if let Some(proxy) = proxy.as_deref() {
+            self.proxies.record(proxy, &result);
+        }
+        if result.is_ok() {
+            Self::record_fetch_timing(&self.database, url, site, fetcher, &blocked, started.elapsed());
+        }
+        result
+    }
+
+    // Timings only feed statistics, so failing to store one never fails the fetch
+    fn record_fetch_timing(database: &Database, url: &str, site: &SiteConfig, fetcher: FetcherKind, blocked: &[ResourceType], duration: Duration) {
+        let blocked = resource_blocking::describe(blocked);
+        if let Err(e) = database.record_fetch_timing(&site.domain, url, fetcher, &blocked, duration.as_millis() as u64)
+        {
+            println!("Failed to record fetch timing for {}: {}", url, e);
+        }
     }
     
     /// Fetches product details with automatic retry and fallback mechanisms
     pub async fn fetch_and_update_product(&mut self, url: &str) -> anyhow::Result<ProductRecord> {
-        // This will automatically handle service failures and restarts
-        let html_source = self.browser_engine.get_page_source(url).await?;
-
-        let mut details = if url.contains("amazon.in") {
-            amazon_parser::extract_details(&html_source, url)?
-        } else if url.contains("flipkart.com") {
-            flipkart_parser::extract_details(&html_source, url)?
-        } else {
+        let Some(parser) = parser_for(url) else {
             anyhow::bail!("Unsupported URL: {}", url)
         };
+        let site = self.site_config_for(url);
+        let (details, fetcher, fell_back) = self.fetch_details_with_fallback(url, &site, parser).await?;
+        self.store_product_details(url, &site, details, fetcher, fell_back).await
+    }
+
+    /// Re-checks every tracked product. Browser-served pages are loaded in parallel
+    /// through the session pool; anything that fails there, or is served over HTTP,
+    /// goes through the regular single-product path with its retries and fallback.
+    pub async fn refresh_all_products(&mut self) -> anyhow::Result<Vec<RefreshOutcome>> {
+        let urls: Vec<String> = self
+            .database
+            .get_all_products_with_history()?
+            .into_iter()
+            .map(|p| p.url)
+            .collect();
+
+        let pooled: Vec<(String, SiteConfig)> = urls
+            .iter()
+            .map(|url| (url.clone(), self.site_config_for(url)))
+            .filter(|(url, site)| site.fetcher == FetcherKind::Browser && parser_for(url).is_some())
+            .collect();
+        let mut prefetched = self.prefetch_with_browser_pool(pooled).await;
+
+        let mut outcomes = Vec::with_capacity(urls.len());
+        for url in urls {
+            let result = match prefetched.remove(&url) {
+                Some(page) => self.update_from_prefetched(&url, &page.html).await,
+                None => self.fetch_and_update_product(&url).await,
+            };
+            outcomes.push(match result {
+                Ok(product) => RefreshOutcome { url, product: Some(product), error: None },
+                Err(e) => RefreshOutcome { url, product: None, error: Some(e.to_string()) },
+            });
+        }
+        Ok(outcomes)
+    }
+
+    /// Loads pages through the browser session pool. Sites behind the same proxy are
+    /// fetched together, since every pooled session shares the browser's proxy.
+    /// Returns every page that loaded.
+    async fn prefetch_with_browser_pool(&mut self, pooled: Vec<(String, SiteConfig)>) -> HashMap<String, LoadedPage> {
+        let mut prefetched = HashMap::new();
+        let Some(browser_engine) = self.browser_engine.clone().filter(|_| !pooled.is_empty()) else {
+            return prefetched;
+        };
+        let mut browser_engine = browser_engine.lock().await;
+
+        let mut by_proxy: HashMap<Option<String>, Vec<(String, SiteConfig)>> = HashMap::new();
+        for (url, site) in pooled {
+            by_proxy.entry(self.proxies.current(&site)).or_default().push((url, site));
+        }
+
+        for (proxy, group) in by_proxy {
+            if let Err(e) = browser_engine.use_proxy(proxy.as_deref()).await {
+                println!("Could not switch the browser to proxy {:?}, refreshing its sites one by one: {}", proxy, e);
+                continue;
+            }
+            let jobs = group
+                .iter()
+                .map(|(url, site)| PageJob {
+                    url: url.clone(),
+                    domain: site.domain.clone(),
+                    condition: site.ready_condition.clone(),
+                    timeout: Duration::from_millis(site.ready_timeout_ms as u64),
+                    rate_limit: site.rate_limit.clone(),
+                    retry_policy: site.retry_policy.clone(),
+                    block_cooldown_ms: site.block_cooldown_ms,
+                    blocked_resources: browser_engine.blocked_resources_for(site),
+                })
+                .collect();
+            let results = match browser_engine
+                .fetch_pages_concurrently(jobs, self.rate_limiter.clone(), self.events.clone())
+                .await
+            {
+                Ok(results) => results,
+                Err(e) => {
+                    println!("Parallel refresh unavailable, refreshing one by one: {}", e);
+                    continue;
+                }
+            };
+            for ((url, site), result) in group.into_iter().zip(results) {
+                if let Some(proxy) = proxy.as_deref() {
+                    self.proxies.record(proxy, &result);
+                }
+                match result {
+                    Ok(page) => {
+                        let blocked = browser_engine.blocked_resources_for(&site);
+                        Self::record_fetch_timing(&self.database, &url, &site, FetcherKind::Browser, &blocked, page.duration);
+                        prefetched.insert(url, page);
+                    }
+                    Err(e) => println!("Parallel fetch of {} failed, will retry individually: {}", url, e),
+                }
+            }
+        }
+        prefetched
+    }
+
+    /// Parses a page loaded by the session pool, falling back to a full single
+    /// fetch when the page is unusable.
+    async fn update_from_prefetched(&mut self, url: &str, html: &str) -> anyhow::Result<ProductRecord> {
+        let site = self.site_config_for(url);
+        if let Some(parser) = parser_for(url) {
+            if let Ok(details) = parser(html, url) {
+                if !is_incomplete(&details) {
+                    return self.store_product_details(url, &site, details, FetcherKind::Browser, false).await;
+                }
+            }
+        }
+        self.fetch_and_update_product(url).await
+    }
+
+    /// Saves a freshly parsed product and returns its full record.
+    async fn store_product_details(
+        &mut self,
+        url: &str,
+        site: &SiteConfig,
+        mut details: ProductDetails,
+        fetcher: FetcherKind,
+        fell_back: bool,
+    ) -> anyhow::Result<ProductRecord> {
         details.url = url.to_string();
 
+        // Remember which path produced the data so we learn which sites need a browser
+        self.database.record_fetch_check(&site.domain, url, fetcher, fell_back)?;
+
         println!("Updating database for product ID: {}", details.id);
         
         // Always update the product basic information (title, seller, images, etc.)
         self.database.upsert_product(&details)?;
+        self.cache_product_images(&details, site).await;
         
         // Only update price entry if the item is in stock
         if details.in_stock {
-            if let Some(price) = details.price {
+            if let Some(price) = &details.price {
                 println!("Item is in stock with price: {}, updating price entry", price);
                 self.database.insert_price_entry(&details)?;
             } else {
@@ -104,6 +636,282 @@ impl PriceEngine {
         Ok(product_record)
     }
 
+    /// Fetches and parses a product with the site's configured fetcher, retrying once
+    /// through the other fetcher when the first attempt fails or misses critical fields.
+    /// Returns the details, the fetcher that produced them and whether a fallback happened.
+    async fn fetch_details_with_fallback(
+        &mut self,
+        url: &str,
+        site: &SiteConfig,
+        parser: Parser,
+    ) -> anyhow::Result<(ProductDetails, FetcherKind, bool)> {
+        let primary = site.fetcher;
+        let first = self.fetch_details(url, site, primary, parser).await;
+        let reason = match &first {
+            Ok(details) if !is_incomplete(details) => return first.map(|d| (d, primary, false)),
+            Ok(_) => "page is missing critical fields".to_string(),
+            // The whole site is paused; the other fetcher would hit the same wall
+            Err(e) if fetch_error::classify(e) == FetchErrorKind::BotWall => return Err(first.unwrap_err()),
+            Err(e) => e.to_string(),
+        };
+
+        let alternate = match primary {
+            FetcherKind::Http if self.browser_engine.is_some() => FetcherKind::Browser,
+            FetcherKind::Browser => FetcherKind::Http,
+            FetcherKind::Http => return first.map(|d| (d, primary, false)),
+        };
+        if !site.fallback {
+            return first.map(|d| (d, primary, false));
+        }
+
+        println!("{:?} fetch of {} failed ({}), falling back to {:?}", primary, url, reason, alternate);
+        match self.fetch_details(url, site, alternate, parser).await {
+            Ok(details) => Ok((details, alternate, true)),
+            // Prefer incomplete data from the first attempt over no data at all
+            Err(e) => first
+                .map(|d| (d, primary, false))
+                .map_err(|first_err| anyhow::anyhow!("{:?} fetch failed: {}; {:?} fallback failed: {}", primary, first_err, alternate, e)),
+        }
+    }
+
+    async fn fetch_details(
+        &mut self,
+        url: &str,
+        site: &SiteConfig,
+        fetcher: FetcherKind,
+        parser: Parser,
+    ) -> anyhow::Result<ProductDetails> {
+        let html_source = match self.fetch_page_source(url, site, fetcher).await {
+            Ok(html) => html,
+            Err(e) => {
+                if Self::should_capture(&e) {
+                    self.capture_failure(url, fetcher, None, &e.to_string()).await;
+                }
+                return Err(e);
+            }
+        };
+
+        match parser(&html_source, url) {
+            Ok(details) => {
+                if is_incomplete(&details) {
+                    self.capture_failure(url, fetcher, Some(&html_source), "page is missing critical fields").await;
+                }
+                Ok(details)
+            }
+            Err(e) => {
+                self.capture_failure(url, fetcher, Some(&html_source), &e.to_string()).await;
+                Err(FetchError::ParseFailure {
+                    url: url.to_string(),
+                    reason: e.to_string(),
+                }
+                .into())
+            }
+        }
+    }
+
+    /// Whether a failed fetch left a page worth saving. A dead driver shows nothing and a
+    /// skipped request would only capture whatever page was open before.
+    fn should_capture(error: &anyhow::Error) -> bool {
+        fetch_error::classify(error) != FetchErrorKind::DriverDead
+            && !matches!(error.downcast_ref::<FetchError>(), Some(FetchError::CoolingDown { .. }))
+    }
+
+    /// Saves the page a failed or suspicious fetch ended on, with a screenshot when the
+    /// browser was used. Capturing never fails the fetch itself.
+    async fn capture_failure(&self, url: &str, fetcher: FetcherKind, html: Option<&str>, reason: &str) {
+        let (browser_html, screenshot) = match (fetcher, self.browser_engine.as_ref()) {
+            (FetcherKind::Browser, Some(browser_engine)) => browser_engine.lock().await.capture_current_page().await,
+            _ => (None, None),
+        };
+        let html = html.or(browser_html.as_deref());
+        match self.diagnostics.save(url, fetcher.as_str(), reason, html, screenshot.as_deref()) {
+            Ok(Some(capture)) => println!("Saved diagnostic capture {} for {}", capture.id, url),
+            Ok(None) => {}
+            Err(e) => println!("Failed to save diagnostic capture for {}: {}", url, e),
+        }
+    }
+
+    /// Returns the most recent diagnostic captures for a product, newest first.
+    pub fn get_diagnostic_captures(&self, product_id: &str, limit: u32) -> anyhow::Result<Vec<DiagnosticCapture>> {
+        let product = self
+            .database
+            .get_product_with_history(product_id)?
+            .ok_or_else(|| anyhow::anyhow!("Product with ID '{}' not found", product_id))?;
+        self.diagnostics.list(Some(&product.url), limit as usize)
+    }
+
+    /// Returns the most recent diagnostic captures of `url`, or of every page when `None`,
+    /// newest first. Unlike [`Self::get_diagnostic_captures`] this needs no product, so it
+    /// also lists pages whose first fetch failed before they were ever saved.
+    pub fn get_diagnostic_captures_for_url(&self, url: Option<String>, limit: u32) -> anyhow::Result<Vec<DiagnosticCapture>> {
+        self.diagnostics.list(url.as_deref(), limit as usize)
+    }
+
+    /// Loads the saved page and screenshot of a diagnostic capture.
+    pub fn open_diagnostic_capture(&self, capture_id: &str) -> anyhow::Result<DiagnosticCaptureContent> {
+        self.diagnostics.open(capture_id)
+    }
+
+    /// Sets how many diagnostic captures are kept; the oldest are deleted first.
+    pub fn set_diagnostics_retention(&mut self, max_captures: u32) -> anyhow::Result<()> {
+        self.diagnostics.set_max_captures(max_captures)
+    }
+
+    /// Imports cookies for `domain` from a cookies.txt or JSON export, e.g. to scrape
+    /// while signed in. They are loaded into the browser and HTTP sessions and stored
+    /// encrypted so they survive restarts. Returns how many cookies were imported.
+    pub async fn import_cookies(&mut self, domain: &str, content: &str, format: CookieFormat) -> anyhow::Result<u32> {
+        let domain = domain.to_lowercase();
+        let cookies = cookie_jar::parse(content, format, &domain)?;
+        if cookies.is_empty() {
+            anyhow::bail!("The file contains no unexpired cookies for {}", domain);
+        }
+
+        self.apply_cookies(&domain, cookies.clone()).await?;
+        self.save_cookies(&domain, &cookies)?;
+        println!("Imported {} cookies for {}", cookies.len(), domain);
+        Ok(cookies.len() as u32)
+    }
+
+    /// Exports the cookies the session currently holds for `domain`, saving them as well
+    /// so changes made by the site (refreshed logins) are kept.
+    pub async fn export_cookies(&mut self, domain: &str, format: CookieFormat) -> anyhow::Result<String> {
+        let domain = domain.to_lowercase();
+        let cookies = match self.browser_engine.as_ref() {
+            Some(browser_engine) => {
+                let cookies = browser_engine.lock().await.get_site_cookies(&domain).await?;
+                self.save_cookies(&domain, &cookies)?;
+                cookies
+            }
+            // The HTTP client's jar cannot be listed, so export what was last saved
+            None => self.load_saved_cookies()?.remove(&domain).unwrap_or_default(),
+        };
+        cookie_jar::format(&cookies, format)
+    }
+
+    async fn apply_cookies(&mut self, domain: &str, cookies: Vec<SessionCookie>) -> anyhow::Result<()> {
+        self.http_fetcher.add_cookies(domain, &cookies)?;
+        if let Some(browser_engine) = self.browser_engine.as_ref() {
+            browser_engine.lock().await.set_site_cookies(domain, cookies).await?;
+        }
+        Ok(())
+    }
+
+    fn save_cookies(&self, domain: &str, cookies: &[SessionCookie]) -> anyhow::Result<()> {
+        let (nonce, ciphertext) = self.cookie_vault.seal(cookies)?;
+        self.database.save_session_cookies(domain, &nonce, &ciphertext)?;
+        Ok(())
+    }
+
+    fn load_saved_cookies(&self) -> anyhow::Result<HashMap<String, Vec<SessionCookie>>> {
+        let mut saved = HashMap::new();
+        for jar in self.database.get_all_session_cookies()? {
+            match self.cookie_vault.open_sealed(&jar.nonce, &jar.ciphertext) {
+                Ok(cookies) => {
+                    saved.insert(jar.domain, cookies);
+                }
+                Err(e) => println!("Ignoring saved cookies for {}: {}", jar.domain, e),
+            }
+        }
+        Ok(saved)
+    }
+
+    /// Loads the cookies saved by earlier runs into the new sessions.
+    async fn restore_saved_cookies(&mut self) {
+        let saved = match self.load_saved_cookies() {
+            Ok(saved) => saved,
+            Err(e) => {
+                println!("Failed to load saved cookies: {}", e);
+                return;
+            }
+        };
+        for (domain, cookies) in saved {
+            if let Err(e) = self.apply_cookies(&domain, cookies).await {
+                println!("Failed to restore cookies for {}: {}", domain, e);
+            }
+        }
+    }
+
+    /// Saves the browser's current cookies for every site that has saved cookies.
+    async fn save_browser_cookies(&mut self) {
+        let Some(browser_engine) = self.browser_engine.clone() else {
+            return;
+        };
+        let browser_engine = browser_engine.lock().await;
+        let domains = match self.database.get_all_session_cookies() {
+            Ok(jars) => jars.into_iter().map(|jar| jar.domain),
+            Err(_) => return,
+        };
+        for domain in domains {
+            let result = match browser_engine.get_site_cookies(&domain).await {
+                Ok(cookies) => self.save_cookies(&domain, &cookies),
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                println!("Failed to save cookies for {}: {}", domain, e);
+            }
+        }
+    }
+
+    /// Returns how often each fetcher served each site, for tuning site settings.
+    pub fn get_fetch_path_stats(&self) -> anyhow::Result<Vec<FetchPathStats>> {
+        self.database.get_fetch_path_stats().map_err(|e| anyhow::anyhow!(e))
+    }
+
+    /// Returns how long successful fetches took, per site, fetcher and set of blocked
+    /// resources, to compare page loads with and without resource blocking.
+    pub fn get_fetch_timing_stats(&self) -> anyhow::Result<Vec<FetchTimingStats>> {
+        self.database.get_fetch_timing_stats().map_err(|e| anyhow::anyhow!(e))
+    }
+
+    /// Downloads any of the product's images that are not cached yet, through the site's
+    /// proxy, and forgets cached images the product no longer shows. Failures are logged
+    /// and skipped so a bad image never fails a price check; all downloads together may
+    /// take at most the per-fetch timeout.
+    async fn cache_product_images(&self, details: &ProductDetails, site: &SiteConfig) {
+        if let Err(e) = self.database.remove_stale_cached_images(&details.id, &details.images) {
+            println!("Failed to forget stale images of {}: {}", details.id, e);
+        }
+
+        let proxy = self.proxies.current(site);
+        let downloads = async {
+            for (position, url) in details.images.iter().enumerate() {
+                let cached = match self.database.get_cached_image_by_url(url) {
+                    Ok(Some(image)) if std::path::Path::new(&image.local_path).exists() => image,
+                    _ => match self.image_cache.cache_image(url, proxy.as_deref()).await {
+                        Ok(image) => image,
+                        Err(e) => {
+                            println!("Failed to cache image {}: {}", url, e);
+                            continue;
+                        }
+                    },
+                };
+                if let Err(e) = self.database.upsert_cached_image(&details.id, position, &cached) {
+                    println!("Failed to record cached image {}: {}", url, e);
+                }
+            }
+        };
+        match self.timeouts.fetch_limit() {
+            Some(limit) => {
+                if tokio::time::timeout(limit, downloads).await.is_err() {
+                    println!("Caching images of {} took over {} ms, leaving the rest for the next check", details.id, limit.as_millis());
+                }
+            }
+            None => downloads.await,
+        }
+    }
+
+    /// Returns the size of the local image cache.
+    pub fn get_image_cache_stats(&self) -> anyhow::Result<ImageCacheStats> {
+        self.image_cache.stats()
+    }
+
+    /// Deletes cached images no longer referenced by any product and returns what was freed.
+    pub fn cleanup_image_cache(&self) -> anyhow::Result<ImageCacheStats> {
+        let referenced = self.database.get_referenced_image_hashes()?;
+        self.image_cache.remove_unreferenced(&referenced)
+    }
+
     /// Removes a product from the database by its ID.
     pub fn remove_product_by_id(&self, product_id: &str) -> anyhow::Result<()> {
         println!("remove_product_by_id called with ID: {}", product_id);
@@ -135,6 +943,16 @@ impl PriceEngine {
     
     /// Shuts down the browser engine gracefully.
     pub async fn shutdown(self) -> anyhow::Result<()> {
-        self.browser_engine.shutdown().await
+        let mut engine = self;
+        if let Some(watchdog) = engine.watchdog.take() {
+            watchdog.abort();
+            let _ = watchdog.await;
+        }
+        engine.save_browser_cookies().await;
+        match engine.browser_engine.take().map(Arc::try_unwrap) {
+            Some(Ok(browser_engine)) => browser_engine.into_inner().shutdown().await,
+            // Still held elsewhere; its sessions are quit when the last holder drops it
+            Some(Err(_)) | None => Ok(()),
+        }
     }
 }
\ No newline at end of file
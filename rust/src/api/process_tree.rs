@@ -0,0 +1,247 @@
+// src/process_tree.rs
+// Looks up and kills the processes a driver spawned. The browser runs as a child of
+// its driver, so the driver's process tree is where browser memory is measured and
+// what has to be killed for the browser to go away with it.
+use crate::api::database::data_dir;
+use std::path::{Path, PathBuf};
+
+const PID_FILE_NAME: &str = "driver.pid";
+
+/// Process ids of every descendant of `pid`, each listed after its parent.
+/// Empty where the process table cannot be read.
+pub(crate) fn descendants(pid: u32) -> Vec<u32> {
+    let parents = parent_pids();
+    let mut found = Vec::new();
+    let mut frontier = vec![pid];
+    while let Some(parent) = frontier.pop() {
+        for &(child, child_parent) in &parents {
+            if child_parent == parent && !found.contains(&child) {
+                found.push(child);
+                frontier.push(child);
+            }
+        }
+    }
+    found
+}
+
+/// Whether [`resident_memory_bytes`] can measure processes on this platform.
+pub(crate) fn can_read_memory() -> bool {
+    cfg!(target_os = "linux")
+}
+
+/// Resident memory of `pid` and all of its descendants, in bytes.
+/// `None` where per-process memory cannot be read.
+pub(crate) fn resident_memory_bytes(pid: u32) -> Option<u64> {
+    let own = resident_bytes(pid)?;
+    Some(own + descendants(pid).into_iter().filter_map(resident_bytes).sum::<u64>())
+}
+
+// (pid, parent pid) of every running process
+#[cfg(target_os = "linux")]
+fn parent_pids() -> Vec<(u32, u32)> {
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<u32>().ok())
+        .filter_map(|pid| {
+            let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+            // The command name is parenthesised and may itself contain spaces or parentheses
+            let after_name = &stat[stat.rfind(')')? + 1..];
+            let parent = after_name.split_whitespace().nth(1)?.parse().ok()?;
+            Some((pid, parent))
+        })
+        .collect()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn parent_pids() -> Vec<(u32, u32)> {
+    Vec::new()
+}
+
+#[cfg(target_os = "linux")]
+fn resident_bytes(pid: u32) -> Option<u64> {
+    let status = std::fs::read_to_string(format!("/proc/{}/status", pid)).ok()?;
+    let kilobytes: u64 = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn resident_bytes(_pid: u32) -> Option<u64> {
+    None
+}
+
+/// Kills `pid` and every process it spawned. On Unix the driver leads its own process
+/// group, so the group is killed too, catching browser processes that were re-parented
+/// after their parent exited; the group of a process that does not lead one is left alone.
+pub(crate) fn kill_tree(pid: u32) {
+    #[cfg(unix)]
+    {
+        let tree = descendants(pid);
+        // SAFETY: getpgid and kill only query or signal; an already-gone pid or group just returns an error
+        unsafe {
+            if libc::getpgid(pid as libc::pid_t) == pid as libc::pid_t {
+                libc::kill(-(pid as libc::pid_t), libc::SIGKILL);
+            }
+            libc::kill(pid as libc::pid_t, libc::SIGKILL);
+            for child in tree {
+                libc::kill(child as libc::pid_t, libc::SIGKILL);
+            }
+        }
+    }
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        let _ = std::process::Command::new("taskkill")
+            .args(["/PID", &pid.to_string(), "/T", "/F"])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output();
+    }
+}
+
+/// Executable name of a running process, or `None` if it is gone or cannot be looked up.
+pub(crate) fn process_name(pid: u32) -> Option<String> {
+    #[cfg(target_os = "linux")]
+    let name = std::fs::read_to_string(format!("/proc/{}/comm", pid)).ok();
+    #[cfg(all(unix, not(target_os = "linux")))]
+    let name = std::process::Command::new("ps")
+        .args(["-p", &pid.to_string(), "-o", "comm="])
+        .output()
+        .ok()
+        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned());
+    #[cfg(windows)]
+    let name = {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        // CSV output starts with the quoted image name, e.g. "chromedriver.exe","1234",...
+        std::process::Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {}", pid), "/FO", "CSV", "/NH"])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output()
+            .ok()
+            .and_then(|output| {
+                let text = String::from_utf8_lossy(&output.stdout).into_owned();
+                text.strip_prefix('"')?.split('"').next().map(str::to_string)
+            })
+    };
+    name.map(|n| n.trim().to_string()).filter(|n| !n.is_empty())
+}
+
+/// Where the driver's pid file for the database at `db_path` lives: `driver.pid` next to it.
+pub(crate) fn pid_file_for(db_path: &str) -> PathBuf {
+    data_dir(db_path).join(PID_FILE_NAME)
+}
+
+/// When `pid` started, in a platform-specific form that is only compared for equality.
+/// `None` if the process is gone or its start time cannot be looked up.
+pub(crate) fn start_time(pid: u32) -> Option<String> {
+    #[cfg(target_os = "linux")]
+    let started = {
+        // Field 22 of stat, in clock ticks since boot; the boot id tells reboots apart
+        let stat = std::fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+        let ticks = stat[stat.rfind(')')? + 1..].split_whitespace().nth(19)?.to_string();
+        let boot_id = std::fs::read_to_string("/proc/sys/kernel/random/boot_id").ok()?;
+        Some(format!("{}@{}", ticks, boot_id.trim()))
+    };
+    #[cfg(all(unix, not(target_os = "linux")))]
+    let started = std::process::Command::new("ps")
+        .args(["-p", &pid.to_string(), "-o", "lstart="])
+        .output()
+        .ok()
+        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned());
+    #[cfg(windows)]
+    let started = {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        std::process::Command::new("powershell")
+            .args(["-NoProfile", "-Command", &format!("(Get-Process -Id {}).StartTime.ToFileTimeUtc()", pid)])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+    started.map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/// A process listed in the pid file, with the start time that tells it apart from an
+/// unrelated process that got the same pid after a crash or reboot
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RecordedProcess {
+    pub pid: u32,
+    pub started: String,
+}
+
+impl RecordedProcess {
+    /// Whether the recorded process is still the one running under its pid.
+    pub(crate) fn is_running(&self) -> bool {
+        start_time(self.pid).as_deref() == Some(self.started.as_str())
+    }
+}
+
+/// Records the pids of a running driver and its browser with their start times, one
+/// process per line. Processes that are already gone are left out.
+pub(crate) fn write_pid_file(path: &Path, pids: &[u32]) -> std::io::Result<()> {
+    let content: String = pids
+        .iter()
+        .filter_map(|&pid| Some(format!("{} {}\n", pid, start_time(pid)?)))
+        .collect();
+    std::fs::write(path, content)
+}
+
+/// Reads the processes recorded by [`write_pid_file`]; empty if there is no file.
+/// Lines without a start time cannot be verified and are skipped.
+pub(crate) fn read_pid_file(path: &Path) -> Vec<RecordedProcess> {
+    std::fs::read_to_string(path)
+        .map(|content| {
+            content
+                .lines()
+                .filter_map(|line| {
+                    let (pid, started) = line.trim().split_once(' ')?;
+                    Some(RecordedProcess {
+                        pid: pid.parse().ok()?,
+                        started: started.trim().to_string(),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pid_file_round_trips_and_verifies_start_times() {
+        let path = std::env::temp_dir().join(format!("palert-pid-test-{}", std::process::id()));
+        write_pid_file(&path, &[std::process::id()]).unwrap();
+        let recorded = read_pid_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].pid, std::process::id());
+        assert!(recorded[0].is_running());
+
+        let reused = RecordedProcess { started: format!("{}-earlier", recorded[0].started), ..recorded[0].clone() };
+        assert!(!reused.is_running());
+    }
+
+    #[test]
+    fn read_pid_file_skips_lines_without_start_time() {
+        let path = std::env::temp_dir().join(format!("palert-legacy-pid-test-{}", std::process::id()));
+        std::fs::write(&path, "1234\n5678 42@boot\n").unwrap();
+        let recorded = read_pid_file(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(recorded, vec![RecordedProcess { pid: 5678, started: "42@boot".to_string() }]);
+    }
+}
@@ -0,0 +1,274 @@
+// src/rate_limiter.rs
+use flutter_rust_bridge::frb;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Politeness settings for requests to one site
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimit {
+    /// Sustained request rate the token bucket refills at.
+    pub requests_per_minute: u32,
+    /// How many requests may go out back-to-back after an idle period.
+    pub burst: u32,
+    /// Minimum gap between the starts of two requests.
+    pub min_spacing_ms: u32,
+    /// Up to this much random delay is added to every request.
+    pub jitter_ms: u32,
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self {
+            requests_per_minute: 12,
+            burst: 2,
+            min_spacing_ms: 2_000,
+            jitter_ms: 1_500,
+        }
+    }
+}
+
+/// Current throttling state of one domain, for the status API
+#[derive(Debug, Clone)]
+pub struct DomainRateStatus {
+    pub domain: String,
+    /// Requests currently sleeping until their slot.
+    pub waiting: u32,
+    /// Time until the next request could start, in milliseconds.
+    pub next_slot_in_ms: u64,
+    /// Time left before fetches resume after the site blocked us, in milliseconds.
+    pub cooldown_remaining_ms: u64,
+}
+
+#[derive(Debug)]
+struct DomainState {
+    tokens: f64,
+    refilled_at: Instant,
+    last_start: Option<Instant>,
+    waiting: u32,
+    cooldown_until: Option<Instant>,
+}
+
+impl DomainState {
+    fn new(now: Instant) -> Self {
+        Self {
+            // A fresh bucket is full; the refill in `reserve` clamps this to the site's burst
+            tokens: f64::INFINITY,
+            refilled_at: now,
+            last_start: None,
+            waiting: 0,
+            cooldown_until: None,
+        }
+    }
+}
+
+/// Token-bucket limiter shared by every fetch path, keyed by domain.
+#[frb(ignore)]
+#[derive(Debug, Default)]
+pub struct RateLimiter {
+    domains: Mutex<HashMap<String, DomainState>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Waits until a request to `domain` is allowed under `limit`.
+    ///
+    /// Each call reserves its slot before sleeping, so concurrent callers are spread
+    /// out instead of all waking at once.
+    pub(crate) async fn acquire(&self, domain: &str, limit: &RateLimit) {
+        let wait = self.reserve(domain, limit, Instant::now());
+        if wait.is_zero() {
+            return;
+        }
+
+        let _waiting = WaitingGuard::new(self, domain);
+        println!("Rate limiting {}: waiting {} ms", domain, wait.as_millis());
+        tokio::time::sleep(wait).await;
+    }
+
+    /// Returns the throttling state of every domain seen so far.
+    pub(crate) fn status(&self) -> Vec<DomainRateStatus> {
+        let now = Instant::now();
+        let domains = self.domains.lock().unwrap();
+        let mut status: Vec<DomainRateStatus> = domains
+            .iter()
+            .map(|(domain, state)| DomainRateStatus {
+                domain: domain.clone(),
+                waiting: state.waiting,
+                next_slot_in_ms: state
+                    .last_start
+                    .map_or(0, |start| start.saturating_duration_since(now).as_millis() as u64),
+                cooldown_remaining_ms: state
+                    .cooldown_until
+                    .map_or(0, |until| until.saturating_duration_since(now).as_millis() as u64),
+            })
+            .collect();
+        status.sort_by(|a, b| a.domain.cmp(&b.domain));
+        status
+    }
+
+    /// Pauses all requests to `domain` for `duration`, e.g. after it served a captcha.
+    pub(crate) fn start_cooldown(&self, domain: &str, duration: Duration) {
+        let now = Instant::now();
+        let mut domains = self.domains.lock().unwrap();
+        let state = domains.entry(domain.to_string()).or_insert_with(|| DomainState::new(now));
+        state.cooldown_until = Some(now + duration);
+    }
+
+    /// Time left in `domain`'s cooldown, or `None` if requests are allowed.
+    pub(crate) fn cooldown_remaining(&self, domain: &str) -> Option<Duration> {
+        let now = Instant::now();
+        let domains = self.domains.lock().unwrap();
+        domains
+            .get(domain)
+            .and_then(|state| state.cooldown_until)
+            .map(|until| until.saturating_duration_since(now))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    fn reserve(&self, domain: &str, limit: &RateLimit, now: Instant) -> Duration {
+        let burst = limit.burst.max(1) as f64;
+        let per_second = limit.requests_per_minute.max(1) as f64 / 60.0;
+
+        let mut domains = self.domains.lock().unwrap();
+        let state = domains.entry(domain.to_string()).or_insert_with(|| DomainState::new(now));
+
+        let elapsed = now.saturating_duration_since(state.refilled_at).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * per_second).min(burst);
+        state.refilled_at = now;
+
+        // A negative balance is a reservation against tokens that have not refilled yet
+        state.tokens -= 1.0;
+        let mut start = if state.tokens >= 0.0 {
+            now
+        } else {
+            now + Duration::from_secs_f64(-state.tokens / per_second)
+        };
+        if let Some(last) = state.last_start {
+            start = start.max(last + Duration::from_millis(limit.min_spacing_ms as u64));
+        }
+        if limit.jitter_ms > 0 {
+            start += Duration::from_millis(rand::random_range(0..=limit.jitter_ms as u64));
+        }
+
+        state.last_start = Some(start);
+        start.saturating_duration_since(now)
+    }
+}
+
+// Counts a caller as waiting for as long as it sleeps, even if its future is dropped.
+struct WaitingGuard<'a> {
+    limiter: &'a RateLimiter,
+    domain: String,
+}
+
+impl<'a> WaitingGuard<'a> {
+    fn new(limiter: &'a RateLimiter, domain: &str) -> Self {
+        if let Some(state) = limiter.domains.lock().unwrap().get_mut(domain) {
+            state.waiting += 1;
+        }
+        Self {
+            limiter,
+            domain: domain.to_string(),
+        }
+    }
+}
+
+impl Drop for WaitingGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(state) = self.limiter.domains.lock().unwrap().get_mut(&self.domain) {
+            state.waiting = state.waiting.saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limit(requests_per_minute: u32, burst: u32) -> RateLimit {
+        RateLimit {
+            requests_per_minute,
+            burst,
+            min_spacing_ms: 0,
+            jitter_ms: 0,
+        }
+    }
+
+    #[test]
+    fn burst_goes_out_immediately_then_waits_for_refill() {
+        let limiter = RateLimiter::new();
+        let limit = limit(60, 2);
+        let now = Instant::now();
+        assert_eq!(limiter.reserve("a.com", &limit, now), Duration::ZERO);
+        assert_eq!(limiter.reserve("a.com", &limit, now), Duration::ZERO);
+        // One token per second at 60 per minute
+        assert_eq!(limiter.reserve("a.com", &limit, now), Duration::from_secs(1));
+        assert_eq!(limiter.reserve("a.com", &limit, now), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn tokens_refill_over_time_up_to_the_burst() {
+        let limiter = RateLimiter::new();
+        let limit = limit(60, 2);
+        let now = Instant::now();
+        limiter.reserve("a.com", &limit, now);
+        limiter.reserve("a.com", &limit, now);
+
+        let later = now + Duration::from_secs(1);
+        assert_eq!(limiter.reserve("a.com", &limit, later), Duration::ZERO);
+
+        // A long idle period refills no more than the burst
+        let much_later = later + Duration::from_secs(600);
+        assert_eq!(limiter.reserve("a.com", &limit, much_later), Duration::ZERO);
+        assert_eq!(limiter.reserve("a.com", &limit, much_later), Duration::ZERO);
+        assert_eq!(limiter.reserve("a.com", &limit, much_later), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn domains_have_separate_buckets() {
+        let limiter = RateLimiter::new();
+        let limit = limit(60, 1);
+        let now = Instant::now();
+        assert_eq!(limiter.reserve("a.com", &limit, now), Duration::ZERO);
+        assert_eq!(limiter.reserve("b.com", &limit, now), Duration::ZERO);
+    }
+
+    #[test]
+    fn requests_keep_the_minimum_spacing() {
+        let limiter = RateLimiter::new();
+        let limit = RateLimit {
+            min_spacing_ms: 500,
+            ..limit(600, 10)
+        };
+        let now = Instant::now();
+        assert_eq!(limiter.reserve("a.com", &limit, now), Duration::ZERO);
+        assert_eq!(limiter.reserve("a.com", &limit, now), Duration::from_millis(500));
+        assert_eq!(limiter.reserve("a.com", &limit, now), Duration::from_millis(1_000));
+    }
+
+    #[test]
+    fn jitter_stays_within_its_bound() {
+        let limit = RateLimit {
+            jitter_ms: 300,
+            ..limit(6_000, 1_000)
+        };
+        let now = Instant::now();
+        for _ in 0..200 {
+            // A fresh limiter each time so only the jitter delays the request
+            let wait = RateLimiter::new().reserve("a.com", &limit, now);
+            assert!(wait <= Duration::from_millis(300), "waited {:?}", wait);
+        }
+    }
+
+    #[test]
+    fn cooldown_pauses_only_its_domain() {
+        let limiter = RateLimiter::new();
+        limiter.start_cooldown("a.com", Duration::from_secs(60));
+        assert!(limiter.cooldown_remaining("a.com").is_some());
+        assert!(limiter.cooldown_remaining("b.com").is_none());
+    }
+}
@@ -0,0 +1,96 @@
+// src/recycle_policy.rs
+use flutter_rust_bridge::frb;
+use std::time::{Duration, Instant};
+
+/// When the browser session is replaced by a fresh one, to shed the memory and
+/// state long-lived sessions accumulate. A limit of 0 disables that check.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecyclePolicy {
+    /// Page loads after which the session is replaced.
+    pub max_navigations: u32,
+    /// Age after which the session is replaced, in milliseconds.
+    pub max_session_age_ms: u64,
+    /// Resident memory of the driver and browser processes above which the session is
+    /// replaced. Memory can only be read on Linux; elsewhere this limit never triggers.
+    pub max_memory_mb: u32,
+}
+
+impl Default for RecyclePolicy {
+    fn default() -> Self {
+        Self {
+            max_navigations: 200,
+            max_session_age_ms: 60 * 60 * 1000,
+            max_memory_mb: 2048,
+        }
+    }
+}
+
+/// How much a session has been used since it was opened
+#[frb(ignore)]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SessionUsage {
+    pub navigations: u32,
+    pub opened_at: Instant,
+}
+
+impl SessionUsage {
+    pub(crate) fn new() -> Self {
+        Self {
+            navigations: 0,
+            opened_at: Instant::now(),
+        }
+    }
+}
+
+impl RecyclePolicy {
+    /// Why a session with `usage` should be replaced, or `None` while it is within limits.
+    /// `memory_bytes` is only looked up when a memory limit is set.
+    pub(crate) fn recycle_reason(&self, usage: &SessionUsage, memory_bytes: impl FnOnce() -> Option<u64>) -> Option<String> {
+        if self.max_navigations > 0 && usage.navigations >= self.max_navigations {
+            return Some(format!("{} page loads", usage.navigations));
+        }
+        let age = usage.opened_at.elapsed();
+        if self.max_session_age_ms > 0 && age >= Duration::from_millis(self.max_session_age_ms) {
+            return Some(format!("session is {} s old", age.as_secs()));
+        }
+        if self.max_memory_mb > 0 {
+            let memory_mb = memory_bytes()? / (1024 * 1024);
+            if memory_mb >= self.max_memory_mb as u64 {
+                return Some(format!("browser uses {} MB", memory_mb));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recycle_reason_checks_navigations_and_age() {
+        let policy = RecyclePolicy { max_navigations: 3, max_session_age_ms: 60_000, max_memory_mb: 0 };
+        let mut usage = SessionUsage::new();
+        usage.navigations = 2;
+        assert_eq!(policy.recycle_reason(&usage, || None), None);
+
+        usage.navigations = 3;
+        assert_eq!(policy.recycle_reason(&usage, || None).as_deref(), Some("3 page loads"));
+
+        let old = SessionUsage { navigations: 0, opened_at: Instant::now() - Duration::from_secs(61) };
+        assert!(policy.recycle_reason(&old, || None).unwrap().contains("old"));
+    }
+
+    #[test]
+    fn recycle_reason_only_reads_memory_when_limited() {
+        let mut policy = RecyclePolicy { max_navigations: 0, max_session_age_ms: 0, max_memory_mb: 0 };
+        let usage = SessionUsage::new();
+        assert_eq!(policy.recycle_reason(&usage, || panic!("memory read without a limit")), None);
+
+        policy.max_memory_mb = 100;
+        assert_eq!(policy.recycle_reason(&usage, || Some(99 * 1024 * 1024)), None);
+        assert!(policy.recycle_reason(&usage, || Some(100 * 1024 * 1024)).is_some());
+        // Unknown memory, as on platforms that cannot read it, never recycles
+        assert_eq!(policy.recycle_reason(&usage, || None), None);
+    }
+}
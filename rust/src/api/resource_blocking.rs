@@ -0,0 +1,71 @@
+// src/resource_blocking.rs
+use serde_json::json;
+use thirtyfour::extensions::cdp::ChromeDevTools;
+
+/// Kinds of page resources the browser can skip downloading
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceType {
+    Image,
+    Font,
+    Media,
+}
+
+impl ResourceType {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            ResourceType::Image => "image",
+            ResourceType::Font => "font",
+            ResourceType::Media => "media",
+        }
+    }
+
+    /// File extensions of this kind of resource.
+    fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            ResourceType::Image => &["jpg", "jpeg", "png", "gif", "webp", "avif", "svg", "ico"],
+            ResourceType::Font => &["woff", "woff2", "ttf", "otf", "eot"],
+            ResourceType::Media => &["mp4", "webm", "m3u8", "mp3", "ogg", "m4a"],
+        }
+    }
+}
+
+// DevTools wildcard patterns for the resources' URLs, with and without a query string
+fn url_patterns(blocked: &[ResourceType]) -> Vec<String> {
+    blocked
+        .iter()
+        .flat_map(|t| t.extensions())
+        .flat_map(|ext| [format!("*.{}", ext), format!("*.{}?*", ext)])
+        .collect()
+}
+
+/// The engine-wide `blocked` types minus those a site needs to render.
+pub(crate) fn effective(blocked: &[ResourceType], allowed: &[ResourceType]) -> Vec<ResourceType> {
+    blocked.iter().copied().filter(|t| !allowed.contains(t)).collect()
+}
+
+/// Names the blocked types, e.g. "font,image"; empty when nothing is blocked.
+/// Used to group fetch timings by blocking setup.
+pub(crate) fn describe(blocked: &[ResourceType]) -> String {
+    let mut names: Vec<&str> = blocked.iter().map(ResourceType::as_str).collect();
+    names.sort();
+    names.dedup();
+    names.join(",")
+}
+
+/// Makes the session skip requests for `blocked` resources until changed again;
+/// an empty list lets everything through. Uses DevTools, so only Chromium-based
+/// browsers block per navigation; Firefox relies on the preferences set when its
+/// session was created, and the failed DevTools call is ignored.
+pub(crate) async fn apply(driver: &thirtyfour::WebDriver, blocked: &[ResourceType]) {
+    let urls = url_patterns(blocked);
+    let dev_tools = ChromeDevTools::new(driver.handle.clone());
+    if dev_tools.execute_cdp("Network.enable").await.is_err() {
+        return;
+    }
+    if let Err(e) = dev_tools
+        .execute_cdp_with_params("Network.setBlockedURLs", json!({ "urls": urls }))
+        .await
+    {
+        println!("Could not set blocked resources: {}", e);
+    }
+}
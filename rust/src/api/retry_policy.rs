@@ -0,0 +1,83 @@
+// src/retry_policy.rs
+use std::time::Duration;
+
+use crate::api::fetch_error::FetchErrorKind;
+
+/// How often and how patiently a failed fetch is retried
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub initial_backoff_ms: u32,
+    /// Upper bound for any single delay.
+    pub max_backoff_ms: u32,
+    /// Factor the delay grows by after every retry.
+    pub backoff_multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff_ms: 1_000,
+            max_backoff_ms: 30_000,
+            backoff_multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Whether a failure of `kind` on attempt number `attempt` (starting at 1) should be retried.
+    pub(crate) fn should_retry(&self, kind: FetchErrorKind, attempt: u32) -> bool {
+        attempt < self.max_attempts && kind.is_retryable()
+    }
+
+    /// Delay to wait after failed attempt number `attempt` (starting at 1).
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        // Clamped so a huge attempt count cannot wrap into a negative exponent
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let delay = self.initial_backoff_ms as f64 * self.backoff_multiplier.max(1.0).powi(exponent);
+        Duration::from_millis(delay.min(self.max_backoff_ms as f64) as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_by_the_multiplier() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(1), Duration::from_millis(1_000));
+        assert_eq!(policy.backoff(2), Duration::from_millis(2_000));
+        assert_eq!(policy.backoff(3), Duration::from_millis(4_000));
+    }
+
+    #[test]
+    fn backoff_is_capped_at_the_maximum() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(10), Duration::from_millis(30_000));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(30_000));
+    }
+
+    #[test]
+    fn backoff_never_shrinks() {
+        let policy = RetryPolicy {
+            backoff_multiplier: 0.5,
+            ..RetryPolicy::default()
+        };
+        assert_eq!(policy.backoff(4), Duration::from_millis(1_000));
+    }
+
+    #[test]
+    fn retries_only_retryable_kinds_within_the_attempt_limit() {
+        let policy = RetryPolicy::default();
+        assert!(policy.should_retry(FetchErrorKind::NavigationTimeout, 1));
+        assert!(policy.should_retry(FetchErrorKind::DriverDead, 2));
+        assert!(!policy.should_retry(FetchErrorKind::NavigationTimeout, 3));
+        assert!(!policy.should_retry(FetchErrorKind::BotWall, 1));
+        assert!(!policy.should_retry(FetchErrorKind::HttpClientError, 1));
+        assert!(!policy.should_retry(FetchErrorKind::ParseFailure, 1));
+    }
+}
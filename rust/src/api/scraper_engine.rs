@@ -1,14 +1,40 @@
 // src/scraper_engine.rs
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use thirtyfour::By;
 use flutter_rust_bridge::frb;
-use crate::api::browser_service_manager::{BrowserServiceManager, ServiceStatus};
+use crate::api::bot_wall;
+use crate::api::browser_service_manager::{BrowserKind, BrowserServiceManager, ServiceStatus};
+use crate::api::cookie_jar::{self, CookiesByDomain, SessionCookie};
+use crate::api::events::EventBus;
+use crate::api::fetch_error::{self, FetchError, FetchErrorKind};
+use crate::api::fetcher::Fetcher;
+use crate::api::rate_limiter::RateLimiter;
+use crate::api::resource_blocking::{self, ResourceType};
+use crate::api::process_tree;
+use crate::api::recycle_policy::{RecyclePolicy, SessionUsage};
+use crate::api::retry_policy::RetryPolicy;
+use crate::api::session_pool::{self, LoadedPage, PageJob, PooledSession, SessionSpec};
+use crate::api::site_config::{ReadyCondition, SiteConfig, DEFAULT_READY_TIMEOUT_MS};
+use crate::api::timeouts::FetchTimeouts;
+
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+const DEFAULT_POOL_SIZE: usize = 2;
 
 /// The main engine to control a persistent browser session with fallback mechanisms.
 #[derive(Debug)]
 #[frb(opaque)]
 pub struct BrowserEngine {
     driver: Option<thirtyfour::WebDriver>,
+    // Idle extra sessions used alongside `driver` for concurrent fetches
+    pool: Vec<PooledSession>,
+    pool_size: usize,
     service_manager: BrowserServiceManager,
+    // Imported cookies, restored into every session the engine opens
+    cookies: CookiesByDomain,
+    recycle_policy: RecyclePolicy,
+    // Use of `driver` since it was opened, checked against `recycle_policy`
+    session_usage: SessionUsage,
 }
 
 #[derive(Debug, Clone)]
@@ -35,7 +61,12 @@ impl BrowserEngine {
 
         Ok(Self {
             driver: Some(driver),
+            pool: Vec::new(),
+            pool_size: DEFAULT_POOL_SIZE,
             service_manager,
+            cookies: CookiesByDomain::new(),
+            recycle_policy: RecyclePolicy::default(),
+            session_usage: SessionUsage::new(),
         })
     }
 
@@ -45,8 +76,14 @@ impl BrowserEngine {
         browser_path: &str, 
         driver_path: &str
     ) -> anyhow::Result<(Self, ServiceStatus)> {
-        let mut service_manager = BrowserServiceManager::new(initial_port, driver_path, browser_path);
-        
+        let service_manager = BrowserServiceManager::new(initial_port, driver_path, browser_path);
+        Self::start_with_fallback(service_manager).await
+    }
+
+    /// Starts a BrowserEngine from a configured service manager, retrying on a new port on failure
+    pub(crate) async fn start_with_fallback(
+        mut service_manager: BrowserServiceManager,
+    ) -> anyhow::Result<(Self, ServiceStatus)> {
         // Try to start service with fallback
         let status = match service_manager.start_service().await {
             Ok(s) if s.is_healthy => s,
@@ -65,7 +102,12 @@ impl BrowserEngine {
 
         Ok((Self {
             driver: Some(driver),
+            pool: Vec::new(),
+            pool_size: DEFAULT_POOL_SIZE,
             service_manager,
+            cookies: CookiesByDomain::new(),
+            recycle_policy: RecyclePolicy::default(),
+            session_usage: SessionUsage::new(),
         }, status))
     }
 
@@ -85,15 +127,16 @@ impl BrowserEngine {
     pub async fn restart_with_fallback(&mut self) -> anyhow::Result<ServiceStatus> {
         // Close existing driver by taking ownership
         self.driver = None; // This will drop the WebDriver
+        self.pool.clear(); // Pooled sessions die with the service
 
         // Restart service
         let status = self.service_manager.restart_service().await?;
         
         if status.is_healthy {
             // Create new WebDriver
-            match self.service_manager.create_webdriver_internal().await {
+            match self.open_session().await {
                 Ok(driver) => {
-                    self.driver = Some(driver);
+                    self.set_session(driver);
                     Ok(status)
                 }
                 Err(e) => {
@@ -107,33 +150,386 @@ impl BrowserEngine {
 
     /// Navigates to a URL and returns the page source with automatic retry
     pub async fn get_page_source(&mut self, url: &str) -> anyhow::Result<String> {
-        // First, try with existing driver
-        if let Some(driver) = self.driver.as_ref() {
-            match self.try_get_page_source(driver, url).await {
+        let timeout = Duration::from_millis(DEFAULT_READY_TIMEOUT_MS as u64);
+        let blocked = self.service_manager.get_browser_options().blocked_resources;
+        self.get_page_source_with_retry(url, &ReadyCondition::DocumentComplete, timeout, &blocked, &RetryPolicy::default())
+            .await
+    }
+
+    /// Loads a page like [`Self::get_page_source_when_ready`], retrying failures that
+    /// `retry` allows with exponential backoff. The browser is only restarted when
+    /// the driver itself is dead.
+    pub(crate) async fn get_page_source_with_retry(
+        &mut self,
+        url: &str,
+        condition: &ReadyCondition,
+        timeout: Duration,
+        blocked: &[ResourceType],
+        retry: &RetryPolicy,
+    ) -> anyhow::Result<String> {
+        let mut attempt = 1;
+        loop {
+            let error = match self.get_page_source_when_ready(url, condition, timeout, blocked).await {
                 Ok(html) => return Ok(html),
-                Err(e) => {
-                    println!("Failed to get page source, attempting restart: {}", e);
+                Err(e) => e,
+            };
+            let kind = fetch_error::classify(&error);
+            if !retry.should_retry(kind, attempt) {
+                return Err(error);
+            }
+
+            let delay = retry.backoff(attempt);
+            println!("Attempt {} for {} failed ({}): {}; retrying in {} ms", attempt, url, kind, error, delay.as_millis());
+            tokio::time::sleep(delay).await;
+            if kind == FetchErrorKind::DriverDead {
+                self.restart_after_driver_failure().await;
+            }
+            attempt += 1;
+        }
+    }
+
+    /// Navigates to a URL in the current session, waits until `condition` holds and
+    /// returns the page source. Makes a single attempt and never restarts the browser.
+    /// Fails with [`FetchError::PageNotReady`] if the condition is not met within `timeout`,
+    /// unless the page is a bot wall, which is returned for the caller's bot-wall check.
+    pub(crate) async fn get_page_source_when_ready(
+        &mut self,
+        url: &str,
+        condition: &ReadyCondition,
+        timeout: Duration,
+        blocked: &[ResourceType],
+    ) -> anyhow::Result<String> {
+        self.recycle_session_if_due().await;
+        match self.driver.as_ref() {
+            Some(driver) => {
+                self.session_usage.navigations += 1;
+                let timeouts = self.service_manager.get_timeouts();
+                Self::load_page(driver, url, condition, timeout, blocked, &timeouts).await
+            }
+            None => Err(FetchError::DriverUnavailable {
+                reason: "browser has no active session".to_string(),
+            }
+            .into()),
+        }
+    }
+
+    /// Replaces the main session with a fresh one once it exceeds the recycle policy.
+    /// Cookies the sites set are carried over for every domain with imported cookies.
+    /// If no new session can be opened the browser is restarted instead.
+    async fn recycle_session_if_due(&mut self) {
+        if self.driver.is_none() {
+            return;
+        }
+        let driver_pid = self.service_manager.driver_pid();
+        let memory = || driver_pid.and_then(process_tree::resident_memory_bytes);
+        let Some(reason) = self.recycle_policy.recycle_reason(&self.session_usage, memory) else {
+            return;
+        };
+
+        println!("Recycling browser session after {}", reason);
+        let domains: Vec<String> = self.cookies.keys().cloned().collect();
+        for domain in domains {
+            match self.get_site_cookies(&domain).await {
+                Ok(cookies) => {
+                    self.cookies.insert(domain, cookies);
                 }
+                Err(e) => println!("Could not carry cookies for {} into the new session: {}", domain, e),
             }
         }
+        if let Some(driver) = self.driver.take() {
+            let _ = driver.quit().await;
+        }
+        match self.open_session().await {
+            Ok(driver) => self.set_session(driver),
+            Err(e) => {
+                println!("Could not open a fresh browser session: {}", e);
+                self.restart_after_driver_failure().await;
+            }
+        }
+    }
 
-        // If that fails, restart and retry
-        let restart_status = self.restart_with_fallback().await?;
-        println!("Browser restarted: {}", restart_status.error_message.unwrap_or_else(|| "Service restarted successfully".to_string()));
+    // Makes `driver` the main session and starts counting its use from zero
+    fn set_session(&mut self, driver: thirtyfour::WebDriver) {
+        self.driver = Some(driver);
+        self.session_usage = SessionUsage::new();
+    }
 
+    /// Restarts the browser after its driver died. A failed restart is only logged;
+    /// the next attempt then reports the browser as unavailable.
+    pub(crate) async fn restart_after_driver_failure(&mut self) {
+        match self.restart_with_fallback().await {
+            Ok(status) => println!("Browser restarted: {}", status.error_message.unwrap_or_else(|| "Service restarted successfully".to_string())),
+            Err(e) => println!("Failed to restart browser: {}", e),
+        }
+    }
+
+    /// Loads a page in the given session, skipping `blocked` resources, and returns
+    /// its source once ready. Fails with [`FetchError::TimedOut`] when the navigation or
+    /// the whole load exceeds `timeouts`.
+    pub(crate) async fn load_page(
+        driver: &thirtyfour::WebDriver,
+        url: &str,
+        condition: &ReadyCondition,
+        timeout: Duration,
+        blocked: &[ResourceType],
+        timeouts: &FetchTimeouts,
+    ) -> anyhow::Result<String> {
+        timeouts
+            .limit_fetch(url, async {
+                resource_blocking::apply(driver, blocked).await;
+                driver
+                    .goto(url)
+                    .await
+                    .map_err(|e| fetch_error::timed_out_or(e, url, "page load", timeouts.page_load_ms))?;
+                Self::wait_until_ready(driver, url, condition, timeout, timeouts).await?;
+                let html = driver.source().await?;
+                Ok(html)
+            })
+            .await
+    }
+
+    /// Polls the page until `condition` holds or `timeout` elapses. A bot wall never meets
+    /// the condition, so the page is checked for one before the wait is given up.
+    async fn wait_until_ready(
+        driver: &thirtyfour::WebDriver,
+        url: &str,
+        condition: &ReadyCondition,
+        timeout: Duration,
+        timeouts: &FetchTimeouts,
+    ) -> anyhow::Result<()> {
+        let deadline = Instant::now() + timeout;
+        // For NetworkIdle: last seen resource count and when it last changed
+        let mut last_resources: Option<(u64, Instant)> = None;
+
+        loop {
+            let ready = match condition {
+                ReadyCondition::DocumentComplete => Self::document_complete(driver).await?,
+                ReadyCondition::ElementPresent(selector) => {
+                    !driver.find_all(By::Css(selector.as_str())).await?.is_empty()
+                }
+                ReadyCondition::Script(expression) => {
+                    let script = format!("return !!({});", expression);
+                    driver
+                        .execute(script, Vec::new())
+                        .await
+                        .map_err(|e| fetch_error::timed_out_or(e, url, "ready script", timeouts.script_ms))?
+                        .json()
+                        .as_bool()
+                        .unwrap_or(false)
+                }
+                ReadyCondition::NetworkIdle { quiet_ms } => {
+                    let count = driver
+                        .execute("return performance.getEntriesByType('resource').length;", Vec::new())
+                        .await?
+                        .json()
+                        .as_u64()
+                        .unwrap_or(0);
+                    let now = Instant::now();
+                    let since = match last_resources {
+                        Some((last, since)) if last == count => since,
+                        _ => now,
+                    };
+                    last_resources = Some((count, since));
+                    Self::document_complete(driver).await?
+                        && now.duration_since(since) >= Duration::from_millis(*quiet_ms as u64)
+                }
+            };
+            if ready {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                let html = driver.source().await?;
+                return Self::timed_out_wait(&html, url, condition, timeout);
+            }
+            tokio::time::sleep(READY_POLL_INTERVAL).await;
+        }
+    }
+
+    // Outcome of a ready wait that ran out on a page with source `html`: bot walls count as
+    // loaded so the caller reports them as blocked, anything else is not ready
+    fn timed_out_wait(html: &str, url: &str, condition: &ReadyCondition, timeout: Duration) -> anyhow::Result<()> {
+        if bot_wall::detect(html).is_some() {
+            return Ok(());
+        }
+        Err(FetchError::PageNotReady {
+            url: url.to_string(),
+            condition: condition.to_string(),
+            timeout_ms: timeout.as_millis() as u64,
+        }
+        .into())
+    }
+
+    async fn document_complete(driver: &thirtyfour::WebDriver) -> anyhow::Result<bool> {
+        let state = driver.execute("return document.readyState;", Vec::new()).await?;
+        Ok(state.json().as_str() == Some("complete"))
+    }
+
+    /// Routes the browser through `proxy`. Sessions opened with a different proxy are
+    /// closed and the main session is reopened, so this is cheap only when nothing changes.
+    pub(crate) async fn use_proxy(&mut self, proxy: Option<&str>) -> anyhow::Result<()> {
+        if self.service_manager.get_proxy().as_deref() == proxy {
+            return Ok(());
+        }
+        println!("Switching browser proxy to {}", proxy.unwrap_or("direct connection"));
+        self.service_manager.set_proxy(proxy.map(str::to_string))?;
+        for driver in self.driver.take().into_iter().chain(self.pool.drain(..).map(|s| s.driver)) {
+            let _ = driver.quit().await;
+        }
+        let driver = self.open_session().await?;
+        self.set_session(driver);
+        Ok(())
+    }
+
+    /// Opens a new session and restores the imported cookies into it
+    async fn open_session(&self) -> anyhow::Result<thirtyfour::WebDriver> {
+        let driver = self.service_manager.create_webdriver_internal().await?;
+        cookie_jar::apply_all_to_session(&driver, &self.cookies).await;
+        Ok(driver)
+    }
+
+    /// Replaces the cookies for `domain` in the current session and in every session opened later.
+    pub(crate) async fn set_site_cookies(&mut self, domain: &str, cookies: Vec<SessionCookie>) -> anyhow::Result<()> {
         if let Some(driver) = self.driver.as_ref() {
-            self.try_get_page_source(driver, url).await
-        } else {
-            anyhow::bail!("Browser is not available after restart")
+            cookie_jar::apply_to_session(driver, domain, &cookies).await?;
         }
+        // Idle pooled sessions are reopened with the new cookies when next needed
+        for session in self.pool.drain(..) {
+            let _ = session.driver.quit().await;
+        }
+        self.cookies.insert(domain.to_string(), cookies);
+        Ok(())
+    }
+
+    /// Returns the source and a PNG screenshot of the page the main session shows,
+    /// whichever of the two could be taken.
+    pub(crate) async fn capture_current_page(&self) -> (Option<String>, Option<Vec<u8>>) {
+        let Some(driver) = self.driver.as_ref() else {
+            return (None, None);
+        };
+        let html = driver.source().await.ok();
+        let screenshot = driver.screenshot_as_png().await.ok();
+        (html, screenshot)
     }
 
-    /// Internal method to try getting page source
-    async fn try_get_page_source(&self, driver: &thirtyfour::WebDriver, url: &str) -> anyhow::Result<String> {
-        driver.goto(url).await?;
-        tokio::time::sleep(Duration::from_secs(2)).await;
-        let html = driver.source().await?;
-        Ok(html)
+    /// Reads the cookies the current session holds for `domain`.
+    pub(crate) async fn get_site_cookies(&self, domain: &str) -> anyhow::Result<Vec<SessionCookie>> {
+        match self.driver.as_ref() {
+            Some(driver) => cookie_jar::read_from_session(driver, domain).await,
+            None => Err(FetchError::DriverUnavailable {
+                reason: "browser has no active session".to_string(),
+            }
+            .into()),
+        }
+    }
+
+    /// Sets how many browser sessions `fetch_pages_concurrently` may use at once
+    pub fn set_pool_size(&mut self, pool_size: u32) {
+        self.pool_size = (pool_size as usize).max(1);
+    }
+
+    /// Gets the configured session pool size
+    pub fn get_pool_size(&self) -> u32 {
+        self.pool_size as u32
+    }
+
+    /// Loads several pages concurrently, one per pooled session, and returns the
+    /// results in job order. Sessions that fail are replaced; the pool is kept for
+    /// the next call.
+    pub(crate) async fn fetch_pages_concurrently(
+        &mut self,
+        jobs: Vec<PageJob>,
+        limiter: Arc<RateLimiter>,
+        events: EventBus,
+    ) -> anyhow::Result<Vec<anyhow::Result<LoadedPage>>> {
+        if self.driver.is_none() || !self.service_manager.check_service_health().await.is_healthy {
+            self.restart_with_fallback().await?;
+        }
+
+        let spec = SessionSpec {
+            driver_url: self.service_manager.driver_url(),
+            capabilities: self.service_manager.webdriver_capabilities()?,
+            cookies: self.cookies.clone(),
+            timeouts: self.service_manager.get_timeouts(),
+            recycle_policy: self.recycle_policy.clone(),
+        };
+        let wanted = self.pool_size.min(self.service_manager.max_sessions()).min(jobs.len()).max(1);
+
+        let main = self.driver.take().map(|driver| PooledSession { driver, usage: self.session_usage });
+        let mut sessions: Vec<PooledSession> = main.into_iter().chain(self.pool.drain(..)).collect();
+        while sessions.len() < wanted {
+            match self.open_session().await {
+                Ok(driver) => sessions.push(PooledSession::new(driver)),
+                Err(e) => {
+                    println!("Could not open browser session {} of {}: {}", sessions.len() + 1, wanted, e);
+                    break;
+                }
+            }
+        }
+        let idle = sessions.split_off(wanted.min(sessions.len()));
+
+        println!("Fetching {} pages with {} browser sessions...", jobs.len(), sessions.len());
+        let (results, mut survivors) = session_pool::run_jobs(sessions, spec, jobs, limiter, events).await;
+
+        survivors.extend(idle);
+        if !survivors.is_empty() {
+            // The main session keeps counting from the use it had in the pool
+            let main = survivors.remove(0);
+            self.driver = Some(main.driver);
+            self.session_usage = main.usage;
+        }
+        self.pool = survivors;
+        Ok(results)
+    }
+
+    /// Resource types blocked while loading `site`'s pages. Firefox blocks through
+    /// session-wide preferences, so there a site's allowlist has no effect.
+    pub(crate) fn blocked_resources_for(&self, site: &SiteConfig) -> Vec<ResourceType> {
+        let blocked = self.service_manager.get_browser_options().blocked_resources;
+        if self.service_manager.get_browser_kind() == BrowserKind::Firefox {
+            return blocked;
+        }
+        resource_blocking::effective(&blocked, &site.allowed_resources)
+    }
+
+    /// Applies `timeouts` to the open sessions and to every session opened later
+    pub async fn set_timeouts(&mut self, timeouts: FetchTimeouts) -> anyhow::Result<()> {
+        for driver in self.driver.iter().chain(self.pool.iter().map(|s| &s.driver)) {
+            driver.update_timeouts(timeouts.webdriver()).await?;
+        }
+        self.service_manager.set_timeouts(timeouts);
+        Ok(())
+    }
+
+    pub fn get_timeouts(&self) -> FetchTimeouts {
+        self.service_manager.get_timeouts()
+    }
+
+    /// Sets when sessions are replaced by fresh ones. Pooled sessions are held to the
+    /// navigation and age limits; the memory limit is checked on the main session only.
+    pub fn set_recycle_policy(&mut self, policy: RecyclePolicy) {
+        if policy.max_memory_mb > 0 && !process_tree::can_read_memory() {
+            println!("Browser memory cannot be read on this platform; the session memory limit is ignored");
+        }
+        self.recycle_policy = policy;
+    }
+
+    pub fn get_recycle_policy(&self) -> RecyclePolicy {
+        self.recycle_policy.clone()
+    }
+
+    /// Returns the newest `lines` lines the driver printed
+    pub(crate) fn driver_log_tail(&self, lines: usize) -> anyhow::Result<Vec<String>> {
+        self.service_manager.driver_log_tail(lines)
+    }
+
+    /// Whether the engine uses an external WebDriver server rather than its own driver
+    pub fn is_remote(&self) -> bool {
+        self.service_manager.is_remote()
+    }
+
+    /// Gets the browser kind the engine drives
+    pub fn get_browser_kind(&self) -> BrowserKind {
+        self.service_manager.get_browser_kind()
     }
 
     /// Gets the current port being used
@@ -158,15 +554,60 @@ impl BrowserEngine {
             let _ = driver.quit().await; // Ignore errors on quit
             println!("Browser session closed.");
         }
+        for session in self.pool.drain(..) {
+            let _ = session.driver.quit().await;
+        }
         // Service manager will be dropped and stop the chromedriver process
         Ok(())
     }
 }
 
+impl Fetcher for BrowserEngine {
+    #[frb(ignore)]
+    async fn fetch_page(&mut self, url: &str, site: &SiteConfig, proxy: Option<&str>) -> anyhow::Result<String> {
+        self.use_proxy(proxy).await?;
+        let timeout = Duration::from_millis(site.ready_timeout_ms as u64);
+        let blocked = self.blocked_resources_for(site);
+        self.get_page_source_when_ready(url, &site.ready_condition, timeout, &blocked).await
+    }
+}
+
 impl Drop for BrowserEngine {
     fn drop(&mut self) {
         if self.driver.is_some() {
-            eprintln!("Warning: BrowserEngine was dropped without calling shutdown(). The browser window may have been left open.");
+            eprintln!("Warning: BrowserEngine was dropped without calling shutdown(); quitting its sessions.");
         }
+        // Dropping a WebDriver quits its session as best it can; do it while the driver
+        // still runs, before the service manager kills it
+        self.driver.take();
+        self.pool.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://www.amazon.in/dp/B0TESTTEST";
+
+    #[test]
+    fn captcha_page_that_never_gets_ready_is_reported_as_blocked() {
+        let captcha = "<html><body><form action=\"/errors/validateCaptcha\">Enter the characters you see below</form></body></html>";
+        let condition = ReadyCondition::ElementPresent("span#productTitle".to_string());
+
+        // The wait hands the page on instead of failing as not ready...
+        BrowserEngine::timed_out_wait(captcha, URL, &condition, Duration::from_secs(10)).unwrap();
+        // ...so the bot-wall check that follows every load reports it
+        let limiter = RateLimiter::new();
+        let error = bot_wall::check_page(captcha, URL, "amazon.in", 60_000, &limiter, &EventBus::new()).unwrap_err();
+        assert_eq!(fetch_error::classify(&error), FetchErrorKind::BotWall);
+        assert!(limiter.cooldown_remaining("amazon.in").is_some());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn page_that_never_gets_ready_is_not_ready() {
+        let condition = ReadyCondition::ElementPresent("span#productTitle".to_string());
+        let error = BrowserEngine::timed_out_wait("<html><body>Loading…</body></html>", URL, &condition, Duration::from_secs(10)).unwrap_err();
+        assert!(matches!(error.downcast_ref::<FetchError>(), Some(FetchError::PageNotReady { .. })));
+    }
+}
@@ -0,0 +1,210 @@
+// src/session_pool.rs
+use flutter_rust_bridge::frb;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use thirtyfour::{Capabilities, WebDriver};
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinSet;
+
+use crate::api::bot_wall;
+use crate::api::cookie_jar::{self, CookiesByDomain};
+use crate::api::events::EventBus;
+use crate::api::fetch_error::{self, FetchErrorKind};
+use crate::api::rate_limiter::{RateLimit, RateLimiter};
+use crate::api::recycle_policy::{RecyclePolicy, SessionUsage};
+use crate::api::resource_blocking::ResourceType;
+use crate::api::retry_policy::RetryPolicy;
+use crate::api::scraper_engine::BrowserEngine;
+use crate::api::site_config::ReadyCondition;
+use crate::api::timeouts::FetchTimeouts;
+
+/// A page to load in one of the pooled sessions
+pub(crate) struct PageJob {
+    pub url: String,
+    pub domain: String,
+    pub condition: ReadyCondition,
+    pub timeout: Duration,
+    pub rate_limit: RateLimit,
+    pub retry_policy: RetryPolicy,
+    pub block_cooldown_ms: u32,
+    pub blocked_resources: Vec<ResourceType>,
+}
+
+/// A page a pooled session loaded, with how long its successful attempt took
+pub(crate) struct LoadedPage {
+    pub html: String,
+    pub duration: Duration,
+}
+
+/// A browser session together with its use so far, which follows it in and out of the
+/// pool so the recycle limits keep counting
+#[frb(ignore)]
+#[derive(Debug)]
+pub(crate) struct PooledSession {
+    pub driver: WebDriver,
+    pub usage: SessionUsage,
+}
+
+impl PooledSession {
+    pub(crate) fn new(driver: WebDriver) -> Self {
+        Self { driver, usage: SessionUsage::new() }
+    }
+}
+
+/// What a worker needs to open a replacement session against the running driver
+#[frb(ignore)]
+#[derive(Clone)]
+pub(crate) struct SessionSpec {
+    pub driver_url: String,
+    pub capabilities: Capabilities,
+    pub cookies: CookiesByDomain,
+    pub timeouts: FetchTimeouts,
+    pub recycle_policy: RecyclePolicy,
+}
+
+impl SessionSpec {
+    async fn open(&self) -> anyhow::Result<PooledSession> {
+        let driver = WebDriver::new(&self.driver_url, self.capabilities.clone()).await?;
+        cookie_jar::apply_all_to_session(&driver, &self.cookies).await;
+        Ok(PooledSession::new(driver))
+    }
+}
+
+type JobQueue = Arc<Mutex<mpsc::Receiver<(usize, PageJob)>>>;
+
+/// Loads `jobs` with one worker per session, all pulling from a bounded queue.
+/// Every load first waits for its domain's slot in `limiter`, and failed loads are
+/// retried as the job's retry policy allows. Bot walls pause their domain and are
+/// reported through `events`.
+///
+/// A session whose driver died, or that exceeds the recycle policy's navigation or age
+/// limit, is quit and replaced before the next attempt. Returns one result per job, in
+/// job order, and the sessions that are still usable.
+pub(crate) async fn run_jobs(
+    sessions: Vec<PooledSession>,
+    spec: SessionSpec,
+    jobs: Vec<PageJob>,
+    limiter: Arc<RateLimiter>,
+    events: EventBus,
+) -> (Vec<anyhow::Result<LoadedPage>>, Vec<PooledSession>) {
+    let job_count = jobs.len();
+    let (sender, receiver) = mpsc::channel(sessions.len().max(1));
+    let receiver: JobQueue = Arc::new(Mutex::new(receiver));
+
+    let mut workers = JoinSet::new();
+    for session in sessions {
+        workers.spawn(run_worker(Some(session), spec.clone(), receiver.clone(), limiter.clone(), events.clone()));
+    }
+    drop(receiver);
+
+    for job in jobs.into_iter().enumerate() {
+        // Only fails once every worker is gone; the missing results are reported below
+        if sender.send(job).await.is_err() {
+            break;
+        }
+    }
+    drop(sender);
+
+    let mut results: Vec<Option<anyhow::Result<LoadedPage>>> = (0..job_count).map(|_| None).collect();
+    let mut survivors = Vec::new();
+    while let Some(joined) = workers.join_next().await {
+        match joined {
+            Ok((done, session)) => {
+                for (index, result) in done {
+                    results[index] = Some(result);
+                }
+                survivors.extend(session);
+            }
+            Err(e) => eprintln!("Browser pool worker panicked: {}", e),
+        }
+    }
+
+    let results = results
+        .into_iter()
+        .map(|r| r.unwrap_or_else(|| Err(anyhow::anyhow!("Page was not fetched: no browser session was available"))))
+        .collect();
+    (results, survivors)
+}
+
+async fn run_worker(
+    mut session: Option<PooledSession>,
+    spec: SessionSpec,
+    queue: JobQueue,
+    limiter: Arc<RateLimiter>,
+    events: EventBus,
+) -> (Vec<(usize, anyhow::Result<LoadedPage>)>, Option<PooledSession>) {
+    let mut done = Vec::new();
+    loop {
+        let next = queue.lock().await.recv().await;
+        let Some((index, job)) = next else {
+            break;
+        };
+
+        let result = run_job(&mut session, &spec, &job, &limiter, &events).await;
+        done.push((index, result));
+    }
+    (done, session)
+}
+
+async fn run_job(
+    session: &mut Option<PooledSession>,
+    spec: &SessionSpec,
+    job: &PageJob,
+    limiter: &RateLimiter,
+    events: &EventBus,
+) -> anyhow::Result<LoadedPage> {
+    let mut attempt = 1;
+    loop {
+        // Memory is measured for the driver's whole process tree, so only the main
+        // session checks the memory limit
+        let due = session.as_ref().and_then(|s| spec.recycle_policy.recycle_reason(&s.usage, || None));
+        if let Some(reason) = due {
+            println!("Recycling pooled browser session after {}", reason);
+            if let Some(old) = session.take() {
+                let _ = old.driver.quit().await;
+            }
+        }
+        if session.is_none() {
+            match spec.open().await {
+                Ok(replacement) => *session = Some(replacement),
+                Err(e) => return Err(anyhow::anyhow!("Could not open a replacement browser session: {}", e)),
+            }
+        }
+        let Some(current) = session.as_mut() else {
+            anyhow::bail!("No browser session available for {}", job.url)
+        };
+
+        bot_wall::ensure_not_cooling_down(&job.url, &job.domain, limiter)?;
+        limiter.acquire(&job.domain, &job.rate_limit).await;
+        bot_wall::ensure_not_cooling_down(&job.url, &job.domain, limiter)?;
+
+        current.usage.navigations += 1;
+        let started = Instant::now();
+        let result = BrowserEngine::load_page(&current.driver, &job.url, &job.condition, job.timeout, &job.blocked_resources, &spec.timeouts)
+            .await
+            .and_then(|html| {
+                bot_wall::check_page(&html, &job.url, &job.domain, job.block_cooldown_ms, limiter, events)?;
+                Ok(html)
+            });
+        let error = match result {
+            Ok(html) => return Ok(LoadedPage { html, duration: started.elapsed() }),
+            Err(e) => e,
+        };
+        let kind = fetch_error::classify(&error);
+        // Page-level problems leave the session usable; a dead driver does not
+        if kind == FetchErrorKind::DriverDead {
+            println!("Recycling browser session after failure on {}: {}", job.url, error);
+            if let Some(broken) = session.take() {
+                let _ = broken.driver.quit().await;
+            }
+        }
+        if !job.retry_policy.should_retry(kind, attempt) {
+            return Err(error);
+        }
+
+        let delay = job.retry_policy.backoff(attempt);
+        println!("Attempt {} for {} failed ({}); retrying in {} ms", attempt, job.url, kind, delay.as_millis());
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
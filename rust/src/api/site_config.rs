@@ -0,0 +1,107 @@
+// src/site_config.rs
+use crate::api::fetcher::FetcherKind;
+use crate::api::rate_limiter::RateLimit;
+use crate::api::resource_blocking::ResourceType;
+use crate::api::retry_policy::RetryPolicy;
+
+/// Default time a browser page gets to satisfy its ready condition.
+pub const DEFAULT_READY_TIMEOUT_MS: u32 = 15_000;
+/// Default pause for a site after it served a captcha or access-denied page.
+pub const DEFAULT_BLOCK_COOLDOWN_MS: u32 = 30 * 60 * 1000;
+
+/// Scraping settings for one site, matched against a product URL's host
+#[derive(Debug, Clone)]
+pub struct SiteConfig {
+    pub domain: String,
+    pub fetcher: FetcherKind,
+    /// Retry through the other fetcher when the first one fails or returns an incomplete page.
+    pub fallback: bool,
+    /// When a browser-loaded page counts as ready to scrape.
+    pub ready_condition: ReadyCondition,
+    /// How long to wait for `ready_condition` before giving up.
+    pub ready_timeout_ms: u32,
+    /// Throttling applied to every request to this site.
+    pub rate_limit: RateLimit,
+    /// How failed fetches of this site's pages are retried.
+    pub retry_policy: RetryPolicy,
+    /// How long to stop fetching from this site after it served a bot wall.
+    pub block_cooldown_ms: u32,
+    /// Proxies this site is fetched through in rotation; empty uses the engine-wide proxies.
+    pub proxies: Vec<String>,
+    /// Resource types this site's pages need, loaded even when the browser blocks them.
+    /// Not supported on Firefox, which blocks the same resources on every site.
+    pub allowed_resources: Vec<ResourceType>,
+}
+
+/// A condition the browser waits for after navigation before reading the page source
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReadyCondition {
+    /// `document.readyState` is "complete".
+    DocumentComplete,
+    /// At least one element matches the CSS selector.
+    ElementPresent(String),
+    /// The document is complete and no new resources were requested for `quiet_ms`.
+    NetworkIdle { quiet_ms: u32 },
+    /// The JavaScript expression evaluates to a truthy value.
+    Script(String),
+}
+
+impl std::fmt::Display for ReadyCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadyCondition::DocumentComplete => write!(f, "document complete"),
+            ReadyCondition::ElementPresent(selector) => write!(f, "element '{}'", selector),
+            ReadyCondition::NetworkIdle { quiet_ms } => write!(f, "network idle for {} ms", quiet_ms),
+            ReadyCondition::Script(expression) => write!(f, "script '{}'", expression),
+        }
+    }
+}
+
+impl SiteConfig {
+    /// Creates settings for `domain` with the default (browser) fetcher and fallback enabled.
+    pub fn new(domain: &str) -> Self {
+        Self {
+            domain: domain.to_lowercase(),
+            fetcher: FetcherKind::Browser,
+            fallback: true,
+            ready_condition: ReadyCondition::DocumentComplete,
+            ready_timeout_ms: DEFAULT_READY_TIMEOUT_MS,
+            rate_limit: RateLimit::default(),
+            retry_policy: RetryPolicy::default(),
+            block_cooldown_ms: DEFAULT_BLOCK_COOLDOWN_MS,
+            proxies: Vec::new(),
+            allowed_resources: Vec::new(),
+        }
+    }
+
+    fn with_ready_selector(domain: &str, selector: &str) -> Self {
+        Self {
+            ready_condition: ReadyCondition::ElementPresent(selector.to_string()),
+            ..Self::new(domain)
+        }
+    }
+
+    /// Whether `url` belongs to this site, including its subdomains.
+    pub fn matches(&self, url: &str) -> bool {
+        match host_of(url) {
+            Some(host) => host == self.domain || host.ends_with(&format!(".{}", self.domain)),
+            None => false,
+        }
+    }
+}
+
+/// Settings for the supported stores.
+pub(crate) fn default_site_configs() -> Vec<SiteConfig> {
+    // Wait for the product title, which the parsers treat as the critical field
+    vec![
+        SiteConfig::with_ready_selector("amazon.in", "span#productTitle"),
+        SiteConfig::with_ready_selector("flipkart.com", "span.VU-ZEz"),
+    ]
+}
+
+/// Extracts the lower-cased host of a URL.
+pub(crate) fn host_of(url: &str) -> Option<String> {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(|h| h.to_lowercase()))
+}
@@ -0,0 +1,68 @@
+// src/timeouts.rs
+use std::future::Future;
+use std::time::Duration;
+use thirtyfour::TimeoutConfiguration;
+
+use crate::api::fetch_error::FetchError;
+
+/// How long fetches may take before they fail with [`FetchError::TimedOut`].
+/// The browser limits apply to every WebDriver session the engine opens.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchTimeouts {
+    /// Time a navigation may take before the browser gives up on the page.
+    pub page_load_ms: u64,
+    /// Time a script run in the page, such as a ready check, may take.
+    pub script_ms: u64,
+    /// Time element lookups keep retrying before reporting no match. Keep at 0 unless
+    /// sites need it, since ready checks then wait this long on every miss.
+    pub implicit_wait_ms: u64,
+    /// Limit for a whole fetch attempt, navigation and ready wait included; 0 disables it.
+    /// Should exceed `page_load_ms` plus the sites' ready timeouts.
+    pub fetch_ms: u64,
+    /// Time the driver may take to answer a health check before it counts as unhealthy.
+    pub status_check_ms: u64,
+}
+
+impl Default for FetchTimeouts {
+    fn default() -> Self {
+        Self {
+            page_load_ms: 30 * 1000,
+            script_ms: 30 * 1000,
+            implicit_wait_ms: 0,
+            fetch_ms: 120 * 1000,
+            status_check_ms: 5 * 1000,
+        }
+    }
+}
+
+impl FetchTimeouts {
+    /// The session timeouts in the form WebDriver expects.
+    pub(crate) fn webdriver(&self) -> TimeoutConfiguration {
+        TimeoutConfiguration::new(
+            Some(Duration::from_millis(self.script_ms)),
+            Some(Duration::from_millis(self.page_load_ms)),
+            Some(Duration::from_millis(self.implicit_wait_ms)),
+        )
+    }
+
+    /// The per-fetch limit, or `None` when it is disabled.
+    pub(crate) fn fetch_limit(&self) -> Option<Duration> {
+        (self.fetch_ms > 0).then(|| Duration::from_millis(self.fetch_ms))
+    }
+
+    /// Runs one fetch attempt of `url`, failing it once it exceeds `fetch_ms`.
+    pub(crate) async fn limit_fetch<T>(&self, url: &str, fetch: impl Future<Output = anyhow::Result<T>>) -> anyhow::Result<T> {
+        let Some(limit) = self.fetch_limit() else {
+            return fetch.await;
+        };
+        match tokio::time::timeout(limit, fetch).await {
+            Ok(result) => result,
+            Err(_) => Err(FetchError::TimedOut {
+                url: url.to_string(),
+                operation: "fetch".to_string(),
+                timeout_ms: self.fetch_ms,
+            }
+            .into()),
+        }
+    }
+}
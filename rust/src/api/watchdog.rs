@@ -0,0 +1,129 @@
+// src/watchdog.rs
+use std::collections::VecDeque;
+use std::sync::Weak;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+use crate::api::events::{now_timestamp, EngineEvent, EventBus};
+use crate::api::scraper_engine::BrowserEngine;
+
+/// How often the background watchdog probes the browser driver and how many
+/// restarts it may make before it stops trying, so a crashing driver is not
+/// restarted in a loop.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchdogConfig {
+    /// Time between health probes, in milliseconds.
+    pub interval_ms: u64,
+    /// Restarts allowed within `restart_window_ms`. Once they are used up the driver is
+    /// left down until the oldest restart leaves the window; 0 only reports status changes.
+    pub max_restarts: u32,
+    pub restart_window_ms: u64,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            interval_ms: 30 * 1000,
+            max_restarts: 3,
+            restart_window_ms: 10 * 60 * 1000,
+        }
+    }
+}
+
+/// Probes the engine every `config.interval_ms` until the engine is dropped or the task
+/// is aborted. The task holds only a weak reference so it never keeps the browser alive.
+pub(crate) fn spawn(engine: Weak<Mutex<BrowserEngine>>, config: WatchdogConfig, events: EventBus) -> JoinHandle<()> {
+    let interval = Duration::from_millis(config.interval_ms.max(1));
+    tokio::spawn(async move {
+        let mut watchdog = Watchdog::new(config, events);
+        loop {
+            tokio::time::sleep(interval).await;
+            let Some(engine) = engine.upgrade() else {
+                break;
+            };
+            // A fetch holding the engine notices a dead driver itself
+            let Ok(mut engine) = engine.try_lock() else {
+                continue;
+            };
+            watchdog.check(&mut engine).await;
+        }
+    })
+}
+
+struct Watchdog {
+    config: WatchdogConfig,
+    events: EventBus,
+    // When the restarts still counted against the budget were made, oldest first
+    restarts: VecDeque<Instant>,
+    // Health last reported to listeners; `None` until the first probe
+    reported_healthy: Option<bool>,
+    budget_exhausted: bool,
+}
+
+impl Watchdog {
+    fn new(config: WatchdogConfig, events: EventBus) -> Self {
+        Self {
+            config,
+            events,
+            restarts: VecDeque::new(),
+            reported_healthy: None,
+            budget_exhausted: false,
+        }
+    }
+
+    async fn check(&mut self, engine: &mut BrowserEngine) {
+        let status = engine.check_service_status().await;
+        if status.is_running {
+            self.budget_exhausted = false;
+            self.report(true, status.current_port, status.message);
+            return;
+        }
+        self.report(false, status.current_port, status.message);
+        if self.config.max_restarts == 0 {
+            return;
+        }
+
+        let window = Duration::from_millis(self.config.restart_window_ms);
+        while self.restarts.front().is_some_and(|at| at.elapsed() >= window) {
+            self.restarts.pop_front();
+        }
+        if self.restarts.len() >= self.config.max_restarts as usize {
+            if !self.budget_exhausted {
+                self.budget_exhausted = true;
+                println!(
+                    "Watchdog: {} restarts within {} s, leaving the browser service down",
+                    self.restarts.len(),
+                    window.as_secs()
+                );
+                self.events.emit(EngineEvent::ServiceRestartsExhausted {
+                    restarts: self.restarts.len() as u32,
+                    window_ms: self.config.restart_window_ms,
+                    timestamp: now_timestamp(),
+                });
+            }
+            return;
+        }
+
+        self.restarts.push_back(Instant::now());
+        println!("Watchdog: browser service is unhealthy, restarting...");
+        match engine.restart_with_fallback().await {
+            Ok(status) => self.report(true, status.port, format!("Service restarted on port {}", status.port)),
+            Err(e) => println!("Watchdog: restart failed: {}", e),
+        }
+    }
+
+    // Emits an event only when the health differs from what was last reported
+    fn report(&mut self, is_healthy: bool, port: u16, message: String) {
+        if self.reported_healthy == Some(is_healthy) {
+            return;
+        }
+        self.reported_healthy = Some(is_healthy);
+        self.events.emit(EngineEvent::ServiceStatusChanged {
+            is_healthy,
+            port,
+            message,
+            timestamp: now_timestamp(),
+        });
+    }
+}